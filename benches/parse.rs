@@ -0,0 +1,93 @@
+#[macro_use]
+extern crate criterion;
+extern crate iso_8601;
+
+use {
+    std::{
+        convert::TryFrom,
+        time::SystemTime
+    },
+    criterion::{
+        Criterion,
+        black_box
+    },
+    iso_8601::{
+        *,
+        bench_input::{
+            Generator,
+            corpus
+        }
+    }
+};
+
+const N: usize = 1_000;
+const SEED: u64 = 8601;
+
+fn parse<F>(c: &mut Criterion, name: &str, generate: F, parser: fn(&[u8]) -> bool)
+where F: FnMut(&mut Generator) -> String {
+    let inputs = corpus(N, SEED, generate);
+    c.bench_function(name, |b| b.iter(|| {
+        for input in &inputs {
+            assert!(parser(black_box(input.as_bytes())));
+        }
+    }));
+}
+
+fn date(c: &mut Criterion) {
+    parse(c, "date", Generator::date, |x| parse::date(x).is_ok());
+}
+
+fn time(c: &mut Criterion) {
+    parse(c, "time", Generator::time, |x| parse::time_global_hms(x).is_ok());
+}
+
+fn datetime(c: &mut Criterion) {
+    parse(c, "datetime", Generator::datetime, |x| parse::datetime_global_hms(x).is_ok());
+    parse(c, "datetime rfc3339", Generator::rfc3339, |x| parse::datetime_global_hms(x).is_ok());
+    parse(c, "datetime rfc3339 check", Generator::rfc3339, |x| parse::is_valid_rfc3339(std::str::from_utf8(x).unwrap()));
+}
+
+fn duration(c: &mut Criterion) {
+    parse(c, "duration", Generator::duration, |x| parse::duration(x).is_ok());
+}
+
+fn interval(c: &mut Criterion) {
+    parse(c, "interval", Generator::interval, |x| parse::interval_global_hms(x).is_ok());
+}
+
+fn conversion(c: &mut Criterion) {
+    let datetimes: Vec<DateTime<Date>> = corpus(N, SEED, Generator::rfc3339)
+        .iter()
+        .map(|x| x.parse().unwrap())
+        .collect();
+    let dates: Vec<YmdDate> = datetimes.iter()
+        .map(|x| x.date.into())
+        .collect();
+
+    c.bench_function("week date from calendar date", |b| b.iter(|| {
+        for date in &dates {
+            black_box(WdDate::from(black_box(*date)));
+        }
+    }));
+    c.bench_function("ordinal date from calendar date", |b| b.iter(|| {
+        for date in &dates {
+            black_box(ODate::from(black_box(*date)));
+        }
+    }));
+    let ordinals: Vec<ODate> = dates.iter()
+        .map(|&x| x.into())
+        .collect();
+    c.bench_function("calendar date from ordinal date", |b| b.iter(|| {
+        for date in &ordinals {
+            black_box(YmdDate::from(black_box(*date)));
+        }
+    }));
+    c.bench_function("system time from datetime", |b| b.iter(|| {
+        for datetime in &datetimes {
+            black_box(SystemTime::try_from(black_box(*datetime)).ok());
+        }
+    }));
+}
+
+criterion_group!(benches, date, time, datetime, duration, interval, conversion);
+criterion_main!(benches);
@@ -0,0 +1,24 @@
+#[cfg(feature = "ffi")]
+extern crate cbindgen;
+
+fn main() {
+    #[cfg(feature = "ffi")]
+    {
+        let dir = ::std::env::var("CARGO_MANIFEST_DIR").unwrap();
+        println!("cargo:rerun-if-changed=src/ffi.rs");
+
+        let mut config = cbindgen::Config::default();
+        config.enumeration.prefix_with_name = true;
+
+        cbindgen::Builder::new()
+            .with_config(config)
+            .with_language(cbindgen::Language::C)
+            .with_include_guard("ISO8601_H")
+            .with_src(format!("{}/src/ffi.rs", dir))
+            .with_documentation(true)
+            .with_cpp_compat(true)
+            .generate()
+            .expect("failed to generate C header")
+            .write_to_file(format!("{}/include/iso8601.h", dir));
+    }
+}
@@ -0,0 +1,70 @@
+#ifndef ISO8601_H
+#define ISO8601_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum Iso8601Error {
+  Iso8601Error_Ok = 0,
+  Iso8601Error_NullPointer,
+  Iso8601Error_InvalidUtf8,
+  /**
+   * The input does not follow the grammar.
+   */
+  Iso8601Error_Syntax,
+  /**
+   * The input continues after the date and time.
+   */
+  Iso8601Error_TrailingInput,
+  /**
+   * The date or time does not exist, such as February 30th.
+   */
+  Iso8601Error_Invalid,
+} Iso8601Error;
+
+/**
+ * Calendar date and time of day, flattened.
+ */
+typedef struct Iso8601DateTime {
+  int16_t year;
+  uint8_t month;
+  uint8_t day;
+  uint8_t hour;
+  uint8_t minute;
+  /**
+   * 60 for a leap second.
+   */
+  uint8_t second;
+  uint32_t nanosecond;
+  /**
+   * Whether `offset_minutes` was given or the time is local.
+   */
+  bool has_offset;
+  /**
+   * Difference from UTC in minutes.
+   */
+  int16_t offset_minutes;
+} Iso8601DateTime;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Parses a complete date and time from a nul-terminated string.
+ *
+ * `out` is only written to on success.
+ *
+ * # Safety
+ *
+ * `input` must be nul-terminated and `out` must be writable.
+ */
+enum Iso8601Error iso8601_parse_datetime(const char *input, struct Iso8601DateTime *out);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* ISO8601_H */
@@ -0,0 +1,150 @@
+//! Python module `iso_8601`.
+//!
+//! Unlike `datetime.fromisoformat`, this accepts all
+//! calendar, week and ordinal dates of the standard.
+
+// false positive in the expansion of `#[pyfunction]`
+#![allow(clippy::useless_conversion)]
+
+use {
+    iso_8601::{
+        Valid,
+        AnyTime,
+        Duration,
+        YmdDate,
+        parse
+    },
+    pyo3::{
+        prelude::*,
+        exceptions::PyValueError,
+        types::{
+            PyDate,
+            PyDateTime,
+            PyDelta,
+            PyTzInfo
+        }
+    },
+    std::{
+        convert::TryFrom,
+        time
+    }
+};
+
+fn parse_complete<'a, T, P>(parser: P, input: &'a str) -> PyResult<T>
+where
+    T: Valid,
+    P: FnOnce(&'a [u8]) -> nom::IResult<&'a [u8], T>
+{
+    match parser(input.as_bytes()) {
+        Ok((rest, value)) if rest.is_empty() && value.is_valid() => Ok(value),
+        _ => Err(PyValueError::new_err(format!("invalid ISO 8601 string: {:?}", input)))
+    }
+}
+
+fn out_of_range<T>(input: &str) -> PyResult<T> {
+    Err(PyValueError::new_err(format!("not representable in Python: {:?}", input)))
+}
+
+/// Parses a calendar, week or ordinal date into a `datetime.date`.
+#[pyfunction]
+fn parse_date<'py>(py: Python<'py>, s: &str) -> PyResult<Bound<'py, PyDate>> {
+    let date = YmdDate::from(parse_complete(parse::date, s)?);
+    if date.year < 1 {
+        return out_of_range(s);
+    }
+    PyDate::new_bound(py, date.year.into(), date.month, date.day)
+}
+
+/// Parses a date and time into a `datetime.datetime`
+/// that is aware if the input has a timezone.
+#[pyfunction]
+fn parse_datetime<'py>(py: Python<'py>, s: &str) -> PyResult<Bound<'py, PyDateTime>> {
+    let datetime = parse_complete(parse::datetime_any_hms, s)?;
+    let date = YmdDate::from(datetime.date);
+    let (local, timezone) = match datetime.time {
+        AnyTime::Global(time) => (time.local, Some(time.timezone)),
+        AnyTime::Local(time)  => (time, None)
+    };
+    // Python has neither leap seconds nor the end of day
+    if date.year < 1 || local.naive.hour == 24 || local.naive.second == 60 {
+        return out_of_range(s);
+    }
+
+    let tzinfo = match timezone {
+        Some(minutes) => Some(py
+            .import_bound("datetime")?
+            .getattr("timezone")?
+            .call1((PyDelta::new_bound(py, 0, i32::from(minutes) * 60, 0, true)?,))?
+            .downcast_into::<PyTzInfo>()?),
+        None => None
+    };
+    PyDateTime::new_bound(
+        py,
+        date.year.into(),
+        date.month,
+        date.day,
+        local.naive.hour,
+        local.naive.minute,
+        local.naive.second,
+        local.nanosecond() / 1000,
+        tzinfo.as_ref()
+    )
+}
+
+/// Parses a duration into a `datetime.timedelta`,
+/// taking days as 24 hours like Python does.
+/// Years and months are rejected as their length varies.
+#[pyfunction]
+fn parse_duration<'py>(py: Python<'py>, s: &str) -> PyResult<Bound<'py, PyDelta>> {
+    let duration = parse_complete(parse::duration, s)?;
+    if duration.years != 0 || duration.months != 0 {
+        return Err(PyValueError::new_err(format!("nominal duration: {:?}", s)));
+    }
+
+    let days = u64::from(duration.weeks) * 7 + u64::from(duration.days);
+    let time = time::Duration::try_from(Duration { weeks: 0, days: 0, negative: false, ..duration })
+        .expect("only time components are left");
+    let sign = if duration.negative { -1 } else { 1 };
+    match (i32::try_from(days), i32::try_from(time.as_secs())) {
+        (Ok(days), Ok(seconds)) => PyDelta::new_bound(py, sign * days, sign * seconds, sign * time.subsec_micros() as i32, true),
+        _ => out_of_range(s)
+    }
+}
+
+#[pymodule]
+#[pyo3(name = "iso_8601")]
+fn init(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(parse_date, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_datetime, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_duration, m)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyo3::types::IntoPyDict;
+
+    #[test]
+    fn parse() {
+        Python::with_gil(|py| {
+            let module = PyModule::new_bound(py, "iso_8601").unwrap();
+            super::init(&module).unwrap();
+            let eval = |code: &str| py.eval_bound(code, Some(&[("iso_8601", &module)].into_py_dict_bound(py)), None)
+                .and_then(|x| x.str())
+                .map(|x| x.to_string());
+
+            assert_eq!(eval("iso_8601.parse_date('2018-W31-4')").unwrap(), "2018-08-02");
+            assert_eq!(eval("iso_8601.parse_date('2018-214')").unwrap(),   "2018-08-02");
+            assert_eq!(eval("iso_8601.parse_datetime('2018-08-02T13:42:02.5+02:00')").unwrap(), "2018-08-02 13:42:02.500000+02:00");
+            assert_eq!(eval("iso_8601.parse_datetime('20180802T134202')").unwrap(),             "2018-08-02 13:42:02");
+            assert_eq!(eval("iso_8601.parse_duration('P1DT1.5S')").unwrap(), "1 day, 0:00:01.500000");
+            assert_eq!(eval("iso_8601.parse_duration('-PT1H')").unwrap(),    "-1 day, 23:00:00");
+
+            assert!(eval("iso_8601.parse_date('2018-02-30')").is_err());
+            assert!(eval("iso_8601.parse_date('2018-08-02 ')").is_err());
+            assert!(eval("iso_8601.parse_datetime('2016-12-31T23:59:60Z')").is_err());
+            assert!(eval("iso_8601.parse_duration('P1M')").is_err());
+        });
+    }
+}
@@ -0,0 +1,101 @@
+//! Adjusters that move a date to a day of the week,
+//! as for rules like "the first Monday of the month".
+
+use {
+    Valid,
+    date::*,
+    names::*
+};
+
+impl YmdDate {
+    /// The first date after this one on the given day of the week.
+    ///
+    /// `None` if the date is not valid
+    /// or the result is out of [`MIN`](Self::MIN) to [`MAX`](Self::MAX).
+    pub fn next_weekday(&self, weekday: Weekday) -> Option<Self> {
+        let days = (i64::from(weekday.number()) - i64::from(self.checked_day_of_week()?.number())).rem_euclid(7);
+        self.checked_add_days(if days == 0 { 7 } else { days })
+    }
+
+    /// The last date before this one on the given day of the week,
+    /// see [`next_weekday`](Self::next_weekday).
+    pub fn prev_weekday(&self, weekday: Weekday) -> Option<Self> {
+        let days = (i64::from(self.checked_day_of_week()?.number()) - i64::from(weekday.number())).rem_euclid(7);
+        self.checked_sub_days(if days == 0 { 7 } else { days })
+    }
+
+    /// The `n`th given day of the week in the month of this date,
+    /// counting from the end of the month if `n` is negative,
+    /// so `-1` is the last one.
+    ///
+    /// `None` if the date is not valid, `n` is zero
+    /// or the month does not have that many.
+    pub fn nth_weekday_of_month(&self, n: i8, weekday: Weekday) -> Option<Self> {
+        if !self.is_valid() || n == 0 {
+            return None;
+        }
+
+        let days = days_in_month(self.year, self.month)?;
+        let weekday = weekday.number();
+        let day = if n > 0 {
+            let first = YmdDate { day: 1, ..*self }.weekday();
+            let day = 1 + (weekday + 7 - first) % 7;
+            i16::from(day) + 7 * (i16::from(n) - 1)
+        } else {
+            let last = YmdDate { day: days, ..*self }.weekday();
+            let day = days - (last + 7 - weekday) % 7;
+            i16::from(day) - 7 * (-i16::from(n) - 1)
+        };
+
+        if (1..=i16::from(days)).contains(&day) {
+            Some(YmdDate { day: day as u8, ..*self })
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(day: u8) -> YmdDate {
+        YmdDate { year: 2018, month: 8, day }
+    }
+
+    #[test]
+    fn next_prev_weekday() {
+        // 2018-08-02 is a Thursday
+        assert_eq!(date(2).next_weekday(Weekday::Friday), Some(date(3)));
+        assert_eq!(date(2).next_weekday(Weekday::Thursday), Some(date(9)));
+        assert_eq!(date(2).next_weekday(Weekday::Monday), Some(date(6)));
+        assert_eq!(date(2).prev_weekday(Weekday::Wednesday), Some(date(1)));
+        assert_eq!(date(2).prev_weekday(Weekday::Thursday), Some(YmdDate { month: 7, day: 26, ..date(2) }));
+        assert_eq!(date(2).prev_weekday(Weekday::Friday), Some(YmdDate { month: 7, day: 27, ..date(2) }));
+
+        assert_eq!(date(32).next_weekday(Weekday::Monday), None);
+        let (min, max): (YmdDate, YmdDate) = (YmdDate::MIN, YmdDate::MAX);
+        assert_eq!(max.next_weekday(max.day_of_week().succ()), None);
+        assert_eq!(min.prev_weekday(min.day_of_week().pred()), None);
+    }
+
+    #[test]
+    fn nth_weekday_of_month() {
+        assert_eq!(date(20).nth_weekday_of_month(1, Weekday::Monday), Some(date(6)));
+        assert_eq!(date(20).nth_weekday_of_month(1, Weekday::Wednesday), Some(date(1)));
+        assert_eq!(date(20).nth_weekday_of_month(5, Weekday::Friday), Some(date(31)));
+        assert_eq!(date(20).nth_weekday_of_month(5, Weekday::Saturday), None);
+        assert_eq!(date(20).nth_weekday_of_month(-1, Weekday::Friday), Some(date(31)));
+        assert_eq!(date(20).nth_weekday_of_month(-1, Weekday::Monday), Some(date(27)));
+        assert_eq!(date(20).nth_weekday_of_month(-5, Weekday::Wednesday), Some(date(1)));
+        assert_eq!(date(20).nth_weekday_of_month(-5, Weekday::Tuesday), None);
+        assert_eq!(date(20).nth_weekday_of_month(0, Weekday::Monday), None);
+        assert_eq!(date(32).nth_weekday_of_month(1, Weekday::Monday), None);
+
+        for n in 1..=4 {
+            let day = date(1).nth_weekday_of_month(n, Weekday::Sunday).unwrap();
+            assert_eq!(day.day_of_week(), Weekday::Sunday);
+            assert_eq!(day.nth_weekday_of_month(n - 5, Weekday::Sunday), Some(day));
+        }
+    }
+}
@@ -0,0 +1,70 @@
+#![cfg(feature = "arbitrary")]
+
+//! Arbitrary valid values, for fuzzing and property tests.
+
+extern crate arbitrary;
+
+use {
+    date::*,
+    time::*,
+    datetime::*
+};
+use self::arbitrary::{
+    Arbitrary,
+    Result,
+    Unstructured
+};
+
+impl<'a> Arbitrary<'a> for YmdDate {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let year = u.arbitrary()?;
+        let month = u.int_in_range(1..=12)?;
+        let last = YmdDate { year, month, day: 1 }.last_of_month().day;
+        Ok(YmdDate {
+            year,
+            month,
+            day: u.int_in_range(1..=last)?
+        })
+    }
+}
+
+/// Includes leap seconds.
+impl<'a> Arbitrary<'a> for GlobalTime {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(GlobalTime {
+            local: LocalTime {
+                naive: HmsTime {
+                    hour:   u.int_in_range(0..=23)?,
+                    minute: u.int_in_range(0..=59)?,
+                    second: u.int_in_range(0..=60)?
+                },
+                fraction: f32::from(u.int_in_range(0..=999u16)?) / 1000.
+            },
+            timezone: u.int_in_range(-24 * 60 + 1..=24 * 60 - 1)?
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for DateTime {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(DateTime {
+            date: u.arbitrary()?,
+            time: u.arbitrary()?
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Valid;
+
+    #[test]
+    fn valid() {
+        let bytes: Vec<u8> = (0..4096u32).map(|x| (x.wrapping_mul(2_654_435_761) >> 13) as u8).collect();
+        let mut u = Unstructured::new(&bytes);
+        while !u.is_empty() {
+            assert!(DateTime::arbitrary(&mut u).unwrap().is_valid());
+        }
+    }
+}
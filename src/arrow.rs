@@ -0,0 +1,120 @@
+#![cfg(feature = "arrow")]
+
+//! Conversions to and from [Arrow](https://arrow.apache.org)'s timestamps,
+//! which count units since the Unix epoch in an `i64`.
+
+extern crate arrow_array;
+extern crate arrow_schema;
+
+use {
+    Valid,
+    date::*,
+    time::*,
+    datetime::*,
+    parse::datetime_global_hms,
+    self::arrow_array::{
+        Array,
+        StringArray,
+        TimestampNanosecondArray
+    }
+};
+
+pub use self::arrow_schema::TimeUnit;
+
+fn units_per_sec(unit: &TimeUnit) -> i64 {
+    match *unit {
+        TimeUnit::Second      => 1,
+        TimeUnit::Millisecond => 1_000,
+        TimeUnit::Microsecond => 1_000_000,
+        TimeUnit::Nanosecond  => 1_000_000_000
+    }
+}
+
+/// The timestamp in the given unit, truncating finer digits.
+///
+/// `None` if the datetime is not valid or the timestamp does not fit.
+pub fn to_timestamp<D>(datetime: DateTime<D, GlobalTime>, unit: TimeUnit) -> Option<i64>
+where D: Datelike + Valid + Into<YmdDate> {
+    let (secs, nanos) = datetime.into_unix()?;
+    let per_sec = units_per_sec(&unit);
+    secs.checked_mul(per_sec)?
+        .checked_add(i64::from(nanos) / (1_000_000_000 / per_sec))
+}
+
+/// The datetime in UTC of a timestamp in the given unit.
+///
+/// `None` if the year does not fit.
+pub fn from_timestamp(timestamp: i64, unit: TimeUnit) -> Option<DateTime> {
+    let per_sec = units_per_sec(&unit);
+    DateTime::checked_from_unix(
+        timestamp.div_euclid(per_sec),
+        (timestamp.rem_euclid(per_sec) * (1_000_000_000 / per_sec)) as u32
+    )
+}
+
+/// Parses each string into a nanosecond timestamp in UTC.
+///
+/// Nulls, invalid representations and timestamps that do not fit become null.
+pub fn parse_timestamps(array: &StringArray) -> TimestampNanosecondArray {
+    array.iter()
+        .map(|x| x
+            .and_then(|x| match datetime_global_hms(x.as_bytes()) {
+                Ok(([], datetime)) => Some(datetime),
+                _ => None
+            })
+            .and_then(|x| to_timestamp(x, TimeUnit::Nanosecond)))
+        .collect::<TimestampNanosecondArray>()
+        .with_timezone("+00:00")
+}
+
+/// Formats each nanosecond timestamp as a datetime in UTC.
+///
+/// Nulls and timestamps whose year does not fit become null.
+pub fn format_timestamps(array: &TimestampNanosecondArray) -> StringArray {
+    (0..array.len())
+        .map(|i| if array.is_null(i) {
+            None
+        } else {
+            from_timestamp(array.value(i), TimeUnit::Nanosecond).map(|x| x.to_string())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timestamp() {
+        let datetime: DateTime<Date> = "2018-08-02T15:42:02.5+02:00".parse().unwrap();
+        assert_eq!(to_timestamp(datetime, TimeUnit::Second),      Some(1_533_217_322));
+        assert_eq!(to_timestamp(datetime, TimeUnit::Millisecond), Some(1_533_217_322_500));
+        assert_eq!(to_timestamp(datetime,         TimeUnit::Nanosecond),  Some(1_533_217_322_500_000_000));
+
+        assert_eq!(
+            from_timestamp(1_533_217_322_500, TimeUnit::Millisecond).map(|x| x.to_string()),
+            Some("2018-08-02T13:42:02.5Z".to_string())
+        );
+        assert_eq!(
+            from_timestamp(-1, TimeUnit::Second).map(|x| x.to_string()),
+            Some("1969-12-31T23:59:59Z".to_string())
+        );
+    }
+
+    #[test]
+    fn timestamps() {
+        let array = StringArray::from(vec![
+            Some("2018-08-02T15:42:02+02:00"),
+            Some("2018-02-30T00:00:00Z"),
+            None
+        ]);
+        let timestamps = parse_timestamps(&array);
+        assert_eq!(timestamps.value(0), 1_533_217_322_000_000_000);
+        assert!(timestamps.is_null(1));
+        assert!(timestamps.is_null(2));
+
+        let strings = format_timestamps(&timestamps);
+        assert_eq!(strings.value(0), "2018-08-02T13:42:02Z");
+        assert!(strings.is_null(1));
+    }
+}
@@ -0,0 +1,72 @@
+use {
+    Valid,
+    date::*,
+    time::*,
+    datetime::*
+};
+
+impl<D> DateTime<D, GlobalTime>
+where D: Datelike + Valid + Into<YmdDate> {
+    fn into_utc(self) -> Option<(DateTime, u32)> {
+        let (secs, nanos) = self.into_unix()?;
+        Some((DateTime::checked_from_unix(secs, nanos)?, nanos))
+    }
+
+    /// ASN.1 `GeneralizedTime` in UTC as DER requires it,
+    /// like `19851106210627.3Z`.
+    ///
+    /// `None` if the datetime is not valid
+    /// or its year in UTC does not have four digits.
+    pub fn to_generalized_time(self) -> Option<String> {
+        let (utc, nanos) = self.into_utc()?;
+        if utc.date.year < 0 || utc.date.year > 9999 {
+            return None;
+        }
+
+        let fraction = format!("{:09}", nanos);
+        let fraction = fraction.trim_end_matches('0');
+        let time = &utc.time.local.naive;
+        Some(format!(
+            "{:04}{:02}{:02}{:02}{:02}{:02}{}{}Z",
+            utc.date.year, utc.date.month, utc.date.day,
+            time.hour, time.minute, time.second,
+            if fraction.is_empty() { "" } else { "." }, fraction
+        ))
+    }
+
+    /// ASN.1 `UTCTime` in UTC with seconds as DER requires it,
+    /// like `851106210627Z`.
+    ///
+    /// `None` if the datetime is not valid
+    /// or its year in UTC is outside 1950 to 2049.
+    /// The fraction is dropped.
+    pub fn to_utc_time(self) -> Option<String> {
+        let (utc, _) = self.into_utc()?;
+        if utc.date.year < 1950 || utc.date.year > 2049 {
+            return None;
+        }
+
+        let time = &utc.time.local.naive;
+        Some(format!(
+            "{:02}{:02}{:02}{:02}{:02}{:02}Z",
+            utc.date.year % 100, utc.date.month, utc.date.day,
+            time.hour, time.minute, time.second
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_generalized_time() {
+        let datetime: DateTime<Date> = "1985-11-06T22:06:27.3+01:00".parse().unwrap();
+        assert_eq!(datetime.to_generalized_time(), Some("19851106210627.3Z".to_string()));
+        assert_eq!(datetime.to_utc_time(), Some("851106210627Z".to_string()));
+
+        let datetime: DateTime<Date> = "2050-01-01T00:00:00Z".parse().unwrap();
+        assert_eq!(datetime.to_generalized_time(), Some("20500101000000Z".to_string()));
+        assert_eq!(datetime.to_utc_time(), None);
+    }
+}
@@ -0,0 +1,150 @@
+#![cfg(feature = "bench-input")]
+
+//! Reproducible corpora of representations to benchmark against.
+
+/// Generates pseudo-random representations from a seed.
+///
+/// Uses xorshift so that the same seed always yields the same corpus.
+pub struct Generator(u64);
+
+impl Generator {
+    pub fn new(seed: u64) -> Self {
+        // xorshift gets stuck at zero
+        Generator(seed | 1)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+
+    fn offset(&mut self) -> String {
+        match self.below(3) {
+            0 => "Z".to_string(),
+            1 => format!("+{:02}:{:02}", self.below(15), self.below(4) * 15),
+            _ => format!("-{:02}:{:02}", self.below(13), self.below(4) * 15)
+        }
+    }
+
+    fn fraction(&mut self) -> String {
+        match self.below(3) {
+            0 => String::new(),
+            1 => format!(".{:03}", self.below(1_000)),
+            _ => format!(".{:09}", self.below(1_000_000_000))
+        }
+    }
+
+    /// A complete date in the calendar, week or ordinal representation,
+    /// in the basic or extended format.
+    pub fn date(&mut self) -> String {
+        let year = 1900 + self.below(200);
+        let extended = self.below(2) == 0;
+        let sep = if extended { "-" } else { "" };
+        match self.below(3) {
+            0 => format!("{:04}{}{:02}{}{:02}", year, sep, 1 + self.below(12), sep, 1 + self.below(28)),
+            1 => format!("{:04}{}W{:02}{}{}", year, sep, 1 + self.below(52), sep, 1 + self.below(7)),
+            _ => format!("{:04}{}{:03}", year, sep, 1 + self.below(365))
+        }
+    }
+
+    /// A time of day with seconds and a difference from UTC,
+    /// in the basic or extended format.
+    pub fn time(&mut self) -> String {
+        let (hour, minute, second) = (self.below(24), self.below(60), self.below(60));
+        let fraction = self.fraction();
+        let offset = self.offset();
+        if self.below(2) == 0 {
+            format!("{:02}:{:02}:{:02}{}{}", hour, minute, second, fraction, offset)
+        } else {
+            format!("{:02}{:02}{:02}{}{}", hour, minute, second, fraction, offset.replace(':', ""))
+        }
+    }
+
+    /// A date and time of any of the representations
+    /// that [`date`](Self::date) and [`time`](Self::time) generate.
+    pub fn datetime(&mut self) -> String {
+        format!("{}T{}", self.date(), self.time())
+    }
+
+    /// A date and time in the shape of RFC 3339,
+    /// `YYYY-MM-DDThh:mm:ss[.f](Z|±hh:mm)`.
+    pub fn rfc3339(&mut self) -> String {
+        format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}{}{}",
+            1900 + self.below(200), 1 + self.below(12), 1 + self.below(28),
+            self.below(24), self.below(60), self.below(60),
+            self.fraction(), self.offset()
+        )
+    }
+
+    /// A duration in the format with designators.
+    pub fn duration(&mut self) -> String {
+        if self.below(4) == 0 {
+            return format!("P{}W", 1 + self.below(52));
+        }
+        format!(
+            "P{}Y{}M{}DT{}H{}M{}S",
+            self.below(10), self.below(12), self.below(31),
+            self.below(24), self.below(60), self.below(60)
+        )
+    }
+
+    /// A time interval of any of the four kinds.
+    pub fn interval(&mut self) -> String {
+        match self.below(4) {
+            0 => format!("{}/{}", self.rfc3339(), self.rfc3339()),
+            1 => format!("{}/{}", self.rfc3339(), self.duration()),
+            2 => format!("{}/{}", self.duration(), self.rfc3339()),
+            _ => self.duration()
+        }
+    }
+}
+
+/// Collects `n` representations from a generator seeded with `seed`.
+pub fn corpus<F>(n: usize, seed: u64, mut f: F) -> Vec<String>
+where F: FnMut(&mut Generator) -> String {
+    let mut generator = Generator::new(seed);
+    (0..n).map(|_| f(&mut generator)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::{
+        date::*,
+        time::*,
+        datetime::*,
+        duration::*,
+        interval::*
+    };
+
+    #[test]
+    fn corpus() {
+        assert_eq!(super::corpus(8, 42, Generator::datetime), super::corpus(8, 42, Generator::datetime));
+
+        for x in super::corpus(64, 42, Generator::date) {
+            assert!(x.parse::<Date>().is_ok(), "{}", x);
+        }
+        for x in super::corpus(64, 42, Generator::time) {
+            assert!(x.parse::<GlobalTime>().is_ok(), "{}", x);
+        }
+        for x in super::corpus(64, 42, Generator::datetime) {
+            assert!(x.parse::<DateTime<Date>>().is_ok(), "{}", x);
+        }
+        for x in super::corpus(64, 42, Generator::rfc3339) {
+            assert!(x.parse::<DateTime<Date>>().is_ok(), "{}", x);
+        }
+        for x in super::corpus(64, 42, Generator::duration) {
+            assert!(x.parse::<Duration>().is_ok(), "{}", x);
+        }
+        for x in super::corpus(64, 42, Generator::interval) {
+            assert!(x.parse::<Interval<DateTime<Date>>>().is_ok(), "{}", x);
+        }
+    }
+}
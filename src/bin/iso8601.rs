@@ -0,0 +1,110 @@
+//! Reads timestamps from stdin, one per line,
+//! and validates, normalizes or converts them.
+
+extern crate iso_8601;
+
+use {
+    iso_8601::*,
+    std::{
+        convert::TryFrom,
+        env,
+        io::{
+            self,
+            BufRead
+        },
+        process,
+        time::SystemTime
+    }
+};
+
+const USAGE: &str = "\
+usage: iso8601 <command>
+
+Reads a date and time per line from stdin.
+
+commands:
+    validate        print the grammar each line conforms to
+    utc             normalize to UTC
+    ymd             convert to a calendar date
+    week            convert to a week date
+    ordinal         convert to an ordinal date
+    format PATTERN  reformat with a strftime-like pattern";
+
+enum Command {
+    Validate,
+    Utc,
+    Ymd,
+    Week,
+    Ordinal,
+    Format(String)
+}
+
+fn main() {
+    let mut args = env::args().skip(1);
+    let command = match (args.next().as_deref(), args.next()) {
+        (Some("validate"), None)         => Command::Validate,
+        (Some("utc"), None)              => Command::Utc,
+        (Some("ymd"), None)              => Command::Ymd,
+        (Some("week"), None)             => Command::Week,
+        (Some("ordinal"), None)          => Command::Ordinal,
+        (Some("format"), Some(pattern))  => Command::Format(pattern),
+        _ => {
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        }
+    };
+
+    let mut failed = false;
+    for line in io::stdin().lock().lines() {
+        let line = line.unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            process::exit(1);
+        });
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        match run(&command, line) {
+            Ok(output) => println!("{}", output),
+            Err(message) => {
+                eprint!("{}", message);
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn run(command: &Command, line: &str) -> Result<String, String> {
+    let (datetime, conformance) = DateTime::parse_best(line)
+        .map_err(|e| e.annotate(line).to_string())?;
+    if !datetime.is_valid() {
+        return Err(format!("{}\nnot a valid date and time\n", line));
+    }
+
+    Ok(match command {
+        Command::Validate => format!("{:?}", conformance),
+        Command::Utc => match datetime.time {
+            AnyTime::Global(time) => {
+                let instant = SystemTime::try_from(DateTime { date: datetime.date, time })
+                    .map_err(|_| format!("{}\nout of range\n", line))?;
+                DateTime::from(instant).to_string()
+            }
+            AnyTime::Local(_) => return Err(format!("{}\nno offset from UTC to normalize\n", line))
+        },
+        Command::Ymd     => DateTime { date: YmdDate::from(datetime.date), time: datetime.time }.to_string(),
+        Command::Week    => DateTime { date: WdDate::from(datetime.date),  time: datetime.time }.to_string(),
+        Command::Ordinal => DateTime { date: ODate::from(datetime.date),   time: datetime.time }.to_string(),
+        Command::Format(pattern) => {
+            let mut output = String::new();
+            use std::fmt::Write;
+            write!(output, "{}", datetime.format_with(pattern))
+                .map_err(|_| format!("{}\npattern needs a component that is missing\n", line))?;
+            output
+        }
+    })
+}
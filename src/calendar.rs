@@ -0,0 +1,239 @@
+#![cfg(feature = "calendar-extras")]
+
+//! Dates that follow from the Gregorian calendar alone,
+//! as needed for holidays and business days.
+
+use {
+    Valid,
+    Weekday,
+    date::*,
+    std::convert::TryFrom
+};
+
+/// Easter Sunday of the given year,
+/// by the anonymous Gregorian computus.
+pub fn easter(year: i16) -> YmdDate {
+    let y = i32::from(year);
+    let a = y.rem_euclid(19);
+    let (b, c) = (y.div_euclid(100), y.rem_euclid(100));
+    let (d, e) = (b.div_euclid(4), b.rem_euclid(4));
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15).rem_euclid(30);
+    let (i, k) = (c / 4, c % 4);
+    let l = (32 + 2 * e + 2 * i - h - k).rem_euclid(7);
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    YmdDate {
+        year,
+        month: month as u8,
+        day: day as u8
+    }
+}
+
+/// Day of the week from 1 (Monday) to 7 (Sunday).
+pub fn weekday(date: &YmdDate) -> u8 {
+    (days_from_civil(date.year.into(), date.month, date.day) + 3).rem_euclid(7) as u8 + 1
+}
+
+/// The `n`th occurrence of the weekday (1 for Monday to 7 for Sunday) in the month,
+/// counting from its end if `n` is negative,
+/// so `-1` is the last one.
+///
+/// `None` if the month has no such occurrence or the arguments are not valid,
+/// see [`YmdDate::nth_weekday_of_month`].
+pub fn nth_weekday_of_month(year: i16, month: u8, weekday: u8, n: i8) -> Option<YmdDate> {
+    YmdDate { year, month, day: 1 }.nth_weekday_of_month(n, Weekday::try_from(weekday).ok()?)
+}
+
+/// A fiscal year of twelve calendar months starting in the given month,
+/// named after the calendar year in which it ends,
+/// so that the fiscal year 2025 starting in October begins on 2024-10-01.
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub struct FiscalYear {
+    /// From 1 for the calendar year to 12
+    pub start_month: u8
+}
+
+impl FiscalYear {
+    /// Only meaningful if the start month and the month of the date are valid.
+    pub fn year(&self, date: &YmdDate) -> i32 {
+        let year = i32::from(date.year);
+        if self.start_month > 1 && date.month >= self.start_month { year + 1 } else { year }
+    }
+
+    /// Month of the fiscal year from 1 to 12, see [`year`](Self::year).
+    pub fn month(&self, date: &YmdDate) -> u8 {
+        (date.month + 12 - self.start_month) % 12 + 1
+    }
+
+    /// Quarter of the fiscal year from 1 to 4, see [`year`](Self::year).
+    pub fn quarter(&self, date: &YmdDate) -> u8 {
+        (self.month(date) - 1) / 3 + 1
+    }
+
+    /// The day that the fiscal year begins on,
+    /// `None` if it is not in the range of [`YmdDate`].
+    pub fn first_day(&self, year: i32) -> Option<YmdDate> {
+        let year = if self.start_month > 1 { year - 1 } else { year };
+        Some(YmdDate {
+            year: i16::try_from(year).ok()?,
+            month: self.start_month,
+            day: 1
+        })
+    }
+}
+
+impl Valid for FiscalYear {
+    fn is_valid(&self) -> bool {
+        (1..=12).contains(&self.start_month)
+    }
+}
+
+/// Days on which business is not done, for settlement dates and deadlines.
+pub trait HolidayCalendar {
+    /// Whether the date is a holiday apart from the weekend.
+    fn is_holiday(&self, date: &YmdDate) -> bool;
+
+    /// Saturday and Sunday by default.
+    fn is_weekend(&self, date: &YmdDate) -> bool {
+        matches!(date.checked_day_of_week(), Some(Weekday::Saturday) | Some(Weekday::Sunday))
+    }
+
+    fn is_business_day(&self, date: &YmdDate) -> bool {
+        !self.is_weekend(date) && !self.is_holiday(date)
+    }
+}
+
+/// A calendar without holidays apart from the weekend.
+#[derive(Eq, PartialEq, Clone, Copy, Default, Debug)]
+pub struct WeekendsOnly;
+
+impl HolidayCalendar for WeekendsOnly {
+    fn is_holiday(&self, _: &YmdDate) -> bool {
+        false
+    }
+}
+
+/// The listed dates are the holidays.
+impl HolidayCalendar for [YmdDate] {
+    fn is_holiday(&self, date: &YmdDate) -> bool {
+        self.contains(date)
+    }
+}
+
+impl YmdDate {
+    /// The `n`th business day after this date, or before it if `n` is negative.
+    /// Zero days leave the date as is, even if it is not a business day.
+    ///
+    /// `None` if the date is not valid
+    /// or the result is out of [`MIN`](Self::MIN) to [`MAX`](Self::MAX).
+    pub fn add_business_days<C>(&self, n: i64, calendar: &C) -> Option<Self>
+    where C: HolidayCalendar + ?Sized {
+        if !self.is_valid() {
+            return None;
+        }
+
+        let step = n.signum();
+        let mut rata_die = self.rata_die();
+        let mut date = *self;
+        for _ in 0..n.unsigned_abs() {
+            loop {
+                rata_die += step;
+                date = Self::from_rata_die(rata_die)?;
+                if calendar.is_business_day(&date) {
+                    break;
+                }
+            }
+        }
+        Some(date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn easter() {
+        for &(year, month, day) in &[
+            (1818, 3, 22),
+            (1961, 4,  2),
+            (2000, 4, 23),
+            (2019, 4, 21),
+            (2024, 3, 31),
+            (2038, 4, 25)
+        ] {
+            assert_eq!(super::easter(year), YmdDate { year, month, day });
+        }
+    }
+
+    #[test]
+    fn business_days() {
+        // 2018-08-02 is a Thursday
+        let date = |day| YmdDate { year: 2018, month: 8, day };
+        assert_eq!(date(2).add_business_days(1, &WeekendsOnly), Some(date(3)));
+        assert_eq!(date(2).add_business_days(2, &WeekendsOnly), Some(date(6)));
+        assert_eq!(date(2).add_business_days(7, &WeekendsOnly), Some(date(13)));
+        assert_eq!(date(6).add_business_days(-1, &WeekendsOnly), Some(date(3)));
+        assert_eq!(date(4).add_business_days(0, &WeekendsOnly), Some(date(4)));
+        assert_eq!(date(4).add_business_days(1, &WeekendsOnly), Some(date(6)));
+        assert_eq!(date(4).add_business_days(-1, &WeekendsOnly), Some(date(3)));
+        assert_eq!(date(32).add_business_days(1, &WeekendsOnly), None);
+
+        let holidays = [date(3), date(6)];
+        assert_eq!(date(2).add_business_days(1, &holidays[..]), Some(date(7)));
+        assert_eq!(date(7).add_business_days(-1, &holidays[..]), Some(date(2)));
+        assert!(!holidays[..].is_business_day(&date(3)));
+        assert!(holidays[..].is_business_day(&date(2)));
+
+        let max: YmdDate = YmdDate::MAX;
+        assert_eq!(max.add_business_days(1, &WeekendsOnly), None);
+    }
+
+    #[test]
+    fn fiscal_year() {
+        let date = |month, day| YmdDate { year: 2024, month, day };
+
+        // US federal
+        let fy = FiscalYear { start_month: 10 };
+        assert_eq!(fy.year(&date(9, 30)), 2024);
+        assert_eq!(fy.year(&date(10, 1)), 2025);
+        assert_eq!(fy.quarter(&date(10, 1)), 1);
+        assert_eq!(fy.quarter(&date(9, 30)), 4);
+        assert_eq!(fy.month(&date(1, 15)), 4);
+        assert_eq!(fy.first_day(2025), Some(date(10, 1)));
+
+        // UK, Japan and India
+        let fy = FiscalYear { start_month: 4 };
+        assert_eq!(fy.year(&date(3, 31)), 2024);
+        assert_eq!(fy.year(&date(4, 1)), 2025);
+        assert_eq!(fy.quarter(&date(7, 1)), 2);
+        assert_eq!(fy.month(&date(3, 31)), 12);
+
+        let fy = FiscalYear { start_month: 1 };
+        assert_eq!(fy.year(&date(12, 31)), 2024);
+        assert_eq!(fy.quarter(&date(12, 31)), 4);
+        assert_eq!(fy.month(&date(12, 31)), 12);
+        assert_eq!(fy.first_day(2024), Some(date(1, 1)));
+        assert_eq!(fy.first_day(40_000), None);
+
+        assert!(!FiscalYear { start_month: 0 }.is_valid());
+        assert!(!FiscalYear { start_month: 13 }.is_valid());
+    }
+
+    #[test]
+    fn nth_weekday_of_month() {
+        // US Thanksgiving, fourth Thursday of November
+        assert_eq!(super::nth_weekday_of_month(2024, 11, 4, 4), Some(YmdDate { year: 2024, month: 11, day: 28 }));
+        // US Memorial Day, last Monday of May
+        assert_eq!(super::nth_weekday_of_month(2024, 5, 1, -1), Some(YmdDate { year: 2024, month: 5, day: 27 }));
+        assert_eq!(super::nth_weekday_of_month(2024, 2, 4, 5), Some(YmdDate { year: 2024, month: 2, day: 29 }));
+        assert_eq!(super::nth_weekday_of_month(2023, 2, 4, 5), None);
+        assert_eq!(super::nth_weekday_of_month(2023, 2, 4, -5), None);
+        assert_eq!(super::nth_weekday_of_month(2023, 13, 4, 1), None);
+        assert_eq!(super::nth_weekday_of_month(2023, 2, 0, 1), None);
+        assert_eq!(super::nth_weekday_of_month(2023, 2, 1, i8::MIN), None);
+    }
+}
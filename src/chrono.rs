@@ -2,106 +2,198 @@
 
 extern crate chrono;
 
-use self::chrono::prelude::*;
+use self::chrono::{
+    prelude::*,
+    Duration
+};
+use std::{
+    convert::{
+        TryFrom,
+        TryInto
+    },
+    error,
+    fmt,
+    time
+};
+use Valid;
 
-impl From<::DateTime<::Date, ::GlobalTime>> for DateTime<FixedOffset> {
-    fn from(dt: ::DateTime<::Date, ::GlobalTime>) -> Self {
-        let date: ::YmdDate = dt.date.into();
+/// Why a date and time has no equivalent in chrono.
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub enum Error {
+    /// The date is not valid,
+    /// such as week 53 of a year with 52 weeks or day 366 of a common year.
+    InvalidDate,
+    /// The time of day is not valid or is the end of the day, 24:00.
+    /// Leap seconds are kept.
+    InvalidTime,
+    /// The offset from UTC is a day or more.
+    InvalidOffset,
+    /// The result lies outside of chrono's range of years.
+    OutOfRange,
+    /// The local time is skipped or repeated in the local time zone.
+    AmbiguousLocalTime
+}
 
-        FixedOffset::east((dt.time.timezone * 60).into())
-            .ymd(
-                date.year.into(),
-                date.month.into(),
-                date.day.into()
-            ).and_hms_nano(
-                dt.time.local.naive.hour.into(),
-                dt.time.local.naive.minute.into(),
-                dt.time.local.naive.second.into(),
-                dt.time.local.nanosecond()
-            )
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Error::InvalidDate        => "invalid date",
+            Error::InvalidTime        => "invalid time of day",
+            Error::InvalidOffset      => "invalid offset from UTC",
+            Error::OutOfRange         => "out of range",
+            Error::AmbiguousLocalTime => "local time does not exist or is ambiguous"
+        })
     }
 }
 
-impl From<::DateTime<::Date, ::GlobalTime>> for DateTime<Utc> {
-    fn from(dt: ::DateTime<::Date, ::GlobalTime>) -> Self {
-        DateTime::<FixedOffset>::from(dt)
-            .with_timezone(&Utc)
+impl error::Error for Error {}
+
+fn naive(date: ::Date, time: ::LocalTime) -> Result<NaiveDateTime, Error> {
+    if !date.is_valid() {
+        return Err(Error::InvalidDate);
     }
+    let date: ::YmdDate = date.into();
+    let date = NaiveDate::from_ymd_opt(date.year.into(), date.month.into(), date.day.into())
+        .ok_or(Error::OutOfRange)?;
+
+    // chrono counts a leap second as the second before it, past one billion nanoseconds
+    let (second, leap) = match time.naive.second {
+        60 => (59, 1_000_000_000),
+        second => (second, 0)
+    };
+    let time = NaiveTime::from_hms_nano_opt(
+        time.naive.hour.into(),
+        time.naive.minute.into(),
+        second.into(),
+        time.nanosecond() + leap
+    ).ok_or(Error::InvalidTime)?;
+
+    Ok(date.and_time(time))
 }
 
-impl From<::DateTime<::Date, ::GlobalTime>> for DateTime<Local> {
-    fn from(dt: ::DateTime<::Date, ::GlobalTime>) -> Self {
-        DateTime::<FixedOffset>::from(dt)
-            .with_timezone(&Local)
+impl TryFrom<::DateTime<::Date, ::GlobalTime>> for DateTime<FixedOffset> {
+    type Error = Error;
+
+    fn try_from(dt: ::DateTime<::Date, ::GlobalTime>) -> Result<Self, Self::Error> {
+        let naive = naive(dt.date, dt.time.local)?;
+        FixedOffset::east_opt(i32::from(dt.time.timezone) * 60)
+            .ok_or(Error::InvalidOffset)?
+            .from_local_datetime(&naive)
+            .single()
+            .ok_or(Error::OutOfRange)
     }
 }
 
-impl From<::DateTime<::Date, ::LocalTime>> for DateTime<FixedOffset> {
-    fn from(dt: ::DateTime<::Date, ::LocalTime>) -> Self {
-        DateTime::<Local>::from(dt)
-            .with_timezone(&Utc.fix())
+impl TryFrom<::DateTime<::Date, ::GlobalTime>> for DateTime<Utc> {
+    type Error = Error;
+
+    fn try_from(dt: ::DateTime<::Date, ::GlobalTime>) -> Result<Self, Self::Error> {
+        DateTime::<FixedOffset>::try_from(dt)
+            .map(|dt| dt.with_timezone(&Utc))
     }
 }
 
-impl From<::DateTime<::Date, ::LocalTime>> for DateTime<Utc> {
-    fn from(dt: ::DateTime<::Date, ::LocalTime>) -> Self {
-        DateTime::<Local>::from(dt)
-            .with_timezone(&Utc)
+impl TryFrom<::DateTime<::Date, ::GlobalTime>> for DateTime<Local> {
+    type Error = Error;
+
+    fn try_from(dt: ::DateTime<::Date, ::GlobalTime>) -> Result<Self, Self::Error> {
+        DateTime::<FixedOffset>::try_from(dt)
+            .map(|dt| dt.with_timezone(&Local))
     }
 }
 
-impl From<::DateTime<::Date, ::LocalTime>> for DateTime<Local> {
-    fn from(dt: ::DateTime<::Date, ::LocalTime>) -> Self {
-        let date: ::YmdDate = dt.date.into();
+impl TryFrom<::DateTime<::Date, ::LocalTime>> for DateTime<FixedOffset> {
+    type Error = Error;
 
-        Local.from_local_datetime(
-            &NaiveDate::from_ymd(
-                date.year.into(),
-                date.month.into(),
-                date.day.into()
-            ).and_hms_nano(
-                dt.time.naive.hour.into(),
-                dt.time.naive.minute.into(),
-                dt.time.naive.second.into(),
-                dt.time.nanosecond()
-            )
-        ).single().unwrap()
+    fn try_from(dt: ::DateTime<::Date, ::LocalTime>) -> Result<Self, Self::Error> {
+        DateTime::<Local>::try_from(dt)
+            .map(|dt| dt.with_timezone(&Utc.fix()))
     }
 }
 
-impl From<::DateTime<::Date, ::AnyTime>> for DateTime<FixedOffset> {
-    fn from(dt: ::DateTime<::Date, ::AnyTime>) -> Self {
-        DateTime::<Local>::from(dt)
-            .with_timezone(&Utc.fix())
+impl TryFrom<::DateTime<::Date, ::LocalTime>> for DateTime<Utc> {
+    type Error = Error;
+
+    fn try_from(dt: ::DateTime<::Date, ::LocalTime>) -> Result<Self, Self::Error> {
+        DateTime::<Local>::try_from(dt)
+            .map(|dt| dt.with_timezone(&Utc))
     }
 }
 
-impl From<::DateTime<::Date, ::AnyTime>> for DateTime<Utc> {
-    fn from(dt: ::DateTime<::Date, ::AnyTime>) -> Self {
-        DateTime::<Local>::from(dt)
-            .with_timezone(&Utc)
+/// Reads the time in the local time zone.
+impl TryFrom<::DateTime<::Date, ::LocalTime>> for DateTime<Local> {
+    type Error = Error;
+
+    fn try_from(dt: ::DateTime<::Date, ::LocalTime>) -> Result<Self, Self::Error> {
+        Local.from_local_datetime(&naive(dt.date, dt.time)?)
+            .single()
+            .ok_or(Error::AmbiguousLocalTime)
     }
 }
 
-impl From<::DateTime<::Date, ::AnyTime>> for DateTime<Local> {
-    fn from(dt: ::DateTime<::Date, ::AnyTime>) -> Self {
+impl TryFrom<::DateTime<::Date, ::AnyTime>> for DateTime<FixedOffset> {
+    type Error = Error;
+
+    fn try_from(dt: ::DateTime<::Date, ::AnyTime>) -> Result<Self, Self::Error> {
+        DateTime::<Local>::try_from(dt)
+            .map(|dt| dt.with_timezone(&Utc.fix()))
+    }
+}
+
+impl TryFrom<::DateTime<::Date, ::AnyTime>> for DateTime<Utc> {
+    type Error = Error;
+
+    fn try_from(dt: ::DateTime<::Date, ::AnyTime>) -> Result<Self, Self::Error> {
+        DateTime::<Local>::try_from(dt)
+            .map(|dt| dt.with_timezone(&Utc))
+    }
+}
+
+impl TryFrom<::DateTime<::Date, ::AnyTime>> for DateTime<Local> {
+    type Error = Error;
+
+    fn try_from(dt: ::DateTime<::Date, ::AnyTime>) -> Result<Self, Self::Error> {
         match dt.time {
             ::AnyTime::Global(time) => {
                 ::DateTime {
                     date: dt.date,
                     time
-                }.into()
+                }.try_into()
             }
             ::AnyTime::Local(time) => {
                 ::DateTime {
                     date: dt.date,
                     time
-                }.into()
+                }.try_into()
             }
         }
     }
 }
 
+impl TryFrom<::Duration> for Duration {
+    type Error = ();
+
+    /// Fails if there are nominal components or on overflow.
+    fn try_from(duration: ::Duration) -> Result<Self, Self::Error> {
+        let magnitude = Duration::from_std(time::Duration::try_from(::Duration {
+            negative: false,
+            ..duration
+        })?).or(Err(()))?;
+        Ok(if duration.negative { -magnitude } else { magnitude })
+    }
+}
+
+impl TryFrom<Duration> for ::Duration {
+    type Error = ();
+
+    /// The result is normalized.
+    /// Fails if the hours overflow.
+    fn try_from(duration: Duration) -> Result<Self, Self::Error> {
+        let magnitude = ::Duration::try_from(duration.abs().to_std().or(Err(()))?)?;
+        Ok(if duration < Duration::zero() { -magnitude } else { magnitude })
+    }
+}
+
 #[cfg(feature = "chrono-serde")]
 pub mod serde {
     extern crate serde;
@@ -110,6 +202,10 @@ pub mod serde {
         Deserialize,
         Deserializer
     };
+    use std::{
+        convert::TryFrom,
+        fmt::Display
+    };
     use super::{
         DateTime,
         TimeZone
@@ -119,12 +215,44 @@ pub mod serde {
     pub fn deserialize_DateTime<'de, D, Tz>(de: D) -> Result<DateTime<Tz>, D::Error> where
         D: Deserializer<'de>,
         Tz: TimeZone,
-        DateTime<Tz>: From<::DateTime<::ApproxDate, ::ApproxAnyTime>>
+        DateTime<Tz>: TryFrom<::DateTime<::ApproxDate, ::ApproxAnyTime>>,
+        <DateTime<Tz> as TryFrom<::DateTime<::ApproxDate, ::ApproxAnyTime>>>::Error: Display
     {
-        Ok(
-            ::parse::datetime_approx_any_approx(String::deserialize(de)?.as_bytes())
-                .map_err(serde::de::Error::custom)?.1
-                .into()
-        )
+        let dt = ::parse::datetime_approx_any_approx(String::deserialize(de)?.as_bytes())
+            .map_err(serde::de::Error::custom)?.1;
+        DateTime::try_from(dt).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn datetime() {
+        let dt = |s: &str| DateTime::<Utc>::try_from(s.parse::<::DateTime<::Date, ::GlobalTime>>().unwrap());
+
+        assert_eq!(dt("2018-08-02T13:42:02.5+02:00"), Ok(Utc.with_ymd_and_hms(2018, 8, 2, 11, 42, 2).unwrap() + Duration::milliseconds(500)));
+        assert_eq!(dt("2018-W31-4T13:42:02Z"),        Ok(Utc.with_ymd_and_hms(2018, 8, 2, 13, 42, 2).unwrap()));
+        assert_eq!(dt("2016-12-31T23:59:60Z").map(|x| x.nanosecond()), Ok(1_000_000_000));
+
+        assert_eq!(dt("2018-02-29T13:42:02Z"), Err(Error::InvalidDate));
+        assert_eq!(dt("2021-W53-1T13:42:02Z"), Err(Error::InvalidDate));
+        assert_eq!(dt("2018-366T13:42:02Z"),   Err(Error::InvalidDate));
+        assert_eq!(dt("2018-08-02T24:00:00Z"), Err(Error::InvalidTime));
+        assert_eq!(dt("2018-08-02T13:42:02+24:00"), Err(Error::InvalidOffset));
+    }
+
+    #[test]
+    fn duration() {
+        let duration: ::Duration = "PT1H30M0.5S".parse().unwrap();
+        let chrono = Duration::milliseconds(5_400_500);
+        assert_eq!(Duration::try_from(duration.clone()), Ok(chrono));
+        assert_eq!(::Duration::try_from(chrono), Ok(duration.clone()));
+
+        assert_eq!(Duration::try_from(-duration.clone()), Ok(-chrono));
+        assert_eq!(::Duration::try_from(-chrono), Ok(-duration));
+
+        assert_eq!(Duration::try_from("P1D".parse::<::Duration>().unwrap()), Err(()));
     }
 }
@@ -0,0 +1,70 @@
+use {
+    datetime::*,
+    std::time::SystemTime
+};
+
+/// A source of the current time.
+pub trait Clock {
+    fn now(&self) -> DateTime;
+}
+
+/// Reads the time from [`SystemTime`].
+#[derive(Eq, PartialEq, Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime {
+        SystemTime::now().into()
+    }
+}
+
+/// Reads the time from JavaScript's `Date.now()`
+/// for use in browsers where [`SystemTime`] is unavailable.
+#[cfg(feature = "wasm")]
+#[derive(Eq, PartialEq, Clone, Copy, Debug, Default)]
+pub struct JsClock;
+
+#[cfg(feature = "wasm")]
+impl Clock for JsClock {
+    fn now(&self) -> DateTime {
+        extern crate js_sys;
+
+        let millis = js_sys::Date::now() as i64;
+        DateTime::from_unix(
+            millis.div_euclid(1000),
+            millis.rem_euclid(1000) as u32 * 1_000_000
+        )
+    }
+}
+
+/// Always returns the same time, useful for tests.
+#[derive(PartialEq, Clone, Debug)]
+pub struct FixedClock(pub DateTime);
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime {
+        self.0
+    }
+}
+
+impl DateTime {
+    /// The current time in UTC as read from the given clock.
+    pub fn now_with(clock: &impl Clock) -> Self {
+        clock.now()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{
+        Duration,
+        UNIX_EPOCH
+    };
+
+    #[test]
+    fn now_with() {
+        let now = DateTime::from(UNIX_EPOCH + Duration::from_secs(1_533_217_322));
+        assert_eq!(DateTime::now_with(&FixedClock(now)), now);
+    }
+}
@@ -1,10 +1,20 @@
 use {
     Valid,
-    std::convert::From
+    Month,
+    Weekday,
+    Era,
+    std::{
+        cmp::Ordering,
+        ops::RangeInclusive,
+        convert::{
+            From,
+            TryFrom
+        }
+    }
 };
 
 /// Complete date representations
-#[derive(Eq, PartialEq, Clone, Debug)]
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
 pub enum Date<Y: Year = i16> {
     YMD(YmdDate<Y>),
     WD(WdDate<Y>),
@@ -12,7 +22,7 @@ pub enum Date<Y: Year = i16> {
 }
 
 /// Date representations with reduced accuracy
-#[derive(Eq, PartialEq, Clone, Debug)]
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
 pub enum ApproxDate<Y: Year = i16> {
     YMD(YmdDate<Y>),
     YM(YmDate<Y>),
@@ -24,7 +34,7 @@ pub enum ApproxDate<Y: Year = i16> {
 }
 
 /// Calendar date (4.1.2.2)
-#[derive(Eq, PartialEq, Clone, Debug)]
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
 pub struct YmdDate<Y: Year = i16> {
     pub year: Y,
     pub month: u8,
@@ -32,47 +42,71 @@ pub struct YmdDate<Y: Year = i16> {
 }
 
 /// A specific month (4.1.2.3a)
-#[derive(Eq, PartialEq, Clone, Debug)]
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
 pub struct YmDate<Y: Year = i16> {
     pub year: Y,
     pub month: u8
 }
 
 /// A specific year (4.1.2.3b)
-#[derive(Eq, PartialEq, Clone, Debug)]
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
 pub struct YDate<Y: Year = i16> {
     pub year: Y
 }
 
 // TODO support expanded century
 /// A specific century (4.1.2.3c)
-#[derive(Eq, PartialEq, Clone, Debug)]
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
 pub struct CDate {
     pub century: i8
 }
 
 /// Week date (4.1.4.2)
-#[derive(Eq, PartialEq, Clone, Debug)]
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
 pub struct WdDate<Y: Year = i16> {
+    /// Week-based year, which differs from the calendar year
+    /// for days around January 1 that belong to a week
+    /// mostly in the other year (2.2.10)
     pub year: Y,
     pub week: u8,
     pub day: u8
 }
 
 /// A specific week (4.1.4.3)
-#[derive(Eq, PartialEq, Clone, Debug)]
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
 pub struct WDate<Y: Year = i16> {
+    /// Week-based year, see [`WdDate::year`]
     pub year: Y,
     pub week: u8
 }
 
 /// Ordinal date (4.1.3)
-#[derive(Eq, PartialEq, Clone, Debug)]
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
 pub struct ODate<Y: Year = i16> {
     pub year: Y,
     pub day: u16
 }
 
+/// A day of the year without the year (ISO 8601:2000 5.2.2.4),
+/// as in satellite two-line elements and meteorological bulletins.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Debug)]
+pub struct DayOfYear(pub u16);
+
+impl DayOfYear {
+    pub fn with_year<Y: Year>(self, year: Y) -> ODate<Y> {
+        ODate {
+            year,
+            day: self.0
+        }
+    }
+}
+
+impl<Y: Year> From<ODate<Y>> for DayOfYear {
+    fn from(date: ODate<Y>) -> Self {
+        DayOfYear(date.day)
+    }
+}
+
 pub trait Datelike<Y: Year = i16> {}
 
 impl<Y: Year> Datelike<Y> for Date<Y> {}
@@ -85,6 +119,176 @@ impl<Y: Year> Datelike<Y> for WdDate<Y> {}
 impl<Y: Year> Datelike<Y> for WDate<Y> {}
 impl<Y: Year> Datelike<Y> for ODate<Y> {}
 
+/// Accessors common to all complete date representations.
+///
+/// Components that have to be derived by conversion
+/// panic if the date is not valid.
+pub trait CompleteDatelike<Y: Year = i16>: Datelike<Y> {
+    /// Calendar year, see [`iso_week`](Self::iso_week) for the week-based year.
+    fn year(&self) -> Y;
+    /// `None` if the date is not valid.
+    fn month(&self) -> Option<u8>;
+    fn day_of_year(&self) -> u16;
+    fn iso_week(&self) -> WDate<Y>;
+    /// Day of the week, Monday being 1.
+    fn weekday(&self) -> u8;
+
+    /// Like [`month`](Self::month) but typed.
+    fn month_of_year(&self) -> Option<Month> {
+        self.month().and_then(|month| Month::try_from(month).ok())
+    }
+
+    /// Like [`weekday`](Self::weekday) but typed.
+    fn day_of_week(&self) -> Weekday {
+        Weekday::try_from(self.weekday()).expect("date is not valid")
+    }
+
+    /// Like [`day_of_week`](Self::day_of_week) but `None` if the date is not valid.
+    fn checked_day_of_week(&self) -> Option<Weekday> where Self: Valid {
+        if !self.is_valid() {
+            return None;
+        }
+        Weekday::try_from(self.weekday()).ok()
+    }
+}
+
+impl<Y> CompleteDatelike<Y> for Date<Y> where
+    Y: Year + Clone,
+    YmdDate<Y>: CompleteDatelike<Y>,
+    WdDate<Y>: CompleteDatelike<Y>,
+    ODate<Y>: CompleteDatelike<Y>
+{
+    fn year(&self) -> Y {
+        match self {
+            Date::YMD(date) => date.year(),
+            Date::WD (date) => date.year(),
+            Date::O  (date) => date.year()
+        }
+    }
+
+    fn month(&self) -> Option<u8> {
+        match self {
+            Date::YMD(date) => date.month(),
+            Date::WD (date) => date.month(),
+            Date::O  (date) => date.month()
+        }
+    }
+
+    fn day_of_year(&self) -> u16 {
+        match self {
+            Date::YMD(date) => date.day_of_year(),
+            Date::WD (date) => date.day_of_year(),
+            Date::O  (date) => date.day_of_year()
+        }
+    }
+
+    fn iso_week(&self) -> WDate<Y> {
+        match self {
+            Date::YMD(date) => date.iso_week(),
+            Date::WD (date) => date.iso_week(),
+            Date::O  (date) => date.iso_week()
+        }
+    }
+
+    fn weekday(&self) -> u8 {
+        match self {
+            Date::YMD(date) => date.weekday(),
+            Date::WD (date) => date.weekday(),
+            Date::O  (date) => date.weekday()
+        }
+    }
+}
+
+impl<Y> CompleteDatelike<Y> for YmdDate<Y> where
+    Y: Year + Clone,
+    ODate<Y>: From<YmdDate<Y>>,
+    WdDate<Y>: From<YmdDate<Y>>
+{
+    fn year(&self) -> Y {
+        self.year.clone()
+    }
+
+    fn month(&self) -> Option<u8> {
+        if YmDate::from(self.clone()).is_valid() {
+            Some(self.month)
+        } else {
+            None
+        }
+    }
+
+    fn day_of_year(&self) -> u16 {
+        ODate::from(self.clone()).day
+    }
+
+    fn iso_week(&self) -> WDate<Y> {
+        WdDate::from(self.clone()).into()
+    }
+
+    fn weekday(&self) -> u8 {
+        WdDate::from(self.clone()).day
+    }
+}
+
+impl<Y> CompleteDatelike<Y> for WdDate<Y> where
+    Y: Year + Clone,
+    ODate<Y>: From<WdDate<Y>>
+{
+    /// The calendar year, which differs from the week-based year
+    /// in the first and last days of some years.
+    fn year(&self) -> Y {
+        ODate::from(self.clone()).year
+    }
+
+    fn month(&self) -> Option<u8> {
+        if self.is_valid() {
+            Some(YmdDate::from(self.clone()).month)
+        } else {
+            None
+        }
+    }
+
+    fn day_of_year(&self) -> u16 {
+        ODate::from(self.clone()).day
+    }
+
+    fn iso_week(&self) -> WDate<Y> {
+        self.clone().into()
+    }
+
+    fn weekday(&self) -> u8 {
+        self.day
+    }
+}
+
+impl<Y> CompleteDatelike<Y> for ODate<Y> where
+    Y: Year + Clone,
+    WdDate<Y>: From<ODate<Y>>
+{
+    fn year(&self) -> Y {
+        self.year.clone()
+    }
+
+    fn month(&self) -> Option<u8> {
+        if self.is_valid() {
+            Some(YmdDate::from(self.clone()).month)
+        } else {
+            None
+        }
+    }
+
+    fn day_of_year(&self) -> u16 {
+        self.day
+    }
+
+    fn iso_week(&self) -> WDate<Y> {
+        WdDate::from(self.clone()).into()
+    }
+
+    fn weekday(&self) -> u8 {
+        WdDate::from(self.clone()).day
+    }
+}
+
 impl_fromstr_parse!(Date,       date);
 impl_fromstr_parse!(ApproxDate, date_approx);
 impl_fromstr_parse!(YmdDate,    date_ymd);
@@ -94,6 +298,7 @@ impl_fromstr_parse!(CDate,      date_c);
 impl_fromstr_parse!(WdDate,     date_wd);
 impl_fromstr_parse!(WDate,      date_w);
 impl_fromstr_parse!(ODate,      date_o);
+impl_fromstr_parse!(DayOfYear,  date_day_of_year);
 
 impl<Y> Valid for Date<Y>
 where Y: Year + Clone {
@@ -125,11 +330,9 @@ impl<Y> Valid for YmdDate<Y>
 where Y: Year {
     fn is_valid(&self) -> bool {
         self.day >= 1 &&
-        self.day <= match self.month {
-            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
-            4 | 6 | 9 | 11              => 30,
-            2 => if self.year.is_leap() { 29 } else { 28 },
-            _ => return false
+        match self.year.days_in_month(self.month) {
+            Some(days) => self.day <= days,
+            None => false
         }
     }
 }
@@ -172,6 +375,13 @@ where Y: Year {
     }
 }
 
+/// Valid in some year, so day 366 is valid.
+impl Valid for DayOfYear {
+    fn is_valid(&self) -> bool {
+        (1..=366).contains(&self.0)
+    }
+}
+
 impl<Y> Valid for ODate<Y>
 where Y: Year {
     fn is_valid(&self) -> bool {
@@ -181,12 +391,45 @@ where Y: Year {
 }
 
 pub trait Year {
+    /// The earliest representable year.
+    const MIN: Self;
+    /// The latest representable year.
+    const MAX: Self;
+    /// 1970, the year of the Unix epoch.
+    const UNIX_EPOCH: Self;
+
     fn is_leap(&self) -> bool;
     fn num_weeks(&self) -> u8;
 
     fn num_days(&self) -> u16 {
         if self.is_leap() { 366 } else { 365 }
     }
+
+    /// Whether the week-based year has a 53rd week.
+    fn has_leap_week(&self) -> bool {
+        self.num_weeks() == 53
+    }
+
+    /// Number of days in the month, `None` if there is no such month.
+    fn days_in_month(&self, month: u8) -> Option<u8> {
+        Some(match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11              => 30,
+            2 => if self.is_leap() { 29 } else { 28 },
+            _ => return None
+        })
+    }
+}
+
+/// See [`Year::days_in_month`].
+pub fn days_in_month<Y: Year>(year: Y, month: u8) -> Option<u8> {
+    year.days_in_month(month)
+}
+
+/// The years in the range whose week-based year has 53 weeks.
+pub fn leap_week_years<Y>(years: RangeInclusive<Y>) -> impl Iterator<Item = Y>
+where Y: Year, RangeInclusive<Y>: Iterator<Item = Y> {
+    years.filter(Y::has_leap_week)
 }
 
 macro_rules! impl_years {
@@ -207,6 +450,10 @@ macro_rules! impl_years {
 macro_rules! impl_year {
     ($ty:ty) => {
         impl Year for $ty {
+            const MIN: Self = <$ty>::MIN;
+            const MAX: Self = <$ty>::MAX;
+            const UNIX_EPOCH: Self = 1970;
+
             fn is_leap(&self) -> bool {
                 let factor = |x| self % x == 0;
                 factor(4) && (!factor(100) || factor(400))
@@ -214,14 +461,106 @@ macro_rules! impl_year {
 
             fn num_weeks(&self) -> u8 {
                 // https://en.wikipedia.org/wiki/ISO_week_date#Weeks_per_year
-                let p = |x| (x + x / 4 - x / 100 + x / 400) % 7;
-                if p(*self) == 4 || p(self - 1) == 3 { 53 } else { 52 }
+                // reduced term by term so that it neither overflows nor truncates towards zero
+                let p = |x: $ty| {
+                    let r = |d: $ty| x.div_euclid(d).rem_euclid(7);
+                    (r(1) + r(4) + 7 - r(100) + r(400)) % 7
+                };
+                // the previous year starts as many days earlier in the week as it has beyond 52 weeks
+                let p = p(*self);
+                if p == 4 || (p + 6 - self.is_leap() as $ty) % 7 == 3 { 53 } else { 52 }
             }
         }
     }
 }
 impl_years!(impl_year);
 
+/// A year from `MIN` to `MAX`,
+/// so that dates out of that range are rejected when they are created.
+///
+/// Week and ordinal dates may move to a neighbouring year,
+/// so only the conversions between calendar and ordinal dates are supported;
+/// convert to dates with `i16` years for the rest.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Debug)]
+pub struct BoundedYear<const MIN: i16, const MAX: i16>(i16);
+
+/// Years of the Gregorian calendar since its first full year,
+/// before which dates are proleptic.
+pub type GregorianYear = BoundedYear<1583, { i16::MAX }>;
+
+impl<const MIN: i16, const MAX: i16> BoundedYear<MIN, MAX> {
+    /// `None` if the year is out of range.
+    pub const fn new(year: i16) -> Option<Self> {
+        if MIN <= year && year <= MAX { Some(BoundedYear(year)) } else { None }
+    }
+
+    pub const fn get(self) -> i16 {
+        self.0
+    }
+}
+
+/// [`UNIX_EPOCH`](Year::UNIX_EPOCH) fails to compile
+/// if the range does not include 1970.
+impl<const MIN: i16, const MAX: i16> Year for BoundedYear<MIN, MAX> {
+    const MIN: Self = BoundedYear(MIN);
+    const MAX: Self = BoundedYear(MAX);
+    const UNIX_EPOCH: Self = match Self::new(1970) {
+        Some(year) => year,
+        None => panic!("1970 is out of range")
+    };
+
+    fn is_leap(&self) -> bool {
+        self.0.is_leap()
+    }
+
+    fn num_weeks(&self) -> u8 {
+        self.0.num_weeks()
+    }
+}
+
+impl<const MIN: i16, const MAX: i16> TryFrom<i16> for BoundedYear<MIN, MAX> {
+    type Error = ();
+
+    fn try_from(year: i16) -> Result<Self, Self::Error> {
+        Self::new(year).ok_or(())
+    }
+}
+
+impl<const MIN: i16, const MAX: i16> From<BoundedYear<MIN, MAX>> for i16 {
+    fn from(year: BoundedYear<MIN, MAX>) -> Self {
+        year.0
+    }
+}
+
+impl<const MIN: i16, const MAX: i16> From<BoundedYear<MIN, MAX>> for i64 {
+    fn from(year: BoundedYear<MIN, MAX>) -> Self {
+        year.0.into()
+    }
+}
+
+/// `Err` if the year is out of range.
+impl<const MIN: i16, const MAX: i16> TryFrom<YmdDate> for YmdDate<BoundedYear<MIN, MAX>> {
+    type Error = ();
+
+    fn try_from(date: YmdDate) -> Result<Self, Self::Error> {
+        Ok(YmdDate {
+            year: BoundedYear::try_from(date.year)?,
+            month: date.month,
+            day: date.day
+        })
+    }
+}
+
+impl<const MIN: i16, const MAX: i16> From<YmdDate<BoundedYear<MIN, MAX>>> for YmdDate {
+    fn from(date: YmdDate<BoundedYear<MIN, MAX>>) -> Self {
+        YmdDate {
+            year: date.year.get(),
+            month: date.month,
+            day: date.day
+        }
+    }
+}
+
 impl<Y> From<Date<Y>> for ApproxDate<Y>
 where Y: Year {
     fn from(date: Date<Y>) -> Self {
@@ -323,38 +662,11 @@ impl<Y> From<WdDate<Y>> for YmdDate<Y> where
 impl<Y> From<ODate<Y>> for YmdDate<Y>
 where Y: Year {
     fn from(date: ODate<Y>) -> Self {
-        let leap = date.year.is_leap();
-        let (month, day) = match date.day {
-              1 ..=  31         => ( 1, date.day -   0),
-             32 ..=  60 if leap => ( 2, date.day -  31),
-             32 ..=  59         => ( 2, date.day -  31),
-             61 ..=  91 if leap => ( 3, date.day -  60),
-             60 ..=  90         => ( 3, date.day -  59),
-             92 ..= 121 if leap => ( 4, date.day -  91),
-             91 ..= 120         => ( 4, date.day -  90),
-            122 ..= 152 if leap => ( 5, date.day - 121),
-            121 ..= 151         => ( 5, date.day - 120),
-            153 ..= 182 if leap => ( 6, date.day - 152),
-            152 ..= 181         => ( 6, date.day - 151),
-            183 ..= 213 if leap => ( 7, date.day - 182),
-            182 ..= 212         => ( 7, date.day - 181),
-            214 ..= 244 if leap => ( 8, date.day - 213),
-            213 ..= 243         => ( 8, date.day - 212),
-            245 ..= 274 if leap => ( 9, date.day - 244),
-            244 ..= 273         => ( 9, date.day - 243),
-            275 ..= 305 if leap => (10, date.day - 274),
-            274 ..= 304         => (10, date.day - 273),
-            306 ..= 335 if leap => (11, date.day - 305),
-            305 ..= 334         => (11, date.day - 304),
-            336 ..= 366 if leap => (12, date.day - 335),
-            335 ..= 365         => (12, date.day - 334),
-            day @ _ => panic!("invalid day: {:?}", day)
-        };
-
+        let (month, day) = month_day(date.year.is_leap(), date.day);
         Self {
             year: date.year,
             month,
-            day: day as u8
+            day
         }
     }
 }
@@ -404,22 +716,35 @@ impl<Y> From<YmdDate<Y>> for WdDate<Y> where
     }
 }
 
+/// Day of the week of January 1 where Sunday is 0.
+// https://en.wikipedia.org/wiki/Determination_of_the_day_of_the_week#Gauss's_algorithm
+macro_rules! weekday_jan1 {
+    ($year:expr) => {{
+        // the previous year, which only matters modulo 400 so that it cannot overflow
+        let y = ($year.rem_euclid(400) + 399) % 400;
+        (1 + 5 * y.rem_euclid(4) + 4 * y.rem_euclid(100) + 6 * y.rem_euclid(400)).rem_euclid(7) as i64
+    }}
+}
+
 macro_rules! impl_wd_from_o {
     ($ty:ty) => {
         impl From<ODate<$ty>> for WdDate<$ty> {
+            /// The week date may be in the previous or next week-based year.
             fn from(date: ODate<$ty>) -> Self {
-                // https://en.wikipedia.org/wiki/ISO_week_date#Calculating_the_week_number_of_a_given_date
-                let y = date.year % 100 % 28;
-                let cc = (date.year / 100) % 4;
-                let mut c = ((y + (y - 1) / 4 + 5 * cc - 1) % 7) as i16;
-                if c > 3 {
-                    c -= 7;
-                }
-                let dc = date.day as i16 + c;
+                // https://en.wikipedia.org/wiki/ISO_week_date#Calculating_the_week_number_from_an_ordinal_date
+                let weekday = (weekday_jan1!(date.year) + i64::from(date.day) - 1 + 6) % 7 + 1;
+                let week = (i64::from(date.day) - weekday + 10) / 7;
+                let (year, week) = if week < 1 {
+                    (date.year - 1, (date.year - 1).num_weeks())
+                } else if week > i64::from(date.year.num_weeks()) {
+                    (date.year + 1, 1)
+                } else {
+                    (date.year, week as u8)
+                };
                 Self {
-                    year: date.year,
-                    week: (dc as f32 / 7.).ceil() as u8,
-                    day: (dc % 7) as u8
+                    year,
+                    week,
+                    day: weekday as u8
                 }
             }
         }
@@ -430,34 +755,10 @@ impl_years!(impl_wd_from_o);
 impl<Y> From<YmdDate<Y>> for ODate<Y>
 where Y: Year {
     fn from(date: YmdDate<Y>) -> Self {
-        let leap = date.year.is_leap();
+        let day = day_of_year(date.year.is_leap(), date.month, date.day);
         Self {
             year: date.year,
-            day: match date.month {
-                 1         =>   0,
-                 2         =>  31,
-                 3 if leap =>  60,
-                 3         =>  59,
-                 4 if leap =>  91,
-                 4         =>  90,
-                 5 if leap => 121,
-                 5         => 120,
-                 6 if leap => 152,
-                 6         => 151,
-                 7 if leap => 182,
-                 7         => 181,
-                 8 if leap => 213,
-                 8         => 212,
-                 9 if leap => 244,
-                 9         => 243,
-                10 if leap => 274,
-                10         => 273,
-                11 if leap => 305,
-                11         => 304,
-                12 if leap => 335,
-                12         => 334,
-                month @ _ => panic!("invalid month: {:?}", month)
-            } + date.day as u16
+            day
         }
     }
 }
@@ -465,30 +766,21 @@ where Y: Year {
 macro_rules! impl_o_from_wd {
     ($ty:ty) => {
         impl From<WdDate<$ty>> for ODate<$ty> {
+            /// The ordinal date may be in the previous or next calendar year.
             fn from(date: WdDate<$ty>) -> Self {
-                // https://en.wikipedia.org/wiki/ISO_week_date#Calculating_a_date_given_the_year,_week_number_and_weekday
-
-                fn weekday_jan4(year: $ty) -> u8 {
-                    fn weekday_jan1(year: $ty) -> u8 {
-                        // https://en.wikipedia.org/wiki/Determination_of_the_day_of_the_week#Gauss's_algorithm
-                        let y = year - 1;
-                        ((1 + 5 * (y % 4) + 4 * (y % 100) + 6 * (y % 400)) % 7) as u8
-                    }
-
-                    (weekday_jan1(year) + 3) % 7
-                }
-
-                let mut day = (date.week * 7 + date.day - (weekday_jan4(date.year) + 3)) as u16;
-                if day < 1 {
-                    day += (date.year - 1).num_days();
-                }
-                if day > date.year.num_days() {
-                    day -= date.year.num_days();
-                }
-
+                // https://en.wikipedia.org/wiki/ISO_week_date#Calculating_an_ordinal_or_month_date_from_a_week_date
+                let weekday_jan4 = (weekday_jan1!(date.year) + 3 + 6) % 7 + 1;
+                let day = i64::from(date.week) * 7 + i64::from(date.day) - (weekday_jan4 + 3);
+                let (year, day) = if day < 1 {
+                    (date.year - 1, day + i64::from((date.year - 1).num_days()))
+                } else if day > i64::from(date.year.num_days()) {
+                    (date.year + 1, day - i64::from(date.year.num_days()))
+                } else {
+                    (date.year, day)
+                };
                 Self {
-                    year: date.year,
-                    day
+                    year,
+                    day: day as u16
                 }
             }
         }
@@ -496,10 +788,449 @@ macro_rules! impl_o_from_wd {
 }
 impl_years!(impl_o_from_wd);
 
+fn validated<T: Valid>(date: T) -> Result<T, ()> {
+    if date.is_valid() { Ok(date) } else { Err(()) }
+}
+
+/// From year, month, and day, if valid.
+impl<Y> TryFrom<(Y, u8, u8)> for YmdDate<Y>
+where Y: Year, Self: Valid {
+    type Error = ();
+
+    fn try_from((year, month, day): (Y, u8, u8)) -> Result<Self, Self::Error> {
+        validated(Self { year, month, day })
+    }
+}
+
+/// From year, week, and day of the week, if valid.
+impl<Y> TryFrom<(Y, u8, u8)> for WdDate<Y>
+where Y: Year, Self: Valid {
+    type Error = ();
+
+    fn try_from((year, week, day): (Y, u8, u8)) -> Result<Self, Self::Error> {
+        validated(Self { year, week, day })
+    }
+}
+
+/// From year and day of the year, if valid.
+impl<Y> TryFrom<(Y, u16)> for ODate<Y>
+where Y: Year, Self: Valid {
+    type Error = ();
+
+    fn try_from((year, day): (Y, u16)) -> Result<Self, Self::Error> {
+        validated(Self { year, day })
+    }
+}
+
+/// Difference between two dates in calendar units.
+///
+/// All components have the same sign.
+#[derive(Eq, PartialEq, Clone, Copy, Debug, Default)]
+pub struct CalendarDelta {
+    pub years: i32,
+    pub months: i32,
+    pub days: i32
+}
+
+impl<Y> YmdDate<Y> where
+    Y: Year,
+    WdDate<Y>: From<YmdDate<Y>>,
+    Self: Clone
+{
+    /// The week-based year this date belongs to,
+    /// which may be the previous or next calendar year
+    /// for days around January 1.
+    pub fn iso_week_year(&self) -> Y {
+        WdDate::from(self.clone()).year
+    }
+}
+
+impl<Y> YmdDate<Y> where
+    Y: Year + Clone,
+    WdDate<Y>: From<YmdDate<Y>>,
+    YmdDate<Y>: From<WdDate<Y>>
+{
+    /// The month that the week of this date belongs to by [`WDate::month`]
+    /// and the number of the week among the weeks of that month, starting at 1.
+    ///
+    /// The month may be the previous or next one for days around the first of the month.
+    pub fn week_of_month(&self, by: Weekday) -> (YmDate<Y>, u8) {
+        let day = WDate::from(WdDate::from(self.clone())).day(by);
+        let week = (day.day - 1) / 7 + 1;
+        (day.into(), week)
+    }
+}
+
+impl<Y> YmdDate<Y>
+where Y: Year + Clone {
+    pub fn first_of_month(&self) -> Self {
+        YmdDate {
+            year: self.year.clone(),
+            month: self.month,
+            day: 1
+        }
+    }
+
+    /// Keeps the day if the month is not valid.
+    pub fn last_of_month(&self) -> Self {
+        YmdDate {
+            year: self.year.clone(),
+            month: self.month,
+            day: self.year.days_in_month(self.month).unwrap_or(self.day)
+        }
+    }
+}
+
+impl<Y> YDate<Y>
+where Y: Year + Clone {
+    pub fn first_day(&self) -> YmdDate<Y> {
+        YmdDate {
+            year: self.year.clone(),
+            month: 1,
+            day: 1
+        }
+    }
+
+    pub fn last_day(&self) -> YmdDate<Y> {
+        YmdDate {
+            year: self.year.clone(),
+            month: 12,
+            day: 31
+        }
+    }
+}
+
+impl<Y> WDate<Y>
+where Y: Year + Clone {
+    pub fn monday(&self) -> WdDate<Y> {
+        WdDate {
+            year: self.year.clone(),
+            week: self.week,
+            day: 1
+        }
+    }
+
+    pub fn sunday(&self) -> WdDate<Y> {
+        WdDate {
+            year: self.year.clone(),
+            week: self.week,
+            day: 7
+        }
+    }
+}
+
+impl<Y> WDate<Y> where
+    Y: Year + Clone,
+    YmdDate<Y>: From<WdDate<Y>>
+{
+    /// The month in which the given day of this week falls,
+    /// so that each week belongs to exactly one month, as in 4-4-5 calendars.
+    ///
+    /// By Thursday, weeks belong to months as they belong to years (2.2.10).
+    pub fn month(&self, by: Weekday) -> YmDate<Y> {
+        self.day(by).into()
+    }
+
+    fn day(&self, weekday: Weekday) -> YmdDate<Y> {
+        WdDate {
+            year: self.year.clone(),
+            week: self.week,
+            day: weekday.number()
+        }.into()
+    }
+}
+
+impl<Y> WDate<Y>
+where Y: Year {
+    /// Whether this is the 53rd week of its year.
+    pub fn is_in_leap_week(&self) -> bool {
+        self.week == 53
+    }
+}
+
+impl<Y> WdDate<Y>
+where Y: Year {
+    /// Whether this is in the 53rd week of its year.
+    pub fn is_in_leap_week(&self) -> bool {
+        self.week == 53
+    }
+}
+
+impl<Y> WdDate<Y> where
+    Y: Year + Clone,
+    YmdDate<Y>: From<WdDate<Y>>
+{
+    /// See [`WDate::month`].
+    pub fn month(&self, by: Weekday) -> YmDate<Y> {
+        WDate::from(self.clone()).month(by)
+    }
+}
+
+impl<Y> WdDate<Y>
+where Y: Year + Clone {
+    /// The Monday of the same week.
+    pub fn monday(&self) -> Self {
+        WDate::from(self.clone()).monday()
+    }
+
+    /// The Sunday of the same week.
+    pub fn sunday(&self) -> Self {
+        WDate::from(self.clone()).sunday()
+    }
+}
+
+impl<Y: Year> YmdDate<Y> {
+    /// The earliest date with this year type.
+    pub const MIN: Self = YmdDate { year: Y::MIN, month: 1, day: 1 };
+    /// The latest date with this year type.
+    pub const MAX: Self = YmdDate { year: Y::MAX, month: 12, day: 31 };
+    /// 1970-01-01, the first day of the Unix epoch.
+    pub const UNIX_EPOCH: Self = YmdDate { year: Y::UNIX_EPOCH, month: 1, day: 1 };
+}
+
+/// [`UNIX_EPOCH`](YmdDate::UNIX_EPOCH) rather than year zero,
+/// so that defaults agree with `SystemTime` and databases.
+impl<Y: Year> Default for YmdDate<Y> {
+    fn default() -> Self {
+        Self::UNIX_EPOCH
+    }
+}
+
+impl YmdDate {
+    /// Era and year of the era, see [`Era::of_year`].
+    pub fn era(&self) -> (Era, u64) {
+        Era::of_year(self.year.into())
+    }
+
+    /// Rata Die, the count of days in which 0001-01-01 is day 1.
+    ///
+    /// Only meaningful if the date is valid.
+    pub fn rata_die(&self) -> i64 {
+        days_from_civil(self.year.into(), self.month, self.day) + RATA_DIE_UNIX_EPOCH
+    }
+
+    /// `None` if the result is out of [`MIN`](Self::MIN) to [`MAX`](Self::MAX).
+    pub fn from_rata_die(rata_die: i64) -> Option<Self> {
+        if !(Self::MIN.rata_die()..=Self::MAX.rata_die()).contains(&rata_die) {
+            return None;
+        }
+
+        let (year, month, day) = civil_from_days(rata_die - RATA_DIE_UNIX_EPOCH);
+        Some(YmdDate {
+            year: year as i16,
+            month,
+            day
+        })
+    }
+
+    /// `None` if the date is not valid
+    /// or the result is out of [`MIN`](Self::MIN) to [`MAX`](Self::MAX).
+    pub fn checked_add_days(&self, days: i64) -> Option<Self> {
+        if !self.is_valid() {
+            return None;
+        }
+
+        Self::from_rata_die(self.rata_die().checked_add(days)?)
+    }
+
+    /// See [`checked_add_days`](Self::checked_add_days).
+    pub fn checked_sub_days(&self, days: i64) -> Option<Self> {
+        self.checked_add_days(days.checked_neg()?)
+    }
+
+    /// Moves the day to the end of the month if needed.
+    ///
+    /// `None` if the date is not valid
+    /// or the result is out of [`MIN`](Self::MIN) to [`MAX`](Self::MAX).
+    pub fn checked_add_months(&self, months: i64) -> Option<Self> {
+        if !self.is_valid() {
+            return None;
+        }
+
+        add_months(self, months)
+    }
+
+    /// Normalizes out-of-range months and days by carrying them over,
+    /// so month 13 is January of the next year,
+    /// day 32 of January is February 1
+    /// and day 0 is the last day of the previous month.
+    ///
+    /// `None` if the year does not fit.
+    pub fn from_ymd_overflowing(year: i16, month: u8, day: u8) -> Option<Self> {
+        let months = i64::from(year) * 12 + i64::from(month) - 1;
+        Self::from_rata_die(
+            days_from_civil(months.div_euclid(12), months.rem_euclid(12) as u8 + 1, 1) +
+            i64::from(day) - 1 + RATA_DIE_UNIX_EPOCH
+        )
+    }
+
+    /// The difference from this date to the other in whole years and months
+    /// followed by the remaining days.
+    ///
+    /// Months are counted like adding them:
+    /// a day that does not exist in the target month falls on its last day,
+    /// so January 31 to February 28 of a common year is one month.
+    /// Adding the years and months to this date that way
+    /// and then the days always yields the other date.
+    /// If the other date is earlier, the components are
+    /// those from the other date to this one, negated.
+    ///
+    /// `None` if either date is not valid.
+    pub fn delta(&self, other: &Self) -> Option<CalendarDelta> {
+        if !self.is_valid() || !other.is_valid() {
+            return None;
+        }
+
+        let (start, end, sign) = match self.cmp_calendar(other) {
+            Ordering::Greater => (other, self, -1),
+            _ => (self, other, 1)
+        };
+
+        let mut months =
+            (i64::from(end.year)   * 12 + i64::from(end.month)) -
+            (i64::from(start.year) * 12 + i64::from(start.month));
+        let shifted = loop {
+            let shifted = add_months(start, months)?;
+            match shifted.cmp_calendar(end) {
+                Ordering::Greater => months -= 1,
+                _ => break shifted
+            }
+        };
+        let days =
+            days_from_civil(end.year.into(), end.month, end.day) -
+            days_from_civil(shifted.year.into(), shifted.month, shifted.day);
+
+        Some(CalendarDelta {
+            years:  sign * i32::try_from(months / 12).ok()?,
+            months: sign * (months % 12) as i32,
+            days:   sign * days as i32
+        })
+    }
+
+    fn cmp_calendar(&self, other: &Self) -> Ordering {
+        (self.year, self.month, self.day).cmp(&(other.year, other.month, other.day))
+    }
+}
+
+/// Adds months to a date, moving its day to the end of the month if needed.
+pub(crate) fn add_months(date: &YmdDate, months: i64) -> Option<YmdDate> {
+    let months = (i64::from(date.year) * 12 + i64::from(date.month) - 1).checked_add(months)?;
+    let year = i16::try_from(months.div_euclid(12)).ok()?;
+    let month = months.rem_euclid(12) as u8 + 1;
+    Some(YmdDate {
+        year,
+        month,
+        day: date.day.min(year.days_in_month(month)?)
+    })
+}
+
+/// [`YmdDate::rata_die`] of 1970-01-01.
+const RATA_DIE_UNIX_EPOCH: i64 = 719_163;
+
+/// Days from March 1 to the first of the given month counted from March as 0.
+// http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+fn days_from_march(mp: u16) -> u16 {
+    (153 * mp + 2) / 5
+}
+
+/// Days before the first of each month and in the whole year,
+/// in common and in leap years.
+const DAYS_BEFORE_MONTH: [[u16; 13]; 2] = [
+    [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334, 365],
+    [0, 31, 60, 91, 121, 152, 182, 213, 244, 274, 305, 335, 366]
+];
+
+/// Day of the year of a month and day.
+///
+/// Panics if the month is not valid.
+fn day_of_year(leap: bool, month: u8, day: u8) -> u16 {
+    match month {
+        1 ..= 12 => DAYS_BEFORE_MONTH[leap as usize][usize::from(month) - 1] + u16::from(day),
+        month => panic!("invalid month: {:?}", month)
+    }
+}
+
+/// Month and day of a day of the year.
+///
+/// Panics if the day is not valid.
+fn month_day(leap: bool, day: u16) -> (u8, u8) {
+    let days_before = &DAYS_BEFORE_MONTH[leap as usize];
+    if day == 0 || day > days_before[12] {
+        panic!("invalid day: {:?}", day);
+    }
+
+    // months are 28 to 31 days long, so this is the month or the one before
+    let mut month = usize::from(day.div_ceil(32));
+    if day > days_before[month] {
+        month += 1;
+    }
+    (month as u8, (day - days_before[month - 1]) as u8)
+}
+
+/// Days since 1970-01-01 of a proleptic Gregorian calendar date.
+// http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+pub(crate) fn days_from_civil(year: i64, month: u8, day: u8) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let doy = i64::from(days_from_march((u16::from(month) + 9) % 12)) + i64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Proleptic Gregorian calendar date of the given days since 1970-01-01.
+// http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+pub(crate) fn civil_from_days(days: i64) -> (i64, u8, u8) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let doe = days.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn try_from_tuple() {
+        assert_eq!(YmdDate::try_from((2024, 2, 29)), Ok(YmdDate { year: 2024, month: 2, day: 29 }));
+        assert_eq!(YmdDate::try_from((2023, 2, 29)), Err(()));
+        assert_eq!(WdDate::try_from((2020, 53, 7)), Ok(WdDate { year: 2020, week: 53, day: 7 }));
+        assert_eq!(WdDate::try_from((2021, 53, 1)), Err(()));
+        assert_eq!(ODate::try_from((2024, 366)), Ok(ODate { year: 2024, day: 366 }));
+        assert_eq!(ODate::try_from((2023, 366)), Err(()));
+    }
+
+    #[test]
+    fn from_ymd_overflowing() {
+        assert_eq!(YmdDate::from_ymd_overflowing(2023, 13, 1),  Some(YmdDate { year: 2024, month: 1, day: 1 }));
+        assert_eq!(YmdDate::from_ymd_overflowing(2023, 1, 32),  Some(YmdDate { year: 2023, month: 2, day: 1 }));
+        assert_eq!(YmdDate::from_ymd_overflowing(2024, 2, 30),  Some(YmdDate { year: 2024, month: 3, day: 1 }));
+        assert_eq!(YmdDate::from_ymd_overflowing(2024, 3, 0),   Some(YmdDate { year: 2024, month: 2, day: 29 }));
+        assert_eq!(YmdDate::from_ymd_overflowing(2024, 0, 1),   Some(YmdDate { year: 2023, month: 12, day: 1 }));
+        assert_eq!(YmdDate::from_ymd_overflowing(2024, 5, 1),   Some(YmdDate { year: 2024, month: 5, day: 1 }));
+        assert_eq!(YmdDate::from_ymd_overflowing(i16::MAX, 12, 32), None);
+    }
+
+    #[test]
+    fn delta() {
+        let delta = |a: &str, b: &str| a.parse::<YmdDate>().unwrap().delta(&b.parse().unwrap());
+
+        assert_eq!(delta("2000-02-15", "2024-05-01"), Some(CalendarDelta { years: 24, months: 2, days: 16 }));
+        assert_eq!(delta("2024-05-01", "2000-02-15"), Some(CalendarDelta { years: -24, months: -2, days: -16 }));
+        assert_eq!(delta("2023-01-31", "2023-02-28"), Some(CalendarDelta { years: 0, months: 1, days: 0 }));
+        assert_eq!(delta("2023-01-31", "2023-03-01"), Some(CalendarDelta { years: 0, months: 1, days: 1 }));
+        assert_eq!(delta("2020-02-29", "2021-02-28"), Some(CalendarDelta { years: 1, months: 0, days: 0 }));
+        assert_eq!(delta("2024-05-01", "2024-05-01"), Some(CalendarDelta::default()));
+        assert_eq!(delta("2023-02-29", "2024-05-01"), None);
+    }
+
     #[test]
     fn ymd_from_wd() {
         assert_eq!(
@@ -559,6 +1290,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn week_year_boundaries() {
+        for &(ymd, wd) in &[
+            ("2024-12-30", "2025-W01-1"),
+            ("2021-01-01", "2020-W53-5"),
+            ("2023-01-01", "2022-W52-7"),
+            ("2020-12-31", "2020-W53-4"),
+            ("2026-01-01", "2026-W01-4"),
+            ("2018-09-16", "2018-W37-7")
+        ] {
+            let ymd: YmdDate = ymd.parse().unwrap();
+            let wd: WdDate = wd.parse().unwrap();
+            assert_eq!(WdDate::from(ymd), wd);
+            assert_eq!(YmdDate::from(wd), ymd);
+            assert_eq!(ymd.iso_week_year(), wd.year);
+        }
+    }
+
     #[test]
     fn wd_from_o() {
         assert_eq!(
@@ -572,6 +1321,28 @@ mod tests {
                 day: 5
             }
         );
+        assert_eq!(
+            WdDate::from(ODate {
+                year: 2018,
+                day: 259
+            }),
+            WdDate {
+                year: 2018,
+                week: 37,
+                day: 7
+            }
+        );
+
+        for year in -400..=2800i16 {
+            for day in 1..=year.num_days() {
+                let o = ODate { year, day };
+                let wd = WdDate::from(o);
+                assert!(wd.is_valid(), "{:?}", o);
+                let days = days_from_civil(year.into(), 1, 1) + i64::from(day) - 1;
+                assert_eq!(i64::from(wd.day), (days + 3).rem_euclid(7) + 1, "{:?}", o);
+                assert_eq!(ODate::from(wd), o);
+            }
+        }
     }
 
     #[test]
@@ -604,6 +1375,179 @@ mod tests {
         );
     }
 
+    #[test]
+    fn complete_datelike() {
+        let dates = [
+            Date::YMD(YmdDate {
+                year: 1985,
+                month: 4,
+                day: 12
+            }),
+            Date::WD(WdDate {
+                year: 1985,
+                week: 15,
+                day: 5
+            }),
+            Date::O(ODate {
+                year: 1985,
+                day: 102
+            })
+        ];
+        for date in &dates {
+            assert_eq!(date.year(), 1985);
+            assert_eq!(date.month(), Some(4));
+            assert_eq!(date.day_of_year(), 102);
+            assert_eq!(date.iso_week(), WDate {
+                year: 1985,
+                week: 15
+            });
+            assert_eq!(date.weekday(), 5);
+            assert_eq!(date.month_of_year(), Some(Month::April));
+            assert_eq!(date.day_of_week(), Weekday::Friday);
+            assert_eq!(date.checked_day_of_week(), Some(Weekday::Friday));
+        }
+        assert_eq!(Date::YMD(YmdDate { year: 1985, month: 13, day: 1 }).checked_day_of_week(), None);
+        assert_eq!(Date::O(ODate { year: 1985, day: 366 }).checked_day_of_week(), None);
+
+        assert_eq!(YmdDate { year: 1985, month: 13, day: 1 }.month(), None);
+        assert_eq!(ODate   { year: 1985, day: 366 }.month(), None);
+    }
+
+    #[test]
+    fn week_month() {
+        let week = WDate { year: 2020, week: 1 };
+        assert_eq!(week.month(Weekday::Thursday), YmDate { year: 2020, month: 1 });
+        assert_eq!(week.month(Weekday::Monday),   YmDate { year: 2019, month: 12 });
+        assert_eq!(week.monday().month(Weekday::Sunday), YmDate { year: 2020, month: 1 });
+
+        let date = YmdDate { year: 2020, month: 1, day: 1 };
+        assert_eq!(date.week_of_month(Weekday::Thursday), (YmDate { year: 2020, month: 1 }, 1));
+        assert_eq!(date.week_of_month(Weekday::Monday),   (YmDate { year: 2019, month: 12 }, 5));
+        assert_eq!(YmdDate { year: 2020, month: 1, day: 31 }.week_of_month(Weekday::Thursday), (YmDate { year: 2020, month: 1 }, 5));
+        assert_eq!(YmdDate { year: 2020, month: 2, day: 2 }.week_of_month(Weekday::Thursday),  (YmDate { year: 2020, month: 1 }, 5));
+        assert_eq!(YmdDate { year: 2020, month: 2, day: 3 }.week_of_month(Weekday::Thursday),  (YmDate { year: 2020, month: 2 }, 1));
+
+        // every week belongs to one month, which has four or five of them
+        let mut weeks = [0; 12];
+        for week in 1..=53 {
+            let month = WDate { year: 2020, week }.month(Weekday::Thursday);
+            assert_eq!(month.year, 2020);
+            weeks[usize::from(month.month) - 1] += 1;
+        }
+        assert_eq!(weeks, [5, 4, 4, 5, 4, 4, 5, 4, 4, 5, 4, 5]);
+    }
+
+    #[test]
+    fn bounds() {
+        assert!(YmdDate::<i16>::MIN.is_valid());
+        assert!(YmdDate::<i16>::MAX.is_valid());
+        assert_eq!(YmdDate::<u32>::MIN, YmdDate { year: 0, month: 1, day: 1 });
+
+        let date = YmdDate { year: 2024, month: 1, day: 31 };
+        assert_eq!(date.checked_add_days(29), Some(YmdDate { year: 2024, month: 2, day: 29 }));
+        assert_eq!(date.checked_sub_days(31), Some(YmdDate { year: 2023, month: 12, day: 31 }));
+        assert_eq!(date.checked_add_months(1), Some(YmdDate { year: 2024, month: 2, day: 29 }));
+        assert_eq!(date.checked_add_months(-13), Some(YmdDate { year: 2022, month: 12, day: 31 }));
+
+        assert_eq!(YmdDate::MAX.checked_add_days(1), None);
+        assert_eq!(YmdDate::MIN.checked_sub_days(1), None);
+        assert_eq!(YmdDate::MAX.checked_add_months(1), None);
+        assert_eq!(YmdDate::MIN.checked_add_months(i64::MIN), None);
+        assert_eq!(YmdDate { year: 2024, month: 2, day: 30 }.checked_add_days(0), None);
+    }
+
+    #[test]
+    fn first_and_last() {
+        let date = YmdDate { year: 2024, month: 2, day: 14 };
+        assert_eq!(date.first_of_month(), YmdDate { year: 2024, month: 2, day: 1 });
+        assert_eq!(date.last_of_month(),  YmdDate { year: 2024, month: 2, day: 29 });
+        assert_eq!(YmdDate { year: 2023, month: 2, day: 14 }.last_of_month().day, 28);
+
+        assert_eq!(YDate { year: 2024 }.first_day(), YmdDate { year: 2024, month: 1,  day: 1 });
+        assert_eq!(YDate { year: 2024 }.last_day(),  YmdDate { year: 2024, month: 12, day: 31 });
+
+        let date = WdDate { year: 2020, week: 53, day: 4 };
+        assert_eq!(date.monday(), WdDate { year: 2020, week: 53, day: 1 });
+        assert_eq!(date.sunday(), WdDate { year: 2020, week: 53, day: 7 });
+        assert_eq!(YmdDate::from(date.sunday()), YmdDate { year: 2021, month: 1, day: 3 });
+    }
+
+    #[test]
+    fn days_in_month() {
+        assert_eq!(super::days_in_month(2024, 2),  Some(29));
+        assert_eq!(super::days_in_month(1900, 2),  Some(28));
+        assert_eq!(super::days_in_month(2024, 4),  Some(30));
+        assert_eq!(super::days_in_month(2024, 12), Some(31));
+        assert_eq!(super::days_in_month(2024, 13), None);
+        assert_eq!(2000u32.days_in_month(2), Some(29));
+    }
+
+    #[test]
+    fn leap_week() {
+        assert!(2020.has_leap_week());
+        assert!(!2021.has_leap_week());
+        assert!(WdDate { year: 2026, week: 53, day: 4 }.is_in_leap_week());
+        assert!(!WDate { year: 2026, week: 52 }.is_in_leap_week());
+        assert_eq!(leap_week_years(2000..=2030).collect::<Vec<i16>>(), [2004, 2009, 2015, 2020, 2026]);
+    }
+
+    #[test]
+    fn era() {
+        assert_eq!(YmdDate { year: -43, month: 3, day: 15 }.era(), (Era::Bce, 44));
+        assert_eq!(YmdDate::UNIX_EPOCH.era(), (Era::Ce, 1970));
+    }
+
+    #[test]
+    fn day_of_year_only() {
+        assert_eq!("-123".parse(), Ok(DayOfYear(123)));
+        assert_eq!("123".parse(), Ok(DayOfYear(123)));
+        assert_eq!("12".parse::<DayOfYear>(), Err(()));
+        assert_eq!(DayOfYear(60).with_year(2024), ODate { year: 2024, day: 60 });
+        assert_eq!(DayOfYear::from(ODate { year: 2024, day: 60 }), DayOfYear(60));
+        assert!(DayOfYear(366).is_valid());
+        assert!(!DayOfYear(366).with_year(2023).is_valid());
+        assert!(!DayOfYear(0).is_valid());
+    }
+
+    #[test]
+    fn rata_die() {
+        assert_eq!(YmdDate { year: 1, month: 1, day: 1 }.rata_die(), 1);
+        assert_eq!(YmdDate::UNIX_EPOCH.rata_die(), 719_163);
+        assert_eq!(YmdDate { year: 0, month: 12, day: 31 }.rata_die(), 0);
+        assert_eq!(YmdDate::from_rata_die(737_375), Some(YmdDate { year: 2019, month: 11, day: 12 }));
+        assert_eq!(YmdDate::from_rata_die(YmdDate::MIN.rata_die()), Some(YmdDate::MIN));
+        assert_eq!(YmdDate::from_rata_die(YmdDate::MAX.rata_die() + 1), None);
+        assert_eq!(YmdDate::from_rata_die(i64::MAX), None);
+    }
+
+    #[test]
+    fn ordinal_days() {
+        for &leap in &[false, true] {
+            let mut day = 0;
+            for month in 1..=12 {
+                for d in 1..=if leap { 2000 } else { 2001 }.days_in_month(month).unwrap() {
+                    day += 1;
+                    assert_eq!(day_of_year(leap, month, d), day);
+                    assert_eq!(month_day(leap, day), (month, d));
+                }
+            }
+            assert_eq!(day, if leap { 366 } else { 365 });
+        }
+    }
+
+    #[test]
+    fn civil_days() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2000, 3, 1), 11_017);
+        assert_eq!(days_from_civil(1969, 12, 31), -1);
+        assert_eq!(days_from_civil(-1, 12, 31), -719_529);
+
+        for days in -800_000..800_000 {
+            let (year, month, day) = civil_from_days(days);
+            assert_eq!(days_from_civil(year, month, day), days);
+        }
+    }
+
     #[test]
     fn valid_date_ymd() {
         assert!(!YmdDate {
@@ -649,6 +1593,53 @@ mod tests {
         }.is_valid());
     }
 
+    #[test]
+    fn complete_year_boundary() {
+        // 2018-12-31 is in the first week of 2019
+        let ymd = YmdDate { year: 2018, month: 12, day: 31 };
+        let wd = WdDate { year: 2019, week: 1, day: 1 };
+        let o = ODate { year: 2018, day: 365 };
+        assert_eq!(WdDate::from(ymd), wd);
+        assert_eq!(ODate::from(ymd), o);
+
+        for date in &[Date::YMD(ymd), Date::WD(wd), Date::O(o)] {
+            assert_eq!(date.year(), 2018, "{:?}", date);
+            assert_eq!(date.month(), Some(12), "{:?}", date);
+            assert_eq!(date.day_of_year(), 365, "{:?}", date);
+            assert_eq!(date.iso_week(), WDate { year: 2019, week: 1 }, "{:?}", date);
+            assert_eq!(date.weekday(), 1, "{:?}", date);
+        }
+
+        // 2021-01-03 is in the last week of 2020
+        let wd = WdDate { year: 2020, week: 53, day: 7 };
+        assert_eq!(CompleteDatelike::year(&wd), 2021);
+        assert_eq!(wd.iso_week().year, 2020);
+    }
+
+    #[test]
+    fn bounded_year() {
+        assert_eq!(GregorianYear::new(1582), None);
+        assert_eq!(GregorianYear::new(1583).map(i16::from), Some(1583));
+        assert_eq!(BoundedYear::<1900, 2099>::try_from(2100), Err(()));
+        assert!(GregorianYear::new(1600).unwrap().is_leap());
+        assert!(!GregorianYear::new(1700).unwrap().is_leap());
+        assert_eq!(GregorianYear::new(2020).unwrap().num_weeks(), 53);
+        assert_eq!(<GregorianYear as Year>::MIN.get(), 1583);
+
+        let date: YmdDate = "1582-10-04".parse().unwrap();
+        assert_eq!(YmdDate::<GregorianYear>::try_from(date), Err(()));
+
+        let date: YmdDate = "1582-10-15".parse().unwrap();
+        assert_eq!(YmdDate::<BoundedYear<1582, 1582>>::try_from(date).map(YmdDate::from), Ok(date));
+
+        let date = YmdDate::<GregorianYear>::try_from(YmdDate { year: 2024, month: 2, day: 29 }).unwrap();
+        assert!(date.is_valid());
+        assert!(!YmdDate { day: 30, ..date }.is_valid());
+        assert_eq!(ODate::from(date).day, 60);
+        assert_eq!(YmdDate::from(ODate::from(date)), date);
+        assert_eq!(YmdDate::<GregorianYear>::default().year.get(), 1970);
+    }
+
     #[test]
     fn valid_date_o() {
         assert!(!ODate {
@@ -1,10 +1,22 @@
 use {
     Valid,
     date::*,
-    time::*
+    time::*,
+    std::{
+        convert::TryFrom,
+        str::FromStr
+    }
 };
 
-#[derive(Eq, PartialEq, Clone, Debug)]
+/// Defaults to the defaults of its date and time,
+/// which is [`UNIX_EPOCH`](DateTime::UNIX_EPOCH) for the default types.
+///
+/// Equality compares representations, so `2018-08-02T13:42:02+02:00`
+/// differs from the same instant `2018-08-02T11:42:02Z`
+/// and from the same local time `2018-08-02T13:42:02Z`.
+/// Equal datetimes are both [`same_instant`](DateTime::same_instant)
+/// and [`same_local`](DateTime::same_local), which compare by either meaning.
+#[derive(Eq, PartialEq, Clone, Copy, Default, Debug)]
 pub struct DateTime<D = YmdDate, T = GlobalTime>
 where D: Datelike, T: Timelike {
     pub date: D,
@@ -46,7 +58,242 @@ impl<D, T> Valid for DateTime<D, T> where
     }
 }
 
-#[derive(PartialEq, Clone, Debug)]
+impl<D, T> DateTime<D, T> where
+    D: Datelike + Valid,
+    T: Timelike + Valid
+{
+    /// `Err` if the date or the time is not valid.
+    pub fn new(date: D, time: T) -> Result<Self, ()> {
+        let datetime = DateTime { date, time };
+        if datetime.is_valid() { Ok(datetime) } else { Err(()) }
+    }
+
+    /// Parses a date and a time that are stored apart,
+    /// as in separate columns of a CSV file or database table,
+    /// see [`new`](Self::new).
+    pub fn from_parts(date: &str, time: &str) -> Result<Self, ()> where
+        D: FromStr,
+        T: FromStr
+    {
+        Self::new(
+            date.parse().or(Err(()))?,
+            time.parse().or(Err(()))?
+        )
+    }
+}
+
+/// Promotion to a datetime, converting week and ordinal dates to calendar dates.
+impl Date {
+    /// The local time on this date, `offset` minutes from UTC,
+    /// `None` if the date is not valid.
+    pub fn at(self, time: LocalTime, offset: i16) -> Option<DateTime> {
+        if !self.is_valid() {
+            return None;
+        }
+
+        Some(DateTime {
+            date: self.into(),
+            time: GlobalTime {
+                local: time,
+                timezone: offset
+            }
+        })
+    }
+
+    /// The start of this date in UTC, see [`at`](Self::at).
+    pub fn at_midnight_utc(self) -> Option<DateTime> {
+        self.at(LocalTime::MIDNIGHT, 0)
+    }
+}
+
+impl<D, T> CompleteTimelike for DateTime<D, T> where
+    D: Datelike,
+    T: Timelike + CompleteTimelike
+{
+    fn hour      (&self) -> u8  { self.time.hour() }
+    fn minute    (&self) -> u8  { self.time.minute() }
+    fn second    (&self) -> u8  { self.time.second() }
+    fn nanosecond(&self) -> u32 { self.time.nanosecond() }
+}
+
+impl<D> DateTime<D, GlobalTime>
+where D: Datelike + Valid + Copy + Into<YmdDate> {
+    /// Nanoseconds since 1970-01-01T00:00:00Z,
+    /// a total order on instants regardless of the representation and offset.
+    ///
+    /// `None` if the date is not valid.
+    pub fn sort_key(&self) -> Option<i128> {
+        if !self.date.is_valid() {
+            return None;
+        }
+
+        let date: YmdDate = self.date.into();
+        Some(
+            i128::from(days_from_civil(date.year.into(), date.month, date.day)) * 86_400_000_000_000 +
+            i128::from(self.time.nanos_of_day()) -
+            i128::from(self.time.timezone) * 60_000_000_000
+        )
+    }
+
+    /// Whether both are the same instant regardless of their offsets and date representations,
+    /// as by [`sort_key`](Self::sort_key), so a leap second is the same as the second after it.
+    ///
+    /// `false` if either date is not valid.
+    pub fn same_instant<E>(&self, other: &DateTime<E, GlobalTime>) -> bool
+    where E: Datelike + Valid + Copy + Into<YmdDate> {
+        self.sort_key().is_some() && self.sort_key() == other.sort_key()
+    }
+
+    /// Whether both have the same date and time of day regardless of their offsets and date representations.
+    ///
+    /// `false` if either date is not valid.
+    pub fn same_local<E>(&self, other: &DateTime<E, GlobalTime>) -> bool
+    where E: Datelike + Valid + Copy + Into<YmdDate> {
+        self.date.is_valid() && other.date.is_valid() &&
+        self.date.into() == other.date.into() &&
+        self.time.local == other.time.local
+    }
+
+    /// Fixed-width big-endian encoding of the [`sort_key`](Self::sort_key)
+    /// whose byte order is chronological order,
+    /// as for keys of ordered key-value stores.
+    ///
+    /// A leap second encodes like the first second of the next day.
+    /// `None` if the date is not valid.
+    pub fn to_lexicographic_key(&self) -> Option<[u8; LEXICOGRAPHIC_KEY_LEN]> {
+        // offset by half the range so that negative keys sort first
+        let key = (self.sort_key()? + (1 << (LEXICOGRAPHIC_KEY_LEN * 8 - 1))) as u128;
+        let mut bytes = [0; LEXICOGRAPHIC_KEY_LEN];
+        bytes.copy_from_slice(&key.to_be_bytes()[16 - LEXICOGRAPHIC_KEY_LEN..]);
+        Some(bytes)
+    }
+}
+
+/// Width of [`DateTime::to_lexicographic_key`].
+pub const LEXICOGRAPHIC_KEY_LEN: usize = 12;
+
+impl DateTime {
+    /// Inverse of [`to_lexicographic_key`](Self::to_lexicographic_key) in UTC,
+    /// `None` if the year does not fit.
+    pub fn from_lexicographic_key(key: [u8; LEXICOGRAPHIC_KEY_LEN]) -> Option<Self> {
+        let mut bytes = [0; 16];
+        bytes[16 - LEXICOGRAPHIC_KEY_LEN..].copy_from_slice(&key);
+        let nanos = u128::from_be_bytes(bytes) as i128 - (1 << (LEXICOGRAPHIC_KEY_LEN * 8 - 1));
+        Self::checked_from_unix(
+            i64::try_from(nanos.div_euclid(1_000_000_000)).ok()?,
+            nanos.rem_euclid(1_000_000_000) as u32
+        )
+    }
+}
+
+macro_rules! with {
+    ($(#[$attr:meta])* $name:ident, $ty:ty, $($field:ident).+) => {
+        $(#[$attr])*
+        pub fn $name(mut self, value: $ty) -> Result<Self, ()> {
+            self.$($field).+ = value;
+            if self.is_valid() { Ok(self) } else { Err(()) }
+        }
+    }
+}
+
+/// Component accessors and setters that fail
+/// if the result would not be valid.
+impl DateTime {
+    /// The earliest datetime in UTC.
+    pub const MIN: Self = DateTime {
+        date: YmdDate::MIN,
+        time: GlobalTime::MIDNIGHT_UTC
+    };
+
+    /// 1970-01-01T00:00:00Z
+    pub const UNIX_EPOCH: Self = DateTime {
+        date: YmdDate::UNIX_EPOCH,
+        time: GlobalTime::MIDNIGHT_UTC
+    };
+
+    /// The latest datetime in UTC, not counting leap seconds.
+    pub const MAX: Self = DateTime {
+        date: YmdDate::MAX,
+        time: GlobalTime {
+            local: LocalTime {
+                naive: HmsTime { hour: 23, minute: 59, second: 59 },
+                fraction: 0.999_999_94
+            },
+            timezone: 0
+        }
+    };
+
+    pub fn year  (&self) -> i16 { self.date.year }
+    pub fn month (&self) -> u8  { self.date.month }
+    pub fn day   (&self) -> u8  { self.date.day }
+
+    /// Difference from UTC in minutes.
+    pub fn offset(&self) -> i16 { self.time.timezone }
+
+    with!(with_year,   i16, date.year);
+    with!(with_month,  u8,  date.month);
+    with!(with_day,    u8,  date.day);
+    with!(with_hour,   u8,  time.local.naive.hour);
+    with!(with_minute, u8,  time.local.naive.minute);
+    with!(with_second, u8,  time.local.naive.second);
+    with!(
+        /// Keeps the local time, changing the instant.
+        with_offset, i16, time.timezone
+    );
+}
+
+impl DateTime<Date, AnyTime> {
+    /// Parses by the strictest grammar that matches the whole input,
+    /// see [`datetime_best`](::parse::datetime_best).
+    pub fn parse_best(s: &str) -> Result<(Self, ::parse::Conformance), ::parse::ParseError> {
+        ::parse::datetime_best(s.as_bytes())
+    }
+}
+
+/// Ordinal date with a decimal fraction of the day like `2024-123.75`,
+/// as in some scientific formats.
+///
+/// This is not part of ISO 8601,
+/// so parsing it is opt-in with [`ordinal_decimal_day`](::parse::ordinal_decimal_day).
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct OrdinalDateTime {
+    pub date: ODate,
+    /// Fraction of the day in `0..1`.
+    pub fraction: f64
+}
+
+impl_fromstr_parse!(OrdinalDateTime, ordinal_decimal_day);
+
+impl OrdinalDateTime {
+    /// The time of day the fraction stands for,
+    /// to the microsecond.
+    pub fn local_time(&self) -> LocalTime {
+        let micros = ((self.fraction * 86_400e6).round() as u64).min(86_400_000_000 - 1);
+        let (local, _) = LocalTime::from_seconds_of_day_overflowing(micros / 1_000_000);
+        LocalTime {
+            fraction: (micros % 1_000_000) as f32 / 1e6,
+            ..local
+        }
+    }
+
+    pub fn to_datetime(&self) -> DateTime<ODate, LocalTime> {
+        DateTime {
+            date: self.date,
+            time: self.local_time()
+        }
+    }
+}
+
+impl From<DateTime<ODate, LocalTime>> for OrdinalDateTime {
+    fn from(datetime: DateTime<ODate, LocalTime>) -> Self {
+        OrdinalDateTime {
+            date: datetime.date,
+            fraction: datetime.time.nanos_of_day() as f64 / 86_400e9
+        }
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
 pub enum PartialDateTime<D = ApproxDate, T = ApproxAnyTime>
 where D: Datelike, T: Timelike {
     Date(D),
@@ -55,3 +302,148 @@ where D: Datelike, T: Timelike {
 }
 
 impl_fromstr_parse!(PartialDateTime<ApproxDate, ApproxAnyTime>, partial_datetime_approx_any_approx);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn promotion() {
+        let expected = DateTime { date: YmdDate { year: 2018, month: 8, day: 2 }, ..DateTime::UNIX_EPOCH };
+        for date in &["2018-08-02", "2018-W31-4", "2018-214"] {
+            let date: Date = date.parse().unwrap();
+            assert_eq!(date.at_midnight_utc(), Some(expected));
+        }
+
+        let time: LocalTime = "13:42:02.5".parse().unwrap();
+        let date: Date = "2018-W31-4".parse().unwrap();
+        assert_eq!(date.at(time, 120).unwrap().to_string(), "2018-08-02T13:42:02.5+02:00");
+
+        for date in &["2018-400", "2018-W60-1", "2018-02-30"] {
+            let date: Date = date.parse().unwrap();
+            assert_eq!(date.at_midnight_utc(), None);
+            assert_eq!(date.at(time, 120), None);
+        }
+    }
+
+    #[test]
+    fn equality() {
+        let dt = |s: &str| s.parse::<DateTime<Date>>().unwrap();
+        let a = dt("2018-08-02T13:42:02+02:00");
+
+        assert!(a.same_instant(&dt("2018-08-02T11:42:02Z")));
+        assert!(a.same_instant(&dt("2018-W31-4T13:42:02+02:00")));
+        assert!(!a.same_instant(&dt("2018-08-02T13:42:02Z")));
+        assert_ne!(a, dt("2018-08-02T11:42:02Z"));
+
+        assert!(a.same_local(&dt("2018-08-02T13:42:02Z")));
+        assert!(a.same_local(&dt("2018-214T13:42:02-05:00")));
+        assert!(!a.same_local(&dt("2018-08-02T11:42:02Z")));
+        assert_ne!(a, dt("2018-08-02T13:42:02Z"));
+
+        let ymd = DateTime { date: YmdDate { year: 2018, month: 8, day: 2 }, time: a.time };
+        assert!(ymd.same_instant(&a) && ymd.same_local(&a));
+        assert!(a.same_instant(&a) && a.same_local(&a));
+
+        let invalid = dt("2018-400T13:42:02+02:00");
+        assert!(!invalid.same_instant(&invalid) && !invalid.same_local(&invalid));
+        assert!(!a.same_instant(&invalid) && !invalid.same_local(&a));
+    }
+
+    #[test]
+    fn from_parts() {
+        let expected: DateTime<Date, GlobalTime> = "2018-08-02T13:42:02+02:00".parse().unwrap();
+        assert_eq!(DateTime::from_parts("2018-08-02", "13:42:02+02:00"),  Ok(expected));
+        assert_eq!(DateTime::from_parts("2018-08-02", "T13:42:02+02:00"), Ok(expected));
+        assert_eq!(DateTime::new(expected.date, expected.time), Ok(expected));
+
+        assert_eq!(DateTime::<Date, GlobalTime>::from_parts("2018-08-02", "13:42:02"),       Err(()));
+        assert_eq!(DateTime::<Date, GlobalTime>::from_parts("2018-02-30", "13:42:02Z"),      Err(()));
+        assert_eq!(DateTime::<Date, GlobalTime>::from_parts("2018-08-02", "25:42:02Z"),      Err(()));
+        assert_eq!(DateTime::<YmdDate, LocalTime>::from_parts("2018-W31-4", "13:42:02"),     Err(()));
+        assert_eq!(
+            DateTime::<YmdDate, LocalTime>::from_parts("2018-08-02", "13:42:02"),
+            Ok(DateTime { date: YmdDate { year: 2018, month: 8, day: 2 }, time: expected.time.local })
+        );
+    }
+
+    #[test]
+    fn sort_key() {
+        let key = |x: &str| x.parse::<DateTime<Date>>().unwrap().sort_key().unwrap();
+
+        assert_eq!(key("1970-01-01T00:00:00Z"), 0);
+        assert_eq!(key("1970-01-01T01:00:00.5+01:00"), 500_000_000);
+        assert_eq!(key("2018-W31-4T13:42:02Z"), key("2018-08-02T15:42:02+02:00"));
+        assert_eq!(key("2018-214T13:42:02Z"),   key("2018-08-02T13:42:02Z"));
+        assert!(key("1969-12-31T23:59:59Z") < 0);
+        assert!(key("2018-08-02T13:42:02+00:01") < key("2018-08-02T13:42:02Z"));
+
+        for invalid in &["2018-400T13:42:02Z", "2018-W60-1T13:42:02Z", "2018-02-30T13:42:02Z"] {
+            assert_eq!(invalid.parse::<DateTime<Date>>().unwrap().sort_key(), None, "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn default() {
+        let epoch: DateTime = Default::default();
+        assert_eq!(epoch, DateTime::UNIX_EPOCH);
+        assert_eq!(epoch.to_string(), "1970-01-01T00:00:00Z");
+        assert_eq!(epoch.sort_key(), Some(0));
+        assert_eq!(YmdDate::<i64>::default(), YmdDate { year: 1970, month: 1, day: 1 });
+        assert_eq!(LocalTime::<HmTime>::default().to_string(), "00:00");
+    }
+
+    #[test]
+    fn lexicographic_key() {
+        let key = |x: &str| x.parse::<DateTime<Date>>().unwrap().to_lexicographic_key().unwrap();
+
+        let mut keys = [
+            key("2018-08-02T13:42:02+02:00"),
+            key("-0001-12-31T23:59:59Z"),
+            key("1969-12-31T23:59:59.999Z"),
+            key("2018-08-02T11:42:02.5Z"),
+            key("1970-01-01T00:00:00Z")
+        ];
+        keys.sort();
+        assert_eq!(keys, [
+            key("-0001-12-31T23:59:59Z"),
+            key("1969-12-31T23:59:59.999Z"),
+            key("1970-01-01T00:00:00Z"),
+            key("2018-08-02T13:42:02+02:00"),
+            key("2018-08-02T11:42:02.5Z")
+        ]);
+
+        assert_eq!(DateTime::from_lexicographic_key(key("2018-W31-4T13:42:02+02:00")).unwrap().to_string(), "2018-08-02T11:42:02Z");
+        assert_eq!(DateTime::from_lexicographic_key(DateTime::MIN.to_lexicographic_key().unwrap()), Some(DateTime::MIN));
+        assert_eq!(DateTime::from_lexicographic_key([0xff; LEXICOGRAPHIC_KEY_LEN]), None);
+    }
+
+    #[test]
+    fn ordinal_date_time() {
+        let x: OrdinalDateTime = "2024-123.75".parse().unwrap();
+        assert_eq!(x.date, ODate { year: 2024, day: 123 });
+        assert_eq!(x.local_time(), LocalTime {
+            naive: HmsTime { hour: 18, minute: 0, second: 0 },
+            fraction: 0.
+        });
+        assert_eq!(OrdinalDateTime::from(x.to_datetime()), x);
+
+        let x: OrdinalDateTime = "2024123.5000058".parse().unwrap();
+        assert_eq!(x.local_time().naive, HmsTime { hour: 12, minute: 0, second: 0 });
+        assert!((x.local_time().fraction - 0.50112).abs() < 1e-6);
+    }
+
+    #[test]
+    fn with() {
+        let dt = DateTime {
+            date: YmdDate { year: 2024, month: 1, day: 31 },
+            time: GlobalTime::utc(13, 42, 2)
+        };
+        let dt = dt.with_hour(23).and_then(|x| x.with_offset(60)).unwrap();
+        assert_eq!((dt.hour(), dt.offset()), (23, 60));
+        assert_eq!(dt.with_month(2), Err(()));
+        assert_eq!(dt.with_day(29).and_then(|x| x.with_month(2)).map(|x| x.month()), Ok(2));
+        assert_eq!(dt.with_year(2023).map(|x| x.year()), Ok(2023));
+        assert_eq!(dt.with_minute(60), Err(()));
+    }
+}
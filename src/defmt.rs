@@ -0,0 +1,104 @@
+#![cfg(feature = "defmt")]
+
+//! Compact [`defmt::Format`] implementations for logging on embedded targets.
+//!
+//! Components are sent as integers and rendered in ISO 8601 notation
+//! by the host, so no formatting code ends up on the device.
+
+extern crate defmt;
+
+use {
+    date::*,
+    time::*,
+    datetime::*,
+    duration::*
+};
+use self::defmt::{
+    Format,
+    Formatter,
+    write
+};
+
+impl Format for YmdDate {
+    fn format(&self, f: Formatter) {
+        write!(f, "{=i16}-{=u8:02}-{=u8:02}", self.year, self.month, self.day)
+    }
+}
+
+impl Format for WdDate {
+    fn format(&self, f: Formatter) {
+        write!(f, "{=i16}-W{=u8:02}-{=u8}", self.year, self.week, self.day)
+    }
+}
+
+impl Format for ODate {
+    fn format(&self, f: Formatter) {
+        write!(f, "{=i16}-{=u16:03}", self.year, self.day)
+    }
+}
+
+impl Format for Date {
+    fn format(&self, f: Formatter) {
+        match self {
+            Date::YMD(date) => date.format(f),
+            Date::WD (date) => date.format(f),
+            Date::O  (date) => date.format(f)
+        }
+    }
+}
+
+impl Format for LocalTime {
+    fn format(&self, f: Formatter) {
+        write!(f, "{=u8:02}:{=u8:02}:{=u8:02}", self.naive.hour, self.naive.minute, self.naive.second);
+        let nanosecond = self.nanosecond();
+        if nanosecond != 0 {
+            write!(f, ".{=u32:09}", nanosecond);
+        }
+    }
+}
+
+impl Format for GlobalTime {
+    fn format(&self, f: Formatter) {
+        self.local.format(f);
+        if self.timezone == 0 {
+            write!(f, "Z");
+        } else {
+            let sign = if self.timezone < 0 { '-' } else { '+' };
+            let minutes = self.timezone.unsigned_abs();
+            write!(f, "{=char}{=u16:02}:{=u16:02}", sign, minutes / 60, minutes % 60);
+        }
+    }
+}
+
+impl Format for AnyTime {
+    fn format(&self, f: Formatter) {
+        match self {
+            AnyTime::Global(time) => time.format(f),
+            AnyTime::Local (time) => time.format(f)
+        }
+    }
+}
+
+impl<D, T> Format for DateTime<D, T>
+where
+    D: Datelike + Format,
+    T: Timelike + Format
+{
+    fn format(&self, f: Formatter) {
+        write!(f, "{}T{}", self.date, self.time)
+    }
+}
+
+impl Format for Duration {
+    fn format(&self, f: Formatter) {
+        if self.negative {
+            write!(f, "-");
+        }
+        write!(
+            f,
+            "P{=u32}Y{=u32}M{=u32}W{=u32}DT{=u32}H{=u32}M{=u32}.{=u32:09}S",
+            self.years, self.months, self.weeks, self.days,
+            self.hours, self.minutes, self.seconds, self.nanoseconds
+        )
+    }
+}
@@ -0,0 +1,762 @@
+use {
+    Valid,
+    date::*,
+    std::{
+        cmp::Ordering,
+        convert::TryFrom,
+        fmt,
+        ops::{
+            Add,
+            Sub,
+            Mul,
+            Div,
+            Neg
+        },
+        time
+    }
+};
+
+pub(crate) const NANOS_PER_SEC:  u128 = 1_000_000_000;
+pub(crate) const NANOS_PER_MIN:  u128 = 60 * NANOS_PER_SEC;
+pub(crate) const NANOS_PER_HOUR: u128 = 60 * NANOS_PER_MIN;
+pub(crate) const NANOS_PER_DAY:  u128 = 24 * NANOS_PER_HOUR;
+
+/// Duration (4.4.3.2)
+///
+/// Weeks are only written as such (4.4.4.2.2)
+/// if there are no other components,
+/// otherwise they are written as days,
+/// unless [`DurationFormatOptions`] say otherwise.
+///
+/// Years, months, weeks, and days are nominal:
+/// their accurate length depends on where the duration is applied.
+/// Hence durations only compare equal if
+/// their months (including years), days (including weeks), and time agree.
+/// [`calendar`](Self::calendar) and [`exact`](Self::exact) take them apart
+/// into types that do not mix.
+///
+/// A negative duration (ISO 8601-2) points backwards in time
+/// with all of its components.
+#[derive(Eq, Clone, Debug, Default)]
+pub struct Duration {
+    pub years: u32,
+    pub months: u32,
+    pub weeks: u32,
+    pub days: u32,
+    pub hours: u32,
+    pub minutes: u32,
+    pub seconds: u32,
+    pub nanoseconds: u32,
+    pub negative: bool
+}
+
+impl_fromstr_parse!(Duration, duration);
+
+/// The nominal components of a [`Duration`],
+/// which only have a length where they are applied.
+///
+/// There is deliberately no conversion into a [`TimeDuration`],
+/// as a month is not 30 days, see [`Duration::resolve_from`].
+#[derive(Eq, PartialEq, Clone, Copy, Default, Debug)]
+pub struct CalendarDuration {
+    pub years: u32,
+    pub months: u32,
+    pub weeks: u32,
+    pub days: u32
+}
+
+/// The accurate components of a [`Duration`].
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Default, Debug)]
+pub struct TimeDuration {
+    pub secs: u64,
+    /// Below one second
+    pub nanos: u32
+}
+
+impl CalendarDuration {
+    /// Component-wise, `None` on overflow.
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(Self {
+            years:  self.years .checked_add(other.years)?,
+            months: self.months.checked_add(other.months)?,
+            weeks:  self.weeks .checked_add(other.weeks)?,
+            days:   self.days  .checked_add(other.days)?
+        })
+    }
+
+    /// Adds months (including years) on the calendar,
+    /// clamping the day to the end of the month,
+    /// then days (including weeks).
+    ///
+    /// `None` if the date is not valid or the year overflows.
+    pub fn checked_add_to(&self, date: &YmdDate) -> Option<YmdDate> {
+        if !date.is_valid() {
+            return None;
+        }
+
+        let months = i64::from(self.years) * 12 + i64::from(self.months);
+        let days = i64::from(self.weeks) * 7 + i64::from(self.days);
+        add_months(date, months)?.checked_add_days(days)
+    }
+}
+
+impl TimeDuration {
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        let nanos = self.nanos + other.nanos;
+        Some(Self {
+            secs: self.secs
+                .checked_add(other.secs)?
+                .checked_add(u64::from(nanos / NANOS_PER_SEC as u32))?,
+            nanos: nanos % NANOS_PER_SEC as u32
+        })
+    }
+
+    pub(crate) fn from_nanos(nanos: u128) -> Option<Self> {
+        Some(Self {
+            secs: u64::try_from(nanos / NANOS_PER_SEC).ok()?,
+            nanos: (nanos % NANOS_PER_SEC) as u32
+        })
+    }
+
+    fn as_nanos(&self) -> u128 {
+        u128::from(self.secs) * NANOS_PER_SEC + u128::from(self.nanos)
+    }
+}
+
+impl Add for CalendarDuration {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.checked_add(&rhs).expect("overflow when adding durations")
+    }
+}
+
+impl Add for TimeDuration {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.checked_add(&rhs).expect("overflow when adding durations")
+    }
+}
+
+impl From<TimeDuration> for time::Duration {
+    fn from(duration: TimeDuration) -> Self {
+        Self::new(duration.secs, duration.nanos)
+    }
+}
+
+impl From<time::Duration> for TimeDuration {
+    fn from(duration: time::Duration) -> Self {
+        Self {
+            secs: duration.as_secs(),
+            nanos: duration.subsec_nanos()
+        }
+    }
+}
+
+impl Valid for Duration {
+    fn is_valid(&self) -> bool {
+        u128::from(self.nanoseconds) < NANOS_PER_SEC
+    }
+}
+
+impl Duration {
+    pub fn from_weeks(weeks: u32) -> Self {
+        Self {
+            weeks,
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn total_months(&self) -> u64 {
+        u64::from(self.years) * 12 + u64::from(self.months)
+    }
+
+    pub(crate) fn total_days(&self) -> u64 {
+        u64::from(self.weeks) * 7 + u64::from(self.days)
+    }
+
+    pub(crate) fn total_nanos(&self) -> u128 {
+        u128::from(self.hours)   * NANOS_PER_HOUR +
+        u128::from(self.minutes) * NANOS_PER_MIN +
+        u128::from(self.seconds) * NANOS_PER_SEC +
+        u128::from(self.nanoseconds)
+    }
+
+    pub(crate) fn from_totals(months: u64, days: u64, nanos: u128) -> Option<Self> {
+        Some(Self {
+            years:       u32::try_from(months / 12).ok()?,
+            months:      (months % 12) as u32,
+            weeks:       0,
+            days:        u32::try_from(days).ok()?,
+            hours:       u32::try_from(nanos / NANOS_PER_HOUR).ok()?,
+            minutes:     (nanos / NANOS_PER_MIN % 60) as u32,
+            seconds:     (nanos / NANOS_PER_SEC % 60) as u32,
+            nanoseconds: (nanos % NANOS_PER_SEC) as u32,
+            negative:    false
+        })
+    }
+
+    /// Months, days, and nanoseconds carrying the sign.
+    fn signed_totals(&self) -> [i128; 3] {
+        let sign = if self.negative { -1 } else { 1 };
+        [
+            sign * i128::from(self.total_months()),
+            sign * i128::from(self.total_days()),
+            sign * self.total_nanos() as i128
+        ]
+    }
+
+    /// `None` if the totals do not share a sign.
+    fn from_signed_totals(totals: [i128; 3]) -> Option<Self> {
+        let negative = totals.iter().any(|&x| x < 0);
+        if negative && totals.iter().any(|&x| x > 0) {
+            return None;
+        }
+
+        Self::from_totals(
+            u64::try_from(totals[0].unsigned_abs()).ok()?,
+            u64::try_from(totals[1].unsigned_abs()).ok()?,
+            totals[2].unsigned_abs()
+        ).map(|duration| Self { negative, ..duration })
+    }
+
+    /// Carries over seconds into minutes, minutes into hours, and months into years,
+    /// so that `PT90M` becomes `PT1H30M`.
+    /// Weeks become days.
+    ///
+    /// Hours are not carried into days since days are nominal.
+    ///
+    /// Panics if the hours overflow.
+    pub fn normalized(&self) -> Self {
+        Self::from_totals(self.total_months(), self.total_days(), self.total_nanos())
+            .map(|duration| Self { negative: self.negative, ..duration })
+            .expect("overflow when normalizing duration")
+    }
+
+    /// The years, months, weeks, and days, without the sign.
+    pub fn calendar(&self) -> CalendarDuration {
+        CalendarDuration {
+            years:  self.years,
+            months: self.months,
+            weeks:  self.weeks,
+            days:   self.days
+        }
+    }
+
+    /// The hours, minutes, and seconds, without the sign.
+    pub fn exact(&self) -> TimeDuration {
+        TimeDuration::from_nanos(self.total_nanos()).expect("hours fit into seconds")
+    }
+
+    /// The exact time is written normalized.
+    ///
+    /// `None` if its hours overflow.
+    pub fn from_calendar_and_exact(calendar: CalendarDuration, exact: TimeDuration, negative: bool) -> Option<Self> {
+        let CalendarDuration { years, months, weeks, days } = calendar;
+        Self::from_totals(0, 0, exact.as_nanos())
+            .map(|time| Self { years, months, weeks, days, negative, ..time })
+    }
+
+    /// Whether there are only hours, minutes, and seconds,
+    /// which have an accurate length.
+    pub fn is_time_only(&self) -> bool {
+        self.total_months() == 0 &&
+        self.total_days() == 0
+    }
+
+    /// Adds component-wise if both have the same sign.
+    /// Otherwise adds months (including years), days (including weeks), and time separately,
+    /// returning `None` if their signs disagree, and the result is normalized.
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        if self.negative != other.negative {
+            return self.checked_sub(&-other.clone());
+        }
+
+        let nanoseconds = self.nanoseconds.checked_add(other.nanoseconds)?;
+        Some(Self {
+            years:       self.years  .checked_add(other.years)?,
+            months:      self.months .checked_add(other.months)?,
+            weeks:       self.weeks  .checked_add(other.weeks)?,
+            days:        self.days   .checked_add(other.days)?,
+            hours:       self.hours  .checked_add(other.hours)?,
+            minutes:     self.minutes.checked_add(other.minutes)?,
+            seconds:     self.seconds.checked_add(other.seconds)?
+                .checked_add(nanoseconds / NANOS_PER_SEC as u32)?,
+            nanoseconds: nanoseconds % NANOS_PER_SEC as u32,
+            negative:    self.negative
+        })
+    }
+
+    /// Subtracts months (including years), days (including weeks), and time separately,
+    /// returning `None` if the signs of the differences disagree.
+    ///
+    /// The result is normalized.
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        let (a, b) = (self.signed_totals(), other.signed_totals());
+        Self::from_signed_totals([a[0] - b[0], a[1] - b[1], a[2] - b[2]])
+    }
+
+    pub fn checked_mul(&self, rhs: u32) -> Option<Self> {
+        let nanoseconds = u64::from(self.nanoseconds) * u64::from(rhs);
+        Some(Self {
+            years:       self.years  .checked_mul(rhs)?,
+            months:      self.months .checked_mul(rhs)?,
+            weeks:       self.weeks  .checked_mul(rhs)?,
+            days:        self.days   .checked_mul(rhs)?,
+            hours:       self.hours  .checked_mul(rhs)?,
+            minutes:     self.minutes.checked_mul(rhs)?,
+            seconds:     self.seconds.checked_mul(rhs)?
+                .checked_add(u32::try_from(nanoseconds / NANOS_PER_SEC as u64).ok()?)?,
+            nanoseconds: (nanoseconds % NANOS_PER_SEC as u64) as u32,
+            negative:    self.negative
+        })
+    }
+
+    /// Divides months (including years), days (including weeks), and time separately,
+    /// truncating the remainders.
+    ///
+    /// The result is normalized.
+    pub fn checked_div(&self, rhs: u32) -> Option<Self> {
+        if rhs == 0 {
+            return None;
+        }
+
+        Self::from_totals(
+            self.total_months() / u64::from(rhs),
+            self.total_days() / u64::from(rhs),
+            self.total_nanos() / u128::from(rhs)
+        ).map(|duration| Self { negative: self.negative, ..duration })
+    }
+
+    /// Builds a duration from the number and optional decimal fraction
+    /// of each of years, months, days, hours, minutes, and seconds, in that order.
+    ///
+    /// Only the lowest order component present may have a fraction.
+    /// Fractions of years must amount to whole months,
+    /// fractions of months are not supported,
+    /// and fractions of days count a day as 24 hours.
+    pub(crate) fn from_parts(parts: [Option<(u32, Option<f64>)>; 6]) -> Option<Self> {
+        let lowest = parts.iter().rposition(Option::is_some)?;
+        if parts[..lowest].iter().flatten().any(|(_, fraction)| fraction.is_some()) {
+            return None;
+        }
+
+        let value = |i: usize| parts[i].map_or(0, |(value, _)| value);
+        let mut duration = Self {
+            years:   value(0),
+            months:  value(1),
+            weeks:   0,
+            days:    value(2),
+            hours:   value(3),
+            minutes: value(4),
+            seconds: value(5),
+            nanoseconds: 0,
+            negative: false
+        };
+
+        let fraction = match parts[lowest].and_then(|(_, fraction)| fraction) {
+            Some(fraction) => fraction,
+            None => return Some(duration)
+        };
+
+        let unit = match lowest {
+            0 => {
+                let months = fraction * 12.;
+                if (months - months.round()).abs() > 1e-9 {
+                    return None;
+                }
+                duration.months = duration.months.checked_add(months.round() as u32)?;
+                return Some(duration);
+            }
+            1 => return None,
+            2 => NANOS_PER_DAY,
+            3 => NANOS_PER_HOUR,
+            4 => NANOS_PER_MIN,
+            _ => NANOS_PER_SEC
+        };
+
+        let nanos = (fraction * unit as f64).round() as u128;
+        duration.checked_add(&Self::from_totals(0, 0, nanos)?)
+    }
+}
+
+impl PartialEq for Duration {
+    fn eq(&self, other: &Self) -> bool {
+        self.signed_totals() == other.signed_totals()
+    }
+}
+
+impl PartialOrd for Duration {
+    /// Durations are only comparable if
+    /// their months (including years), days (including weeks), and time
+    /// are all greater or equal or all less or equal.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let (a, b) = (self.signed_totals(), other.signed_totals());
+
+        [a[0].cmp(&b[0]), a[1].cmp(&b[1]), a[2].cmp(&b[2])].iter().try_fold(Ordering::Equal, |acc, &ord| match (acc, ord) {
+            (acc, Ordering::Equal) => Some(acc),
+            (Ordering::Equal, ord) => Some(ord),
+            (acc, ord) if acc == ord => Some(acc),
+            _ => None
+        })
+    }
+}
+
+impl Add for Duration {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.checked_add(&rhs).expect("overflow when adding durations")
+    }
+}
+
+impl Sub for Duration {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.checked_sub(&rhs).expect("overflow when subtracting durations")
+    }
+}
+
+impl Neg for Duration {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            negative: !self.negative,
+            ..self
+        }
+    }
+}
+
+impl Mul<u32> for Duration {
+    type Output = Self;
+
+    fn mul(self, rhs: u32) -> Self {
+        self.checked_mul(rhs).expect("overflow when multiplying duration by scalar")
+    }
+}
+
+impl Div<u32> for Duration {
+    type Output = Self;
+
+    fn div(self, rhs: u32) -> Self {
+        self.checked_div(rhs).expect("divide by zero error when dividing duration by scalar")
+    }
+}
+
+impl TryFrom<Duration> for time::Duration {
+    type Error = ();
+
+    /// Fails if there are nominal components or the duration is negative.
+    fn try_from(duration: Duration) -> Result<Self, Self::Error> {
+        if !duration.is_time_only() || duration.negative {
+            return Err(());
+        }
+
+        let nanos = duration.total_nanos();
+        Ok(Self::new(
+            u64::try_from(nanos / NANOS_PER_SEC).or(Err(()))?,
+            (nanos % NANOS_PER_SEC) as u32
+        ))
+    }
+}
+
+impl TryFrom<time::Duration> for Duration {
+    type Error = ();
+
+    /// The result is normalized.
+    /// Fails if the hours overflow.
+    fn try_from(duration: time::Duration) -> Result<Self, Self::Error> {
+        Self::from_totals(0, 0, duration.as_nanos()).ok_or(())
+    }
+}
+
+/// How to write the weeks of a [`Duration`].
+#[derive(Eq, PartialEq, Clone, Copy, Default, Debug)]
+pub enum WeekForm {
+    /// As `PnW` if there are no other components, otherwise as days (4.4.4.2.2)
+    #[default]
+    Alone,
+    /// Always as days, as XSD requires
+    Days,
+    /// As a component before the days, as ISO 8601-2 permits
+    Component
+}
+
+/// Which component of a [`Duration`] carries the decimal fraction.
+#[derive(Eq, PartialEq, Clone, Copy, Default, Debug)]
+pub enum FractionPlacement {
+    /// The seconds, as XSD requires
+    #[default]
+    Seconds,
+    /// The highest order component that the lower ones
+    /// are an exact fraction of, as in `PT1.5H` for an hour and 30 minutes
+    Highest
+}
+
+/// Choices for writing durations that the standard leaves open,
+/// so that strings satisfy stricter grammars.
+///
+/// The default writes the fewest designators, as `Display` does.
+#[derive(Eq, PartialEq, Clone, Copy, Default, Debug)]
+pub struct DurationFormatOptions {
+    /// Write all components, including those that are zero,
+    /// so that the fraction is always on the seconds.
+    pub zero_components: bool,
+    pub weeks: WeekForm,
+    pub fraction: FractionPlacement
+}
+
+impl DurationFormatOptions {
+    pub fn zero_components(mut self, zero: bool) -> Self {
+        self.zero_components = zero;
+        self
+    }
+
+    pub fn weeks(mut self, form: WeekForm) -> Self {
+        self.weeks = form;
+        self
+    }
+
+    pub fn fraction(mut self, placement: FractionPlacement) -> Self {
+        self.fraction = placement;
+        self
+    }
+}
+
+/// A [`Duration`] to write with options, see [`Duration::display_with`].
+#[derive(Clone, Copy, Debug)]
+pub struct DisplayDuration<'a> {
+    duration: &'a Duration,
+    options: DurationFormatOptions
+}
+
+impl Duration {
+    pub fn display_with(&self, options: DurationFormatOptions) -> DisplayDuration<'_> {
+        DisplayDuration {
+            duration: self,
+            options
+        }
+    }
+}
+
+impl fmt::Display for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.display_with(DurationFormatOptions::default()).fmt(f)
+    }
+}
+
+impl<'a> fmt::Display for DisplayDuration<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (duration, options) = (self.duration, self.options);
+        let all = options.zero_components;
+
+        if duration.negative {
+            f.write_str("-")?;
+        }
+        f.write_str("P")?;
+
+        if options.weeks == WeekForm::Alone && !all &&
+            duration.weeks != 0 && duration == &Duration::from_weeks(duration.weeks) {
+            return write!(f, "{}W", duration.weeks);
+        }
+
+        let (weeks, days) = match options.weeks {
+            WeekForm::Component => (Some(u64::from(duration.weeks)), u64::from(duration.days)),
+            _ => (None, duration.total_days())
+        };
+        for &(value, designator) in &[
+            (Some(u64::from(duration.years)),  'Y'),
+            (Some(u64::from(duration.months)), 'M'),
+            (weeks, 'W'),
+            (Some(days), 'D')
+        ] {
+            match value {
+                Some(value) if value != 0 || all => write!(f, "{}{}", value, designator)?,
+                _ => {}
+            }
+        }
+
+        if !(all || duration.is_time_only() || duration.total_nanos() != 0) {
+            return Ok(());
+        }
+        f.write_str("T")?;
+
+        let components = [
+            (duration.hours,   NANOS_PER_HOUR, 'H'),
+            (duration.minutes, NANOS_PER_MIN,  'M'),
+            (duration.seconds, NANOS_PER_SEC,  'S')
+        ];
+        // the lowest order component to write and the nanoseconds below it
+        let (mut lowest, mut below) = (2, u128::from(duration.nanoseconds));
+        if options.fraction == FractionPlacement::Highest && !all {
+            while lowest > 0 {
+                let (value, unit, _) = components[lowest];
+                let (upper, upper_unit, _) = components[lowest - 1];
+                let below_upper = u128::from(value) * unit + below;
+                if upper == 0 || below_upper >= upper_unit || !(below_upper * NANOS_PER_SEC).is_multiple_of(upper_unit) {
+                    break;
+                }
+                lowest -= 1;
+                below = below_upper;
+            }
+        }
+
+        for (i, &(value, unit, designator)) in components[..=lowest].iter().enumerate() {
+            let fraction = if i == lowest { below * NANOS_PER_SEC / unit } else { 0 };
+            // a zero duration still needs a component
+            let required = all || (i == 2 && duration.total_nanos() == 0);
+            if value == 0 && fraction == 0 && !required {
+                continue;
+            }
+
+            write!(f, "{}", value)?;
+            if fraction != 0 {
+                let fraction = format!("{:09}", fraction);
+                write!(f, ".{}", fraction.trim_end_matches('0'))?;
+            }
+            write!(f, "{}", designator)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn d(s: &str) -> Duration {
+        Duration::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn display() {
+        for s in &[
+            "P1Y2M3DT4H5M6S",
+            "P1Y",
+            "P3D",
+            "PT4H",
+            "PT0.5S",
+            "P1DT0.000000001S",
+            "P2W",
+            "PT0S",
+            "-P1DT0.5S"
+        ] {
+            assert_eq!(d(s).to_string(), *s);
+        }
+    }
+
+    #[test]
+    fn parts() {
+        let duration = Duration { weeks: 3, ..d("-P1Y2M4DT25H0.5S") };
+        assert_eq!(duration.calendar(), CalendarDuration { years: 1, months: 2, weeks: 3, days: 4 });
+        assert_eq!(duration.exact(), TimeDuration { secs: 90_000, nanos: 500_000_000 });
+        assert_eq!(Duration::from_calendar_and_exact(duration.calendar(), duration.exact(), true), Some(duration));
+
+        let one = TimeDuration { secs: 1, nanos: 600_000_000 };
+        assert_eq!(one + one, TimeDuration { secs: 3, nanos: 200_000_000 });
+        assert_eq!(time::Duration::from(one + one), time::Duration::from_millis(3200));
+        assert_eq!(TimeDuration { secs: u64::MAX, nanos: 0 }.checked_add(&one), None);
+
+        let month = CalendarDuration { months: 1, ..Default::default() };
+        let jan31 = YmdDate { year: 2024, month: 1, day: 31 };
+        assert_eq!(month.checked_add_to(&jan31), Some(YmdDate { year: 2024, month: 2, day: 29 }));
+        assert_eq!(
+            (month + CalendarDuration { weeks: 1, days: 1, ..Default::default() }).checked_add_to(&jan31),
+            Some(YmdDate { year: 2024, month: 3, day: 8 })
+        );
+    }
+
+    #[test]
+    fn display_with() {
+        let with = |s: &str, options: DurationFormatOptions| d(s).display_with(options).to_string();
+        let default = DurationFormatOptions::default();
+        let all = default.zero_components(true);
+        let highest = default.fraction(FractionPlacement::Highest);
+
+        assert_eq!(with("P1Y2M3DT4H5M6S", default), "P1Y2M3DT4H5M6S");
+        assert_eq!(with("P1Y", all), "P1Y0M0DT0H0M0S");
+        assert_eq!(with("PT0.5S", all), "P0Y0M0DT0H0M0.5S");
+        assert_eq!(with("P2W", all), "P0Y0M14DT0H0M0S");
+        assert_eq!(with("P2W", default.weeks(WeekForm::Days)), "P14D");
+        let mixed = Duration { weeks: 1, days: 3, ..Default::default() };
+        assert_eq!(mixed.to_string(), "P10D");
+        assert_eq!(mixed.display_with(default.weeks(WeekForm::Component)).to_string(), "P1W3D");
+        assert_eq!(mixed.display_with(all.weeks(WeekForm::Component)).to_string(), "P0Y0M1W3DT0H0M0S");
+
+        assert_eq!(with("PT1H30M", highest), "PT1.5H");
+        assert_eq!(with("PT1M30S", highest), "PT1.5M");
+        assert_eq!(with("PT1H1M30S", highest), "PT1.025H");
+        assert_eq!(with("PT1H1M20S", highest), "PT1H1M20S");
+        assert_eq!(with("PT1H1M6S", highest), "PT1H1.1M");
+        assert_eq!(with("PT1H20M", highest), "PT1H20M");
+        assert_eq!(with("PT1H0.5S", highest), "PT1H0.5S");
+        assert_eq!(with("PT30S", highest), "PT30S");
+        assert_eq!(with("PT90S", highest), "PT90S");
+        assert_eq!(with("PT0S", highest), "PT0S");
+        assert_eq!(with("-P1DT1H15M", highest), "-P1DT1.25H");
+        assert_eq!(with("PT1H30M", highest.zero_components(true)), "P0Y0M0DT1H30M0S");
+    }
+
+    #[test]
+    fn weeks() {
+        assert_eq!(d("P2W"), Duration::from_weeks(2));
+        assert_eq!(d("P2W"), d("P14D"));
+        assert_eq!((d("P1W") + d("P1D")).to_string(), "P8D");
+        assert_eq!((d("P1W") + d("PT1H")).to_string(), "P7DT1H");
+        assert_eq!(d("P0.5W"), d("P3DT12H"));
+    }
+
+    #[test]
+    fn normalized() {
+        assert_eq!(d("PT90M").normalized().to_string(), "PT1H30M");
+        assert_eq!(d("P14MT3601S").normalized().to_string(), "P1Y2MT1H1S");
+        assert_eq!(d("P40DT25H").normalized().to_string(), "P40DT25H");
+        assert_eq!(d("PT90M"), d("PT1H30M"));
+        assert!(d("P1D") != d("PT24H"));
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(d("P1Y2DT0.6S") + d("P1MT0.5S"), d("P1Y1M2DT1.1S"));
+        assert_eq!(d("P1YT1H") - d("P1MT30M"), d("P11MT30M"));
+        assert_eq!(d("P1D").checked_sub(&d("PT1H")), None);
+        assert_eq!(d("P1MT0.6S") * 3, d("P3MT1.8S"));
+        assert_eq!(d("P1Y3DT1H") / 2, d("P6M1DT30M"));
+        assert_eq!(d("P1D").checked_div(0), None);
+    }
+
+    #[test]
+    fn negative() {
+        assert_eq!(-d("P1D"), d("-P1D"));
+        assert_eq!(d("PT1H") - d("PT2H30M"), d("-PT1H30M"));
+        assert_eq!(d("-PT1H") + d("PT30M"), d("-PT30M"));
+        assert_eq!(d("-PT1H") + d("-PT30M"), d("-PT1H30M"));
+        assert_eq!(d("P1D").checked_add(&d("-PT1H")), None);
+        assert_eq!(d("-P1MT1S") * 2, d("-P2MT2S"));
+        assert_eq!(d("-PT0S"), d("PT0S"));
+        assert!(d("-PT1H") < d("PT0S"));
+        assert!(d("-PT1H") < d("-PT59M"));
+        assert_eq!(time::Duration::try_from(d("-PT1H")), Err(()));
+    }
+
+    #[test]
+    fn partial_cmp() {
+        assert!(d("PT1H") > d("PT59M"));
+        assert!(d("P1MT1H") > d("P1M"));
+        assert!(d("P1Y") >= d("P12M"));
+        assert_eq!(d("P1M").partial_cmp(&d("P30D")), None);
+        assert_eq!(d("P1DT1H").partial_cmp(&d("P2D")), None);
+    }
+
+    #[test]
+    fn std_duration() {
+        assert_eq!(time::Duration::try_from(d("PT1H0.5S")), Ok(time::Duration::new(3600, 500_000_000)));
+        assert_eq!(time::Duration::try_from(d("P1DT1H")), Err(()));
+        assert_eq!(Duration::try_from(time::Duration::from_secs(5400)).map(|d| d.to_string()), Ok("PT1H30M".to_string()));
+    }
+}
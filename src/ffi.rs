@@ -0,0 +1,142 @@
+#![cfg(feature = "ffi")]
+
+//! C interface, see `include/iso8601.h`.
+//!
+//! The types here are plain old data with a stable layout
+//! so that they can be shared with C and C++ as they are.
+//! Link against the library built with
+//! `cargo rustc --release --features ffi --crate-type staticlib`.
+
+use {
+    Valid,
+    date::*,
+    time::*,
+    datetime::*,
+    std::{
+        ffi::CStr,
+        os::raw::c_char
+    }
+};
+
+/// Calendar date and time of day, flattened.
+#[repr(C)]
+#[derive(PartialEq, Clone, Copy, Default, Debug)]
+pub struct Iso8601DateTime {
+    pub year: i16,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    /// 60 for a leap second.
+    pub second: u8,
+    pub nanosecond: u32,
+    /// Whether `offset_minutes` was given or the time is local.
+    pub has_offset: bool,
+    /// Difference from UTC in minutes.
+    pub offset_minutes: i16
+}
+
+#[repr(C)]
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub enum Iso8601Error {
+    Ok = 0,
+    NullPointer,
+    InvalidUtf8,
+    /// The input does not follow the grammar.
+    Syntax,
+    /// The input continues after the date and time.
+    TrailingInput,
+    /// The date or time does not exist, such as February 30th.
+    Invalid
+}
+
+impl From<DateTime<Date, AnyTime>> for Iso8601DateTime {
+    fn from(datetime: DateTime<Date, AnyTime>) -> Self {
+        let date = YmdDate::from(datetime.date);
+        let (local, offset) = match datetime.time {
+            AnyTime::Global(time) => (time.local, Some(time.timezone)),
+            AnyTime::Local(time)  => (time, None)
+        };
+
+        Iso8601DateTime {
+            year: date.year,
+            month: date.month,
+            day: date.day,
+            hour: local.naive.hour,
+            minute: local.naive.minute,
+            second: local.naive.second,
+            nanosecond: local.nanosecond(),
+            has_offset: offset.is_some(),
+            offset_minutes: offset.unwrap_or(0)
+        }
+    }
+}
+
+fn parse_datetime(input: &[u8]) -> Result<Iso8601DateTime, Iso8601Error> {
+    let input = ::std::str::from_utf8(input).or(Err(Iso8601Error::InvalidUtf8))?;
+    let (rest, datetime) = ::parse::datetime_any_hms(input.as_bytes())
+        .or(Err(Iso8601Error::Syntax))?;
+    if !rest.is_empty() {
+        return Err(Iso8601Error::TrailingInput);
+    }
+    if !datetime.is_valid() {
+        return Err(Iso8601Error::Invalid);
+    }
+    Ok(datetime.into())
+}
+
+/// Parses a complete date and time from a nul-terminated string.
+///
+/// `out` is only written to on success.
+///
+/// # Safety
+///
+/// `input` must be nul-terminated and `out` must be writable.
+#[no_mangle]
+pub unsafe extern "C" fn iso8601_parse_datetime(input: *const c_char, out: *mut Iso8601DateTime) -> Iso8601Error {
+    if input.is_null() || out.is_null() {
+        return Iso8601Error::NullPointer;
+    }
+
+    match parse_datetime(CStr::from_ptr(input).to_bytes()) {
+        Ok(datetime) => {
+            *out = datetime;
+            Iso8601Error::Ok
+        }
+        Err(e) => e
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ptr;
+
+    fn parse(input: &[u8]) -> (Iso8601Error, Iso8601DateTime) {
+        let mut out = Iso8601DateTime::default();
+        let e = unsafe { iso8601_parse_datetime(input.as_ptr() as *const c_char, &mut out) };
+        (e, out)
+    }
+
+    #[test]
+    fn parse_datetime() {
+        assert_eq!(parse(b"2018-W31-4T13:42:02.5+02:00\0"), (Iso8601Error::Ok, Iso8601DateTime {
+            year: 2018,
+            month: 8,
+            day: 2,
+            hour: 13,
+            minute: 42,
+            second: 2,
+            nanosecond: 500_000_000,
+            has_offset: true,
+            offset_minutes: 120
+        }));
+        assert!(!parse(b"2018-08-02T13:42:02\0").1.has_offset);
+
+        assert_eq!(parse(b"2018-08-02\0").0,             Iso8601Error::Syntax);
+        assert_eq!(parse(b"2018-08-02T13:42:02Z \0").0,  Iso8601Error::TrailingInput);
+        assert_eq!(parse(b"2018-02-30T13:42:02Z\0").0,   Iso8601Error::Invalid);
+        assert_eq!(parse(b"2018-08-02T13:42:0\xff\0").0, Iso8601Error::InvalidUtf8);
+        assert_eq!(unsafe { iso8601_parse_datetime(ptr::null(), ptr::null_mut()) }, Iso8601Error::NullPointer);
+    }
+}
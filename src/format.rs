@@ -0,0 +1,1469 @@
+use {
+    Valid,
+    date::*,
+    time::*,
+    datetime::*,
+    interval::*,
+    names::*,
+    std::{
+        borrow::Borrow,
+        convert::{
+            TryFrom,
+            TryInto
+        },
+        fmt::{
+            self,
+            Display,
+            Write
+        },
+        io
+    }
+};
+
+/// A single component of a format description.
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub enum Item<'a> {
+    /// Verbatim text
+    Literal(&'a str),
+    /// `%Y`: calendar year with at least four digits,
+    /// prefixed with a sign if negative or expanded
+    Year,
+    /// `%C`: century, two digits
+    Century,
+    /// `%m`: month, two digits
+    Month,
+    /// `%d`: day of month, two digits
+    Day,
+    /// `%G`: year of the week date
+    WeekYear,
+    /// `%V`: week of year, two digits
+    Week,
+    /// `%u`: day of week, one digit (Monday is 1)
+    Weekday,
+    /// `%a`: English abbreviation of the day of week
+    #[cfg(feature = "names")]
+    WeekdayAbbreviation,
+    /// `%A`: English name of the day of week
+    #[cfg(feature = "names")]
+    WeekdayName,
+    /// `%b`: English abbreviation of the month
+    #[cfg(feature = "names")]
+    MonthAbbreviation,
+    /// `%B`: English name of the month
+    #[cfg(feature = "names")]
+    MonthName,
+    /// `%j`: day of year, three digits
+    Ordinal,
+    /// `%H`: hour, two digits
+    Hour,
+    /// `%M`: minute, two digits
+    Minute,
+    /// `%S`: second, two digits
+    Second,
+    /// `%.f`, `%.3f`: decimal fraction of the lowest order time component
+    /// with as many digits as needed (omitted if zero) or exactly as many as given.
+    Fraction(Option<usize>),
+    /// `%z`, `%:z`, `%Z`: offset from UTC
+    Offset(OffsetFormat),
+    /// An invalid specifier, fails formatting
+    Error
+}
+
+/// How to write an offset from UTC.
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub enum OffsetFormat {
+    /// `+hhmm`
+    Basic,
+    /// `+hh:mm`
+    Extended,
+    /// `Z` for UTC, otherwise `+hh:mm`
+    Utc
+}
+
+/// Typographic choices for formatting that the standard leaves open.
+///
+/// The default writes ASCII with uppercase designators.
+#[derive(Eq, PartialEq, Clone, Copy, Default, Debug)]
+pub struct FormatOptions {
+    /// Write the minus sign (U+2212) instead of the hyphen-minus
+    /// for negative years, centuries, and offsets, as the standard typesets it.
+    /// The parsers read both.
+    pub unicode_minus: bool,
+    /// Write the designator `Z` and letters of literals in lowercase,
+    /// by ASCII rules regardless of the locale, as RFC 3339 permits.
+    pub lowercase: bool,
+    /// Write years as the year of the era and the era after the whole value,
+    /// as in `15.03.0044 BCE`, instead of the astronomical numbering
+    /// of the standard, as in `15.03.-0043`.
+    /// The parsers do not read this.
+    pub era: bool
+}
+
+impl FormatOptions {
+    pub fn unicode_minus(mut self, unicode: bool) -> Self {
+        self.unicode_minus = unicode;
+        self
+    }
+
+    pub fn lowercase(mut self, lowercase: bool) -> Self {
+        self.lowercase = lowercase;
+        self
+    }
+
+    pub fn era(mut self, era: bool) -> Self {
+        self.era = era;
+        self
+    }
+
+    fn minus(&self) -> char {
+        if self.unicode_minus { '\u{2212}' } else { '-' }
+    }
+}
+
+/// Iterator over the items of a strftime-like pattern.
+#[derive(Clone, Debug)]
+pub struct StrftimeItems<'a> {
+    remainder: &'a str
+}
+
+impl<'a> StrftimeItems<'a> {
+    pub fn new(pattern: &'a str) -> Self {
+        Self { remainder: pattern }
+    }
+}
+
+impl<'a> Iterator for StrftimeItems<'a> {
+    type Item = Item<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rem = self.remainder;
+        if rem.is_empty() {
+            return None;
+        }
+
+        if !rem.starts_with('%') {
+            let end = rem.find('%').unwrap_or(rem.len());
+            self.remainder = &rem[end..];
+            return Some(Item::Literal(&rem[..end]));
+        }
+
+        let spec = &rem[1..];
+        let (item, len) = match spec.as_bytes() {
+            [b'%', ..] => (Item::Literal("%"), 1),
+            [b'Y', ..] => (Item::Year,     1),
+            [b'C', ..] => (Item::Century,  1),
+            [b'm', ..] => (Item::Month,    1),
+            [b'd', ..] => (Item::Day,      1),
+            [b'G', ..] => (Item::WeekYear, 1),
+            [b'V', ..] => (Item::Week,     1),
+            [b'u', ..] => (Item::Weekday,  1),
+            [b'j', ..] => (Item::Ordinal,  1),
+            #[cfg(feature = "names")]
+            [b'a', ..] => (Item::WeekdayAbbreviation, 1),
+            #[cfg(feature = "names")]
+            [b'A', ..] => (Item::WeekdayName,         1),
+            #[cfg(feature = "names")]
+            [b'b', ..] => (Item::MonthAbbreviation,   1),
+            #[cfg(feature = "names")]
+            [b'B', ..] => (Item::MonthName,           1),
+            [b'H', ..] => (Item::Hour,     1),
+            [b'M', ..] => (Item::Minute,   1),
+            [b'S', ..] => (Item::Second,   1),
+            [b'z', ..]       => (Item::Offset(OffsetFormat::Basic),    1),
+            [b':', b'z', ..] => (Item::Offset(OffsetFormat::Extended), 2),
+            [b'Z', ..]       => (Item::Offset(OffsetFormat::Utc),      1),
+            [b'.', b'f', ..] => (Item::Fraction(None), 2),
+            [b'.', rest @ ..] => {
+                let digits = rest.iter().take_while(|c| c.is_ascii_digit()).count();
+                match (digits, rest.get(digits)) {
+                    (1 ..= 2, Some(b'f')) => (
+                        Item::Fraction(Some(spec[1 .. 1 + digits].parse().unwrap())),
+                        digits + 2
+                    ),
+                    _ => (Item::Error, 1 + digits)
+                }
+            }
+            _ => (Item::Error, spec.chars().next().map_or(0, char::len_utf8))
+        };
+        self.remainder = &spec[len..];
+        Some(item)
+    }
+}
+
+/// The components of a value that format items draw from.
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct Fields {
+    pub year: Option<i64>,
+    pub century: Option<i8>,
+    pub month: Option<u8>,
+    pub day: Option<u8>,
+    pub week_year: Option<i64>,
+    pub week: Option<u8>,
+    pub weekday: Option<u8>,
+    pub ordinal: Option<u16>,
+    pub hour: Option<u8>,
+    pub minute: Option<u8>,
+    pub second: Option<u8>,
+    /// Decimal fraction of the lowest order time component
+    pub fraction: Option<f32>,
+    /// Difference from UTC in minutes
+    pub timezone: Option<i16>
+}
+
+impl Fields {
+    /// Fills in the fields missing from `self` with those of `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            year:      self.year     .or(other.year),
+            century:   self.century  .or(other.century),
+            month:     self.month    .or(other.month),
+            day:       self.day      .or(other.day),
+            week_year: self.week_year.or(other.week_year),
+            week:      self.week     .or(other.week),
+            weekday:   self.weekday  .or(other.weekday),
+            ordinal:   self.ordinal  .or(other.ordinal),
+            hour:      self.hour     .or(other.hour),
+            minute:    self.minute   .or(other.minute),
+            second:    self.second   .or(other.second),
+            fraction:  self.fraction .or(other.fraction),
+            timezone:  self.timezone .or(other.timezone)
+        }
+    }
+
+    /// Writes the given items, failing if a field they need is missing.
+    pub fn write<'a, W, I>(&self, w: &mut W, items: I) -> fmt::Result where
+        W: Write,
+        I: IntoIterator<Item = Item<'a>>
+    {
+        self.write_options(w, items, FormatOptions::default())
+    }
+
+    /// Like [`write`](Self::write) with the given typography.
+    pub fn write_options<'a, W, I>(&self, w: &mut W, items: I, options: FormatOptions) -> fmt::Result where
+        W: Write,
+        I: IntoIterator<Item = Item<'a>>
+    {
+        let mut era = None;
+        for item in items {
+            match item {
+                Item::Literal(s) if options.lowercase => for c in s.chars() {
+                    w.write_char(c.to_ascii_lowercase())?;
+                },
+                Item::Literal(s)   => w.write_str(s)?,
+                Item::Year         => era = era.or(write_year(w, self.year.ok_or(fmt::Error)?, options)?),
+                Item::Century      => match self.century.ok_or(fmt::Error)? {
+                    c if c < 0 => write!(w, "{}{:02}", options.minus(), -i16::from(c))?,
+                    c          => write!(w, "{:02}", c)?
+                },
+                Item::Month        => write!(w, "{:02}", self.month  .ok_or(fmt::Error)?)?,
+                Item::Day          => write!(w, "{:02}", self.day    .ok_or(fmt::Error)?)?,
+                Item::WeekYear     => era = era.or(write_year(w, self.week_year.ok_or(fmt::Error)?, options)?),
+                Item::Week         => write!(w, "{:02}", self.week   .ok_or(fmt::Error)?)?,
+                Item::Weekday      => write!(w, "{}",    self.weekday.ok_or(fmt::Error)?)?,
+                #[cfg(feature = "names")]
+                Item::WeekdayAbbreviation => w.write_str(self.day_of_week()?.abbreviation())?,
+                #[cfg(feature = "names")]
+                Item::WeekdayName         => w.write_str(self.day_of_week()?.name())?,
+                #[cfg(feature = "names")]
+                Item::MonthAbbreviation   => w.write_str(self.month_of_year()?.abbreviation())?,
+                #[cfg(feature = "names")]
+                Item::MonthName           => w.write_str(self.month_of_year()?.name())?,
+                Item::Ordinal      => write!(w, "{:03}", self.ordinal.ok_or(fmt::Error)?)?,
+                Item::Hour         => write!(w, "{:02}", self.hour   .ok_or(fmt::Error)?)?,
+                Item::Minute       => write!(w, "{:02}", self.minute .ok_or(fmt::Error)?)?,
+                Item::Second       => write!(w, "{:02}", self.second .ok_or(fmt::Error)?)?,
+                Item::Fraction(n)  => write_fraction(w, self.fraction.ok_or(fmt::Error)?, n)?,
+                Item::Offset(form) => write_offset(w, self.timezone.ok_or(fmt::Error)?, form, options)?,
+                Item::Error        => return Err(fmt::Error)
+            }
+        }
+        if let Some(era) = era {
+            write!(w, " {}", era)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "names")]
+impl Fields {
+    fn day_of_week(&self) -> Result<Weekday, fmt::Error> {
+        Weekday::try_from(self.weekday.ok_or(fmt::Error)?).map_err(|_| fmt::Error)
+    }
+
+    fn month_of_year(&self) -> Result<Month, fmt::Error> {
+        Month::try_from(self.month.ok_or(fmt::Error)?).map_err(|_| fmt::Error)
+    }
+}
+
+/// Writes the year, returning its era if the options ask for
+/// the year of the era, for the caller to write after the value.
+fn write_year(w: &mut impl Write, year: i64, options: FormatOptions) -> Result<Option<Era>, fmt::Error> {
+    if options.era {
+        let (era, year) = Era::of_year(year);
+        write!(w, "{:04}", year)?;
+        return Ok(Some(era));
+    }
+
+    match year {
+        0 ..= 9999 => write!(w, "{:04}", year),
+        y if y < 0 => write!(w, "{}{:04}", options.minus(), year.unsigned_abs()),
+        _          => write!(w, "+{}", year)
+    }?;
+    Ok(None)
+}
+
+fn write_fraction(w: &mut impl Write, fraction: f32, digits: Option<usize>) -> fmt::Result {
+    // the shortest representation that round-trips is exactly what was parsed
+    let repr = fraction.to_string();
+    let repr = repr.split('.').nth(1).unwrap_or("");
+    match digits {
+        None if repr.is_empty() => Ok(()),
+        Some(0) => Ok(()),
+        None => write!(w, ".{}", repr),
+        Some(n) => {
+            w.write_char('.')?;
+            for i in 0..n {
+                w.write_char(repr.as_bytes().get(i).map_or('0', |&c| c as char))?;
+            }
+            Ok(())
+        }
+    }
+}
+
+fn write_offset(w: &mut impl Write, timezone: i16, format: OffsetFormat, options: FormatOptions) -> fmt::Result {
+    let sign = if timezone < 0 { options.minus() } else { '+' };
+    let (hour, minute) = (timezone.abs() / 60, timezone.abs() % 60);
+    match format {
+        OffsetFormat::Utc if timezone == 0 => w.write_char(if options.lowercase { 'z' } else { 'Z' }),
+        OffsetFormat::Basic => write!(w, "{}{:02}{:02}", sign, hour, minute),
+        _ => write!(w, "{}{:02}:{:02}", sign, hour, minute)
+    }
+}
+
+/// Keeps the I/O error that `fmt::Write` cannot carry.
+struct IoAdapter<'a, W: 'a> {
+    inner: &'a mut W,
+    error: Option<io::Error>
+}
+
+impl<'a, W: io::Write> Write for IoAdapter<'a, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|e| {
+            self.error = Some(e);
+            fmt::Error
+        })
+    }
+}
+
+/// Values that can be written according to format items.
+pub trait Format {
+    fn fields(&self) -> Fields;
+
+    /// Formats according to a strftime-like pattern.
+    ///
+    /// Fails on display if the pattern needs
+    /// a component that this value does not have.
+    fn format_with<'a>(&'a self, pattern: &'a str) -> Formatted<'a, Self, StrftimeItems<'a>> {
+        self.format_items(StrftimeItems::new(pattern))
+    }
+
+    /// Writes according to a strftime-like pattern
+    /// without an intermediate `String`.
+    fn write_with<W: Write>(&self, w: &mut W, pattern: &str) -> fmt::Result {
+        self.fields().write(w, StrftimeItems::new(pattern))
+    }
+
+    /// Like [`write_with`](Self::write_with) but into bytes.
+    ///
+    /// Fails if the pattern needs a component that this value does not have,
+    /// in which case some output may have been written already.
+    fn write_io_with<W: io::Write>(&self, w: &mut W, pattern: &str) -> io::Result<()> {
+        let mut adapter = IoAdapter { inner: w, error: None };
+        self.write_with(&mut adapter, pattern).map_err(|_| adapter.error.take().unwrap_or_else(||
+            io::Error::new(io::ErrorKind::InvalidData, "pattern needs a missing component")
+        ))
+    }
+
+    /// Formats according to the given items.
+    fn format_items<'a, I>(&'a self, items: I) -> Formatted<'a, Self, I>
+    where I: IntoIterator<Item = Item<'a>> + Clone {
+        Formatted {
+            value: self,
+            items,
+            options: FormatOptions::default()
+        }
+    }
+}
+
+/// A value paired with format items, written by its `Display` implementation.
+#[derive(Clone, Debug)]
+pub struct Formatted<'a, T: ?Sized + 'a, I> {
+    value: &'a T,
+    items: I,
+    options: FormatOptions
+}
+
+impl<'a, T: ?Sized, I> Formatted<'a, T, I> {
+    pub fn options(mut self, options: FormatOptions) -> Self {
+        self.options = options;
+        self
+    }
+}
+
+impl<'a, T, I> Display for Formatted<'a, T, I> where
+    T: ?Sized + Format,
+    I: IntoIterator<Item = Item<'a>> + Clone
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.value.fields().write_options(f, self.items.clone(), self.options)
+    }
+}
+
+fn year<Y>(year: Y) -> Option<i64>
+where Y: TryInto<i64> {
+    year.try_into().ok()
+}
+
+impl<Y> Format for Date<Y> where
+    Y: Year + Clone + TryInto<i64>,
+    YmdDate<Y>: Format,
+    WdDate<Y>: Format,
+    ODate<Y>: Format
+{
+    fn fields(&self) -> Fields {
+        match self {
+            Date::YMD(date) => date.fields(),
+            Date::WD (date) => date.fields(),
+            Date::O  (date) => date.fields()
+        }
+    }
+}
+
+impl<Y> Format for ApproxDate<Y> where
+    Y: Year + Clone + TryInto<i64>,
+    YmdDate<Y>: Format,
+    WdDate<Y>: Format,
+    ODate<Y>: Format
+{
+    fn fields(&self) -> Fields {
+        match self {
+            ApproxDate::YMD(date) => date.fields(),
+            ApproxDate::YM (date) => date.fields(),
+            ApproxDate::Y  (date) => date.fields(),
+            ApproxDate::C  (date) => date.fields(),
+            ApproxDate::WD (date) => date.fields(),
+            ApproxDate::W  (date) => date.fields(),
+            ApproxDate::O  (date) => date.fields()
+        }
+    }
+}
+
+impl<Y> Format for YmdDate<Y> where
+    Y: Year + Clone + TryInto<i64>,
+    WdDate<Y>: From<YmdDate<Y>>,
+    ODate<Y>: From<YmdDate<Y>>
+{
+    fn fields(&self) -> Fields {
+        let fields = Fields {
+            year: year(self.year.clone()),
+            month: Some(self.month),
+            day: Some(self.day),
+            ..Default::default()
+        };
+
+        if !::Valid::is_valid(self) {
+            return fields;
+        }
+
+        let wd = WdDate::from(self.clone());
+        Fields {
+            week_year: year(wd.year),
+            week: Some(wd.week),
+            weekday: Some(wd.day),
+            ordinal: Some(ODate::from(self.clone()).day),
+            ..fields
+        }
+    }
+}
+
+impl<Y> Format for YmDate<Y>
+where Y: Year + Clone + TryInto<i64> {
+    fn fields(&self) -> Fields {
+        Fields {
+            year: year(self.year.clone()),
+            month: Some(self.month),
+            ..Default::default()
+        }
+    }
+}
+
+impl<Y> Format for YDate<Y>
+where Y: Year + Clone + TryInto<i64> {
+    fn fields(&self) -> Fields {
+        Fields {
+            year: year(self.year.clone()),
+            ..Default::default()
+        }
+    }
+}
+
+impl Format for CDate {
+    fn fields(&self) -> Fields {
+        Fields {
+            century: Some(self.century),
+            ..Default::default()
+        }
+    }
+}
+
+impl Format for DayOfYear {
+    fn fields(&self) -> Fields {
+        Fields {
+            ordinal: Some(self.0),
+            ..Default::default()
+        }
+    }
+}
+
+impl<Y> Format for WdDate<Y> where
+    Y: Year + Clone + TryInto<i64>,
+    YmdDate<Y>: From<WdDate<Y>>,
+    ODate<Y>: From<WdDate<Y>>
+{
+    fn fields(&self) -> Fields {
+        let fields = Fields {
+            week_year: year(self.year.clone()),
+            week: Some(self.week),
+            weekday: Some(self.day),
+            ..Default::default()
+        };
+
+        if !::Valid::is_valid(self) {
+            return fields;
+        }
+
+        let ymd = YmdDate::from(self.clone());
+        Fields {
+            year: year(ymd.year),
+            month: Some(ymd.month),
+            day: Some(ymd.day),
+            ordinal: Some(ODate::from(self.clone()).day),
+            ..fields
+        }
+    }
+}
+
+impl<Y> Format for WDate<Y>
+where Y: Year + Clone + TryInto<i64> {
+    fn fields(&self) -> Fields {
+        Fields {
+            week_year: year(self.year.clone()),
+            week: Some(self.week),
+            ..Default::default()
+        }
+    }
+}
+
+impl<Y> Format for ODate<Y> where
+    Y: Year + Clone + TryInto<i64>,
+    YmdDate<Y>: From<ODate<Y>>,
+    WdDate<Y>: From<ODate<Y>>
+{
+    fn fields(&self) -> Fields {
+        let fields = Fields {
+            year: year(self.year.clone()),
+            ordinal: Some(self.day),
+            ..Default::default()
+        };
+
+        if !::Valid::is_valid(self) {
+            return fields;
+        }
+
+        let ymd = YmdDate::from(self.clone());
+        let wd = WdDate::from(self.clone());
+        Fields {
+            month: Some(ymd.month),
+            day: Some(ymd.day),
+            week_year: year(wd.year),
+            week: Some(wd.week),
+            weekday: Some(wd.day),
+            ..fields
+        }
+    }
+}
+
+impl Format for HmsTime {
+    fn fields(&self) -> Fields {
+        Fields {
+            hour: Some(self.hour),
+            minute: Some(self.minute),
+            second: Some(self.second),
+            fraction: Some(0.),
+            ..Default::default()
+        }
+    }
+}
+
+impl Format for HmTime {
+    fn fields(&self) -> Fields {
+        Fields {
+            hour: Some(self.hour),
+            minute: Some(self.minute),
+            fraction: Some(0.),
+            ..Default::default()
+        }
+    }
+}
+
+impl Format for HTime {
+    fn fields(&self) -> Fields {
+        Fields {
+            hour: Some(self.hour),
+            fraction: Some(0.),
+            ..Default::default()
+        }
+    }
+}
+
+impl<N> Format for LocalTime<N>
+where N: NaiveTime + Format {
+    fn fields(&self) -> Fields {
+        Fields {
+            fraction: Some(self.fraction),
+            ..self.naive.fields()
+        }
+    }
+}
+
+impl<N> Format for GlobalTime<N>
+where N: NaiveTime + Format {
+    fn fields(&self) -> Fields {
+        Fields {
+            timezone: Some(self.timezone),
+            ..self.local.fields()
+        }
+    }
+}
+
+impl<N> Format for AnyTime<N>
+where N: NaiveTime + Format {
+    fn fields(&self) -> Fields {
+        match self {
+            AnyTime::Global(time) => time.fields(),
+            AnyTime::Local (time) => time.fields()
+        }
+    }
+}
+
+impl Format for ApproxNaiveTime {
+    fn fields(&self) -> Fields {
+        match self {
+            ApproxNaiveTime::HMS(time) => time.fields(),
+            ApproxNaiveTime::HM (time) => time.fields(),
+            ApproxNaiveTime::H  (time) => time.fields()
+        }
+    }
+}
+
+impl Format for ApproxLocalTime {
+    fn fields(&self) -> Fields {
+        match self {
+            ApproxLocalTime::HMS(time) => time.fields(),
+            ApproxLocalTime::HM (time) => time.fields(),
+            ApproxLocalTime::H  (time) => time.fields()
+        }
+    }
+}
+
+impl Format for ApproxGlobalTime {
+    fn fields(&self) -> Fields {
+        match self {
+            ApproxGlobalTime::HMS(time) => time.fields(),
+            ApproxGlobalTime::HM (time) => time.fields(),
+            ApproxGlobalTime::H  (time) => time.fields()
+        }
+    }
+}
+
+impl Format for ApproxAnyTime {
+    fn fields(&self) -> Fields {
+        match self {
+            ApproxAnyTime::HMS(time) => time.fields(),
+            ApproxAnyTime::HM (time) => time.fields(),
+            ApproxAnyTime::H  (time) => time.fields()
+        }
+    }
+}
+
+impl<D, T> Format for DateTime<D, T> where
+    D: Datelike + Format,
+    T: Timelike + Format
+{
+    fn fields(&self) -> Fields {
+        self.date.fields().or(self.time.fields())
+    }
+}
+
+impl<D, T> Format for PartialDateTime<D, T> where
+    D: Datelike + Format,
+    T: Timelike + Format
+{
+    fn fields(&self) -> Fields {
+        match self {
+            PartialDateTime::Date(date) => date.fields(),
+            PartialDateTime::Time(time) => time.fields(),
+            PartialDateTime::DateTime(dt) => dt.fields()
+        }
+    }
+}
+
+/// Values that can be built from the fields matched by format items.
+pub trait FromFields: Sized {
+    /// Takes only the fields this type consists of,
+    /// returning `None` if any of them are missing.
+    fn from_fields(fields: Fields) -> Option<Self>;
+
+    /// Parses input that matches exactly the given items, rejecting anything else.
+    ///
+    /// Items from a pattern can be obtained via [`StrftimeItems`].
+    fn parse_with<'a, I, B>(s: &str, items: I) -> Result<Self, ()> where
+        I: IntoIterator<Item = B>,
+        B: Borrow<Item<'a>>
+    {
+        ::parse::fields(s.as_bytes(), items)
+            .ok()
+            .and_then(Self::from_fields)
+            .ok_or(())
+    }
+}
+
+fn year_from<Y>(year: Option<i64>) -> Option<Y>
+where Y: TryFrom<i64> {
+    year.and_then(|year| Y::try_from(year).ok())
+}
+
+impl<Y> FromFields for Date<Y>
+where Y: Year + TryFrom<i64> {
+    fn from_fields(fields: Fields) -> Option<Self> {
+        match fields {
+            Fields { month: Some(_), day: Some(_), .. } =>
+                YmdDate::from_fields(fields).map(Date::YMD),
+            Fields { week: Some(_), weekday: Some(_), .. } =>
+                WdDate::from_fields(fields).map(Date::WD),
+            _ =>
+                ODate::from_fields(fields).map(Date::O)
+        }
+    }
+}
+
+impl<Y> FromFields for ApproxDate<Y>
+where Y: Year + TryFrom<i64> {
+    fn from_fields(fields: Fields) -> Option<Self> {
+        match fields {
+            Fields { month: Some(_), day: Some(_), .. } =>
+                YmdDate::from_fields(fields).map(ApproxDate::YMD),
+            Fields { week: Some(_), weekday: Some(_), .. } =>
+                WdDate::from_fields(fields).map(ApproxDate::WD),
+            Fields { ordinal: Some(_), .. } =>
+                ODate::from_fields(fields).map(ApproxDate::O),
+            Fields { month: Some(_), .. } =>
+                YmDate::from_fields(fields).map(ApproxDate::YM),
+            Fields { week: Some(_), .. } =>
+                WDate::from_fields(fields).map(ApproxDate::W),
+            Fields { year: Some(_), .. } =>
+                YDate::from_fields(fields).map(ApproxDate::Y),
+            _ =>
+                CDate::from_fields(fields).map(ApproxDate::C)
+        }
+    }
+}
+
+impl<Y> FromFields for YmdDate<Y>
+where Y: Year + TryFrom<i64> {
+    fn from_fields(fields: Fields) -> Option<Self> {
+        Some(Self {
+            year: year_from(fields.year)?,
+            month: fields.month?,
+            day: fields.day?
+        })
+    }
+}
+
+impl<Y> FromFields for YmDate<Y>
+where Y: Year + TryFrom<i64> {
+    fn from_fields(fields: Fields) -> Option<Self> {
+        Some(Self {
+            year: year_from(fields.year)?,
+            month: fields.month?
+        })
+    }
+}
+
+impl<Y> FromFields for YDate<Y>
+where Y: Year + TryFrom<i64> {
+    fn from_fields(fields: Fields) -> Option<Self> {
+        Some(Self {
+            year: year_from(fields.year)?
+        })
+    }
+}
+
+impl FromFields for CDate {
+    fn from_fields(fields: Fields) -> Option<Self> {
+        Some(Self {
+            century: fields.century?
+        })
+    }
+}
+
+impl<Y> FromFields for WdDate<Y>
+where Y: Year + TryFrom<i64> {
+    fn from_fields(fields: Fields) -> Option<Self> {
+        Some(Self {
+            year: year_from(fields.week_year)?,
+            week: fields.week?,
+            day: fields.weekday?
+        })
+    }
+}
+
+impl<Y> FromFields for WDate<Y>
+where Y: Year + TryFrom<i64> {
+    fn from_fields(fields: Fields) -> Option<Self> {
+        Some(Self {
+            year: year_from(fields.week_year)?,
+            week: fields.week?
+        })
+    }
+}
+
+impl<Y> FromFields for ODate<Y>
+where Y: Year + TryFrom<i64> {
+    fn from_fields(fields: Fields) -> Option<Self> {
+        Some(Self {
+            year: year_from(fields.year)?,
+            day: fields.ordinal?
+        })
+    }
+}
+
+impl FromFields for HmsTime {
+    fn from_fields(fields: Fields) -> Option<Self> {
+        Some(Self {
+            hour: fields.hour?,
+            minute: fields.minute?,
+            second: fields.second?
+        })
+    }
+}
+
+impl FromFields for HmTime {
+    fn from_fields(fields: Fields) -> Option<Self> {
+        Some(Self {
+            hour: fields.hour?,
+            minute: fields.minute?
+        })
+    }
+}
+
+impl FromFields for HTime {
+    fn from_fields(fields: Fields) -> Option<Self> {
+        Some(Self {
+            hour: fields.hour?
+        })
+    }
+}
+
+impl<N> FromFields for LocalTime<N>
+where N: NaiveTime + FromFields {
+    fn from_fields(fields: Fields) -> Option<Self> {
+        Some(Self {
+            fraction: fields.fraction.unwrap_or(0.),
+            naive: N::from_fields(fields)?
+        })
+    }
+}
+
+impl<N> FromFields for GlobalTime<N>
+where N: NaiveTime + FromFields {
+    fn from_fields(fields: Fields) -> Option<Self> {
+        Some(Self {
+            timezone: fields.timezone?,
+            local: LocalTime::from_fields(fields)?
+        })
+    }
+}
+
+impl<N> FromFields for AnyTime<N>
+where N: NaiveTime + FromFields {
+    fn from_fields(fields: Fields) -> Option<Self> {
+        match fields.timezone {
+            Some(_) => GlobalTime::from_fields(fields).map(AnyTime::Global),
+            None    => LocalTime ::from_fields(fields).map(AnyTime::Local)
+        }
+    }
+}
+
+impl FromFields for ApproxLocalTime {
+    fn from_fields(fields: Fields) -> Option<Self> {
+        match fields {
+            Fields { second: Some(_), .. } => LocalTime::from_fields(fields).map(ApproxLocalTime::HMS),
+            Fields { minute: Some(_), .. } => LocalTime::from_fields(fields).map(ApproxLocalTime::HM),
+            _                              => LocalTime::from_fields(fields).map(ApproxLocalTime::H)
+        }
+    }
+}
+
+impl FromFields for ApproxGlobalTime {
+    fn from_fields(fields: Fields) -> Option<Self> {
+        match fields {
+            Fields { second: Some(_), .. } => GlobalTime::from_fields(fields).map(ApproxGlobalTime::HMS),
+            Fields { minute: Some(_), .. } => GlobalTime::from_fields(fields).map(ApproxGlobalTime::HM),
+            _                              => GlobalTime::from_fields(fields).map(ApproxGlobalTime::H)
+        }
+    }
+}
+
+impl FromFields for ApproxAnyTime {
+    fn from_fields(fields: Fields) -> Option<Self> {
+        match fields {
+            Fields { second: Some(_), .. } => AnyTime::from_fields(fields).map(ApproxAnyTime::HMS),
+            Fields { minute: Some(_), .. } => AnyTime::from_fields(fields).map(ApproxAnyTime::HM),
+            _                              => AnyTime::from_fields(fields).map(ApproxAnyTime::H)
+        }
+    }
+}
+
+impl<D, T> FromFields for DateTime<D, T> where
+    D: Datelike + FromFields,
+    T: Timelike + FromFields
+{
+    fn from_fields(fields: Fields) -> Option<Self> {
+        Some(Self {
+            date: D::from_fields(fields.clone())?,
+            time: T::from_fields(fields)?
+        })
+    }
+}
+
+impl<D, T> FromFields for PartialDateTime<D, T> where
+    D: Datelike + FromFields,
+    T: Timelike + FromFields
+{
+    fn from_fields(fields: Fields) -> Option<Self> {
+        let has_time = fields.hour.is_some();
+        match (D::from_fields(fields.clone()), has_time) {
+            (Some(date), true)  => T::from_fields(fields).map(|time| PartialDateTime::DateTime(DateTime { date, time })),
+            (Some(date), false) => Some(PartialDateTime::Date(date)),
+            (None,       _)     => T::from_fields(fields).map(PartialDateTime::Time)
+        }
+    }
+}
+
+macro_rules! impl_display_items {
+    ($ty:ident<Y>, $pattern:expr) => {
+        impl<Y> Display for $ty<Y> where
+            Y: Year,
+            $ty<Y>: Format
+        {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                self.fields().write(f, StrftimeItems::new($pattern))
+            }
+        }
+    };
+    ($ty:ty, $pattern:expr) => {
+        impl Display for $ty {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                self.fields().write(f, StrftimeItems::new($pattern))
+            }
+        }
+    }
+}
+impl_display_items!(YmdDate<Y>, "%Y-%m-%d");
+impl_display_items!(YmDate<Y>,  "%Y-%m");
+impl_display_items!(YDate<Y>,   "%Y");
+impl_display_items!(CDate,      "%C");
+impl_display_items!(WdDate<Y>,  "%G-W%V-%u");
+impl_display_items!(WDate<Y>,   "%G-W%V");
+impl_display_items!(ODate<Y>,   "%Y-%j");
+impl_display_items!(DayOfYear,  "-%j");
+impl_display_items!(HmsTime,    "%H:%M:%S");
+impl_display_items!(HmTime,     "%H:%M");
+impl_display_items!(HTime,      "%H");
+
+impl<Y> Display for Date<Y> where
+    Y: Year,
+    YmdDate<Y>: Display,
+    WdDate<Y>: Display,
+    ODate<Y>: Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Date::YMD(date) => date.fmt(f),
+            Date::WD (date) => date.fmt(f),
+            Date::O  (date) => date.fmt(f)
+        }
+    }
+}
+
+impl<Y> Display for ApproxDate<Y> where
+    Y: Year,
+    YmdDate<Y>: Display,
+    YmDate<Y>: Display,
+    YDate<Y>: Display,
+    WdDate<Y>: Display,
+    WDate<Y>: Display,
+    ODate<Y>: Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ApproxDate::YMD(date) => date.fmt(f),
+            ApproxDate::YM (date) => date.fmt(f),
+            ApproxDate::Y  (date) => date.fmt(f),
+            ApproxDate::C  (date) => date.fmt(f),
+            ApproxDate::WD (date) => date.fmt(f),
+            ApproxDate::W  (date) => date.fmt(f),
+            ApproxDate::O  (date) => date.fmt(f)
+        }
+    }
+}
+
+impl<N> Display for LocalTime<N>
+where N: NaiveTime + Display {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.naive.fmt(f)?;
+        write_fraction(f, self.fraction, None)
+    }
+}
+
+impl<N> Display for GlobalTime<N>
+where N: NaiveTime + Display {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.local.fmt(f)?;
+        write_offset(f, self.timezone, OffsetFormat::Utc, FormatOptions::default())
+    }
+}
+
+impl Display for TimeZoneOffset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.minutes() {
+            Some(minutes) => write_offset(f, minutes, OffsetFormat::Utc, FormatOptions::default()),
+            None => {
+                let sign = if self.seconds < 0 { '-' } else { '+' };
+                let seconds = self.seconds.unsigned_abs();
+                write!(f, "{}{:02}:{:02}:{:02}", sign, seconds / 3600, seconds / 60 % 60, seconds % 60)
+            }
+        }
+    }
+}
+
+impl<N> Display for AnyTime<N>
+where N: NaiveTime + Display {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AnyTime::Global(time) => time.fmt(f),
+            AnyTime::Local (time) => time.fmt(f)
+        }
+    }
+}
+
+impl Display for ApproxNaiveTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ApproxNaiveTime::HMS(time) => time.fmt(f),
+            ApproxNaiveTime::HM (time) => time.fmt(f),
+            ApproxNaiveTime::H  (time) => time.fmt(f)
+        }
+    }
+}
+
+impl Display for ApproxLocalTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ApproxLocalTime::HMS(time) => time.fmt(f),
+            ApproxLocalTime::HM (time) => time.fmt(f),
+            ApproxLocalTime::H  (time) => time.fmt(f)
+        }
+    }
+}
+
+impl Display for ApproxGlobalTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ApproxGlobalTime::HMS(time) => time.fmt(f),
+            ApproxGlobalTime::HM (time) => time.fmt(f),
+            ApproxGlobalTime::H  (time) => time.fmt(f)
+        }
+    }
+}
+
+impl Display for ApproxAnyTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ApproxAnyTime::HMS(time) => time.fmt(f),
+            ApproxAnyTime::HM (time) => time.fmt(f),
+            ApproxAnyTime::H  (time) => time.fmt(f)
+        }
+    }
+}
+
+impl<D, T> Display for DateTime<D, T> where
+    D: Datelike + Display,
+    T: Timelike + Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}T{}", self.date, self.time)
+    }
+}
+
+impl<D, T> Display for PartialDateTime<D, T> where
+    D: Datelike + Display,
+    T: Timelike + Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PartialDateTime::Date(date) => date.fmt(f),
+            PartialDateTime::Time(time) => write!(f, "T{}", time),
+            PartialDateTime::DateTime(dt) => dt.fmt(f)
+        }
+    }
+}
+
+impl<P: Display> Display for Interval<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Interval::StartEnd(start, end) => write!(f, "{}/{}", start, end),
+            Interval::Duration(duration) => duration.fmt(f),
+            Interval::StartDuration(start, duration) => write!(f, "{}/{}", start, duration),
+            Interval::DurationEnd(duration, end) => write!(f, "{}/{}", duration, end)
+        }
+    }
+}
+
+/// How [`DateTime::to_filename_stamp_with`] writes a datetime.
+#[derive(Eq, PartialEq, Clone, Copy, Default, Debug)]
+pub enum StampFormat {
+    /// Basic format like `20240501T123000Z`
+    #[default]
+    Basic,
+    /// Extended format with the given character instead of the colon,
+    /// which Windows does not allow in file names, like `2024-05-01T12-30-00Z`
+    Extended(char)
+}
+
+impl<D> DateTime<D, GlobalTime>
+where D: Datelike + Valid + Copy + Into<YmdDate> {
+    /// The calendar date and time in basic format like `20240501T123000Z`,
+    /// which is safe in file names and sorts chronologically within an offset.
+    ///
+    /// `None` if the date is not valid.
+    pub fn to_filename_stamp(&self) -> Option<String> {
+        self.to_filename_stamp_with(StampFormat::Basic)
+    }
+
+    /// Read by [`filename_stamp_with`](::parse::filename_stamp_with),
+    /// see [`to_filename_stamp`](Self::to_filename_stamp).
+    pub fn to_filename_stamp_with(&self, format: StampFormat) -> Option<String> {
+        if !self.date.is_valid() {
+            return None;
+        }
+
+        let mut stamp = String::new();
+        self.write_filename_stamp(&mut stamp, format).expect("writing to a string does not fail");
+        Some(stamp)
+    }
+
+    fn write_filename_stamp(&self, w: &mut impl Write, format: StampFormat) -> fmt::Result {
+        let mut buf = [0; 4];
+        let (date_separator, time_separator) = match format {
+            StampFormat::Basic => ("", ""),
+            StampFormat::Extended(separator) => ("-", &*separator.encode_utf8(&mut buf))
+        };
+
+        let date: YmdDate = self.date.into();
+        let naive = &self.time.local.naive;
+        write_year(w, date.year.into(), FormatOptions::default())?;
+        write!(w, "{0}{1:02}{0}{2:02}T{3:02}{4}{5:02}{4}{6:02}",
+            date_separator, date.month, date.day,
+            naive.hour, time_separator, naive.minute, naive.second)?;
+        write_fraction(w, self.time.local.fraction, None)?;
+        match self.time.timezone {
+            0 => w.write_char('Z'),
+            timezone => write!(w, "{}{:02}{}{:02}",
+                if timezone < 0 { '-' } else { '+' },
+                timezone.abs() / 60, time_separator, timezone.abs() % 60)
+        }
+    }
+}
+
+/// Values whose canonical representation
+/// can be written with a fixed number of fraction digits.
+pub trait FormatFixed {
+    /// Writes the canonical representation with exactly `digits` fraction digits,
+    /// padded with zeros or truncated, and without a fraction if zero.
+    fn write_fixed<W: Write>(&self, w: &mut W, digits: usize) -> fmt::Result;
+
+    /// Displays with exactly `FRAC_DIGITS` fraction digits,
+    /// as for schemas that demand, say, milliseconds.
+    fn format_fixed<const FRAC_DIGITS: usize>(&self) -> Fixed<'_, Self, FRAC_DIGITS> {
+        Fixed { value: self }
+    }
+}
+
+/// See [`FormatFixed::format_fixed`].
+#[derive(Clone, Copy, Debug)]
+pub struct Fixed<'a, T: ?Sized + 'a, const FRAC_DIGITS: usize> {
+    value: &'a T
+}
+
+impl<'a, T, const FRAC_DIGITS: usize> Display for Fixed<'a, T, FRAC_DIGITS>
+where T: ?Sized + FormatFixed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.value.write_fixed(f, FRAC_DIGITS)
+    }
+}
+
+impl<N> FormatFixed for LocalTime<N>
+where N: NaiveTime + Display {
+    fn write_fixed<W: Write>(&self, w: &mut W, digits: usize) -> fmt::Result {
+        write!(w, "{}", self.naive)?;
+        write_fraction(w, self.fraction, Some(digits))
+    }
+}
+
+impl<N> FormatFixed for GlobalTime<N>
+where N: NaiveTime + Display {
+    fn write_fixed<W: Write>(&self, w: &mut W, digits: usize) -> fmt::Result {
+        self.local.write_fixed(w, digits)?;
+        write_offset(w, self.timezone, OffsetFormat::Utc, FormatOptions::default())
+    }
+}
+
+impl<N> FormatFixed for AnyTime<N>
+where N: NaiveTime + Display {
+    fn write_fixed<W: Write>(&self, w: &mut W, digits: usize) -> fmt::Result {
+        match self {
+            AnyTime::Global(time) => time.write_fixed(w, digits),
+            AnyTime::Local (time) => time.write_fixed(w, digits)
+        }
+    }
+}
+
+impl<D, T> FormatFixed for DateTime<D, T> where
+    D: Datelike + Display,
+    T: Timelike + FormatFixed
+{
+    fn write_fixed<W: Write>(&self, w: &mut W, digits: usize) -> fmt::Result {
+        write!(w, "{}T", self.date)?;
+        self.time.write_fixed(w, digits)
+    }
+}
+
+/// Debug formatting in ISO 8601 notation
+/// instead of the verbose derived output of nested structs,
+/// as in `log::debug!("{:?}", Compact(&datetime))`.
+#[derive(Clone, Copy)]
+pub struct Compact<T>(pub T);
+
+impl<T: Display> fmt::Debug for Compact<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn write_with() {
+        let dt = DateTime::<Date, GlobalTime>::from_bytes(b"2018-08-02T13:42:02.5+02:00").unwrap();
+
+        let mut s = String::from("at ");
+        dt.write_with(&mut s, "%Y-%m-%dT%H:%M:%S%.3f%:z").unwrap();
+        assert_eq!(s, "at 2018-08-02T13:42:02.500+02:00");
+
+        let mut bytes = Vec::new();
+        dt.date.write_io_with(&mut bytes, "%G-W%V-%u").unwrap();
+        assert_eq!(bytes, b"2018-W31-4");
+        assert!(dt.date.write_io_with(&mut bytes, "%H").is_err());
+    }
+
+    #[test]
+    fn format_fixed() {
+        let dt = DateTime::<Date, GlobalTime>::from_str("2018-08-02T13:42:02.5+02:00").unwrap();
+        assert_eq!(dt.format_fixed::<3>().to_string(), "2018-08-02T13:42:02.500+02:00");
+        assert_eq!(dt.format_fixed::<0>().to_string(), "2018-08-02T13:42:02+02:00");
+
+        let time = LocalTime::<HmsTime>::from_str("13:42:02.123456").unwrap();
+        assert_eq!(time.format_fixed::<3>().to_string(), "13:42:02.123");
+        assert_eq!(time.format_fixed::<9>().to_string(), "13:42:02.123456000");
+
+        let dt = DateTime::<Date, AnyTime>::from_str("2018-W31-4T13:42:00Z").unwrap();
+        assert_eq!(dt.format_fixed::<2>().to_string(), "2018-W31-4T13:42:00.00Z");
+    }
+
+    #[test]
+    fn compact() {
+        let dt = DateTime::<Date, GlobalTime>::from_str("2018-08-02T13:42:02.5+02:00").unwrap();
+        assert_eq!(format!("{:?}", Compact(&dt)), "2018-08-02T13:42:02.5+02:00");
+        assert_eq!(format!("{:?}", Some(Compact(dt.date))), "Some(2018-08-02)");
+    }
+
+    #[test]
+    fn strftime_items() {
+        assert_eq!(
+            StrftimeItems::new("%Y-%m-%dT%H:%M:%S%.3f%:z %% %.f%q").collect::<Vec<_>>(),
+            vec![
+                Item::Year, Item::Literal("-"), Item::Month, Item::Literal("-"), Item::Day,
+                Item::Literal("T"),
+                Item::Hour, Item::Literal(":"), Item::Minute, Item::Literal(":"), Item::Second,
+                Item::Fraction(Some(3)),
+                Item::Offset(OffsetFormat::Extended),
+                Item::Literal(" "), Item::Literal("%"), Item::Literal(" "),
+                Item::Fraction(None),
+                Item::Error
+            ]
+        );
+    }
+
+    #[test]
+    fn format_with() {
+        let dt = DateTime::<Date, GlobalTime>::from_str("2018-08-02T13:42:02.5+02:00").unwrap();
+        assert_eq!(dt.format_with("%Y%m%dT%H%M%S%z").to_string(), "20180802T134202+0200");
+        assert_eq!(dt.format_with("%Y-%jT%H:%M:%S%.3f%Z").to_string(), "2018-214T13:42:02.500+02:00");
+        assert_eq!(dt.format_with("%G-W%V-%u").to_string(), "2018-W31-4");
+        assert_eq!(dt.format_with("%.1f|%.f").to_string(), ".5|.5");
+        #[cfg(feature = "names")]
+        assert_eq!(dt.format_with("%a %d %b %Y, %A %B").to_string(), "Thu 02 Aug 2018, Thursday August");
+
+        let date = YmDate { year: 2018, month: 8 };
+        let mut s = String::new();
+        assert!(write!(s, "{}", date.format_with("%Y-%m-%d")).is_err());
+        assert!(write!(s, "{}", date.format_with("%Y-%q")).is_err());
+    }
+
+    #[test]
+    fn format_options() {
+        let typeset = FormatOptions::default().unicode_minus(true);
+        let pattern = "%Y-%m-%dT%H:%M%:z";
+        let s = "\u{2212}0044-03-15T12:00\u{2212}05:30";
+        let dt = DateTime::<Date, GlobalTime<HmTime>>::from_str(s).unwrap();
+        assert_eq!(dt.format_with(pattern).to_string(), "-0044-03-15T12:00-05:30");
+        assert_eq!(dt.format_with(pattern).options(typeset).to_string(), s);
+        assert_eq!(CDate { century: -5 }.format_with("%C").options(typeset).to_string(), "\u{2212}05");
+
+        let dt = DateTime::<Date, GlobalTime>::from_str("2018-08-02T13:42:02Z").unwrap();
+        let lowercase = FormatOptions::default().lowercase(true);
+        assert_eq!(dt.format_with("%Y-%m-%dT%H:%M:%S%Z").options(lowercase).to_string(), "2018-08-02t13:42:02z");
+
+        let era = FormatOptions::default().era(true);
+        assert_eq!(dt.format_with("%Y-%m-%d").options(era).to_string(), "2018-08-02 CE");
+        assert_eq!(dt.format_with("%Y-%m-%dT%H:%M").options(era).to_string(), "2018-08-02T13:42 CE");
+        let date = YmdDate { year: -43, month: 3, day: 15 };
+        assert_eq!(date.format_with("%d.%m.%Y").to_string(), "15.03.-0043");
+        assert_eq!(date.format_with("%d.%m.%Y").options(era).to_string(), "15.03.0044 BCE");
+    }
+
+    #[test]
+    fn year() {
+        assert_eq!(YDate { year:     0 }.to_string(), "0000");
+        assert_eq!(YDate { year:    -5 }.to_string(), "-0005");
+        assert_eq!(YDate { year: 12345 }.to_string(), "+12345");
+    }
+
+    #[test]
+    fn display() {
+        for s in &[
+            "2018-08-02",
+            "2018-08",
+            "2018",
+            "20",
+            "-05",
+            "2018-W31-4",
+            "2018-W31",
+            "2018-214"
+        ] {
+            assert_eq!(ApproxDate::from_str(s).unwrap().to_string(), *s);
+        }
+
+        for s in &[
+            "13:42:02",
+            "13:42:02.25",
+            "13:42",
+            "13:42.5",
+            "13",
+            "13Z",
+            "13:42:02-01:30"
+        ] {
+            assert_eq!(ApproxAnyTime::from_str(s).unwrap().to_string(), *s);
+        }
+
+        for s in &["Z", "-01:30", "+01:23:45"] {
+            assert_eq!(TimeZoneOffset::from_str(s).unwrap().to_string(), *s);
+        }
+
+        assert_eq!(DayOfYear(7).to_string(), "-007");
+
+        assert_eq!(
+            DateTime::<Date, GlobalTime>::from_str("20180802T134202Z").unwrap().to_string(),
+            "2018-08-02T13:42:02Z"
+        );
+        assert_eq!(PartialDateTime::from_str("T12:30").unwrap().to_string(), "T12:30");
+    }
+
+    #[test]
+    fn parse_with() {
+        let items = [
+            Item::Year, Item::Month, Item::Day, Item::Literal("T"),
+            Item::Hour, Item::Minute, Item::Second, Item::Offset(OffsetFormat::Utc)
+        ];
+        let dt = DateTime::<Date, GlobalTime>::from_str("2018-08-02T13:42:02Z").unwrap();
+        assert_eq!(DateTime::parse_with("20180802T134202Z", items), Ok(dt));
+        assert_eq!(DateTime::<Date, GlobalTime>::parse_with("2018-08-02T13:42:02Z", items), Err(()));
+        assert_eq!(DateTime::<Date, GlobalTime>::parse_with("20180802T1342Z", items), Err(()));
+        assert_eq!(
+            DateTime::parse_with("2018-214T13:42:02.5", StrftimeItems::new("%Y-%jT%H:%M:%S%.f")),
+            Ok(DateTime {
+                date: Date::O(ODate {
+                    year: 2018,
+                    day: 214
+                }),
+                time: LocalTime {
+                    naive: HmsTime {
+                        hour: 13,
+                        minute: 42,
+                        second: 2
+                    },
+                    fraction: 0.5
+                }
+            })
+        );
+
+        assert_eq!(
+            ApproxDate::parse_with("2018-W31", StrftimeItems::new("%G-W%V")),
+            Ok(ApproxDate::W(WDate {
+                year: 2018,
+                week: 31
+            }))
+        );
+        assert_eq!(YmdDate::<i16>::parse_with("2018-214", StrftimeItems::new("%Y-%j")), Err(()));
+        assert_eq!(YDate::<u16>::parse_with("-2018", StrftimeItems::new("%Y")), Err(()));
+
+        assert_eq!(
+            PartialDateTime::parse_with("13:42Z", StrftimeItems::new("%H:%M%Z")),
+            Ok(PartialDateTime::<ApproxDate, ApproxAnyTime>::Time(ApproxAnyTime::HM(AnyTime::Global(GlobalTime {
+                local: LocalTime {
+                    naive: HmTime {
+                        hour: 13,
+                        minute: 42
+                    },
+                    fraction: 0.
+                },
+                timezone: 0
+            }))))
+        );
+    }
+}
@@ -0,0 +1,125 @@
+#![cfg(feature = "humanize")]
+
+//! Relative phrases in plain English like "3 days ago" or "in 2 hours",
+//! for command line tools.
+
+use {
+    Valid,
+    date::*,
+    time::*,
+    datetime::*,
+    duration::*,
+    std::fmt
+};
+
+/// A relative phrase naming only the largest nonzero unit, rounded down.
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub struct Humanized {
+    amount: u128,
+    unit: &'static str,
+    past: bool
+}
+
+impl Humanized {
+    fn largest(units: &[(u128, &'static str)], past: bool) -> Self {
+        let (amount, unit) = units.iter()
+            .copied()
+            .find(|&(amount, _)| amount != 0)
+            .unwrap_or((0, "second"));
+        Self { amount, unit, past }
+    }
+
+    /// Whether the phrase lies in the past.
+    pub fn is_past(&self) -> bool {
+        self.past && self.amount != 0
+    }
+}
+
+impl fmt::Display for Humanized {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let plural = if self.amount == 1 { "" } else { "s" };
+        match (self.amount, self.past) {
+            (0, _)     => f.write_str("now"),
+            (_, true)  => write!(f, "{} {}{} ago", self.amount, self.unit, plural),
+            (_, false) => write!(f, "in {} {}{}", self.amount, self.unit, plural)
+        }
+    }
+}
+
+impl Duration {
+    /// Positive durations lie in the future, negative ones in the past.
+    ///
+    /// Weeks and days are told apart but hours are not carried into days
+    /// since days are nominal.
+    pub fn humanize(&self) -> Humanized {
+        let (months, days, nanos) = (self.total_months(), self.total_days(), self.total_nanos());
+        Humanized::largest(&[
+            (u128::from(months / 12),   "year"),
+            (u128::from(months % 12),   "month"),
+            (u128::from(days / 7),      "week"),
+            (u128::from(days % 7),      "day"),
+            (nanos / NANOS_PER_HOUR,    "hour"),
+            (nanos / NANOS_PER_MIN % 60, "minute"),
+            (nanos / NANOS_PER_SEC % 60, "second")
+        ], self.negative)
+    }
+}
+
+impl<D> DateTime<D, GlobalTime>
+where D: Datelike + Valid + Copy + Into<YmdDate> {
+    /// How this instant relates to `now`,
+    /// in the accurate units of weeks and below.
+    ///
+    /// `None` if either date is not valid.
+    pub fn humanize_relative_to(&self, now: &Self) -> Option<Humanized> {
+        let diff = self.sort_key()? - now.sort_key()?;
+        let nanos = diff.unsigned_abs();
+        Some(Humanized::largest(&[
+            (nanos / NANOS_PER_DAY / 7,   "week"),
+            (nanos / NANOS_PER_DAY % 7,   "day"),
+            (nanos / NANOS_PER_HOUR % 24, "hour"),
+            (nanos / NANOS_PER_MIN % 60,  "minute"),
+            (nanos / NANOS_PER_SEC % 60,  "second")
+        ], diff < 0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn h(s: &str) -> String {
+        s.parse::<Duration>().unwrap().humanize().to_string()
+    }
+
+    #[test]
+    fn duration() {
+        assert_eq!(h("P3D"),       "in 3 days");
+        assert_eq!(h("-P3D"),      "3 days ago");
+        assert_eq!(h("PT2H30M"),   "in 2 hours");
+        assert_eq!(h("PT36H"),     "in 36 hours");
+        assert_eq!(h("P1Y6M"),     "in 1 year");
+        assert_eq!(h("P18M"),      "in 1 year");
+        assert_eq!(h("P2W"),       "in 2 weeks");
+        assert_eq!(h("P10D"),      "in 1 week");
+        assert_eq!(h("-PT1S"),     "1 second ago");
+        assert_eq!(h("PT0.5S"),    "now");
+        assert!(!"-PT0S".parse::<Duration>().unwrap().humanize().is_past());
+    }
+
+    #[test]
+    fn datetime() {
+        let now: DateTime<Date> = "2018-08-02T13:42:02Z".parse().unwrap();
+        let h = |s: &str| s.parse::<DateTime<Date>>().unwrap().humanize_relative_to(&now).unwrap().to_string();
+
+        assert_eq!(h("2018-07-30T13:42:02Z"),      "3 days ago");
+        assert_eq!(h("2018-08-02T17:42:02+02:00"), "in 2 hours");
+        assert_eq!(h("2018-08-02T13:42:02+01:00"), "1 hour ago");
+        assert_eq!(h("2018-09-02T13:42:02Z"),      "in 4 weeks");
+        assert_eq!(h("2018-08-02T13:42:02.5Z"),    "now");
+
+        let invalid: DateTime<Date> = "2018-400T13:42:02Z".parse().unwrap();
+        assert_eq!(invalid.humanize_relative_to(&now), None);
+        assert_eq!(now.humanize_relative_to(&invalid), None);
+    }
+}
@@ -0,0 +1,651 @@
+use {
+    Valid,
+    date::*,
+    time::*,
+    datetime::*,
+    duration::*,
+    std::{
+        cmp::Ordering,
+        convert::TryFrom
+    }
+};
+
+const NANOS_PER_SEC: i128 = 1_000_000_000;
+const NANOS_PER_DAY: i128 = 24 * 60 * 60 * NANOS_PER_SEC;
+
+/// Time interval (4.4)
+///
+/// Parsing supports the concise representation of the end (4.4.5)
+/// that omits its higher order components shared with the start,
+/// like `2007-12-14T13:30/15:30`.
+#[derive(PartialEq, Clone, Debug)]
+pub enum Interval<P = DateTime> {
+    /// Start and end (4.4.4.1)
+    StartEnd(P, P),
+    /// Duration only (4.4.4.2)
+    Duration(Duration),
+    /// Start and duration (4.4.4.3)
+    StartDuration(P, Duration),
+    /// Duration and end (4.4.4.4)
+    DurationEnd(Duration, P)
+}
+
+impl_fromstr_parse!(Interval<Date>,                                interval_date);
+impl_fromstr_parse!(Interval<DateTime<Date, GlobalTime<HmsTime>>>, interval_global_hms);
+impl_fromstr_parse!(Interval<DateTime<Date, GlobalTime<HmTime>>>,  interval_global_hm);
+impl_fromstr_parse!(Interval<DateTime<Date, GlobalTime<HTime>>>,   interval_global_h);
+impl_fromstr_parse!(Interval<DateTime<Date, LocalTime<HmsTime>>>,  interval_local_hms);
+impl_fromstr_parse!(Interval<DateTime<Date, LocalTime<HmTime>>>,   interval_local_hm);
+impl_fromstr_parse!(Interval<DateTime<Date, LocalTime<HTime>>>,    interval_local_h);
+impl_fromstr_parse!(Interval<DateTime<Date, AnyTime<HmsTime>>>,    interval_any_hms);
+impl_fromstr_parse!(Interval<DateTime<Date, AnyTime<HmTime>>>,     interval_any_hm);
+impl_fromstr_parse!(Interval<DateTime<Date, AnyTime<HTime>>>,      interval_any_h);
+impl_fromstr_parse!(Interval<DateTime<Date, ApproxGlobalTime>>,    interval_global_approx);
+impl_fromstr_parse!(Interval<DateTime<Date, ApproxLocalTime>>,     interval_local_approx);
+impl_fromstr_parse!(Interval<DateTime<Date, ApproxAnyTime>>,       interval_any_approx);
+
+impl<D> Interval<DateTime<D, GlobalTime>>
+where D: Datelike + Clone + Into<YmdDate> {
+    /// `None` if there is only a duration
+    /// or the start cannot be represented.
+    pub fn start(&self) -> Option<DateTime> {
+        match self {
+            Interval::StartEnd(start, _) |
+            Interval::StartDuration(start, _) => Some(to_ymd(start)),
+            Interval::DurationEnd(duration, end) => to_ymd(end).checked_sub_duration(duration),
+            Interval::Duration(_) => None
+        }
+    }
+
+    /// `None` if there is only a duration
+    /// or the end cannot be represented.
+    pub fn end(&self) -> Option<DateTime> {
+        match self {
+            Interval::StartEnd(_, end) |
+            Interval::DurationEnd(_, end) => Some(to_ymd(end)),
+            Interval::StartDuration(start, duration) => to_ymd(start).checked_add_duration(duration),
+            Interval::Duration(_) => None
+        }
+    }
+
+    /// If `nominal`, whole months and years between start and end
+    /// (on the calendar of the start's timezone) are kept as such,
+    /// otherwise they are resolved into days.
+    /// Days always count as 24 hours.
+    ///
+    /// `None` if the end is before the start,
+    /// either of them is invalid,
+    /// or there is only a duration with months or years to resolve.
+    /// The result is normalized unless it is given nominally.
+    pub fn duration(&self, nominal: bool) -> Option<Duration> {
+        match (self, nominal) {
+            (Interval::Duration(duration), true) |
+            (Interval::StartDuration(_, duration), true) |
+            (Interval::DurationEnd(duration, _), true) => Some(duration.clone()),
+            (Interval::Duration(duration), false) => if duration.total_months() == 0 {
+                Some(duration.normalized())
+            } else {
+                None
+            },
+            _ => {
+                let (start, end) = (self.start()?, self.end()?);
+                if nominal {
+                    nominal_between(&start, &end)
+                } else {
+                    let nanos = u128::try_from(instant(&end)? - instant(&start)?).ok()?;
+                    Duration::from_totals(
+                        0,
+                        u64::try_from(nanos / NANOS_PER_DAY as u128).ok()?,
+                        nanos % NANOS_PER_DAY as u128
+                    )
+                }
+            }
+        }
+    }
+
+    /// Whether the datetime lies at or after the start and before the end.
+    ///
+    /// Always `false` if there is only a duration
+    /// or any of the datetimes is invalid.
+    pub fn contains(&self, datetime: &DateTime<D, GlobalTime>) -> bool {
+        (|| {
+            let (start, end) = self.instants()?;
+            let instant = instant(&to_ymd(datetime))?;
+            Some(start <= instant && instant < end)
+        })().unwrap_or(false)
+    }
+
+    /// Whether both intervals share any instant.
+    ///
+    /// Always `false` if either has only a duration
+    /// or any of the datetimes is invalid.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        (|| {
+            let (start, end) = self.instants()?;
+            let (other_start, other_end) = other.instants()?;
+            Some(start < other_end && other_start < end)
+        })().unwrap_or(false)
+    }
+
+    /// The parts before and after the datetime,
+    /// which may lie at the start or end.
+    ///
+    /// `None` if there is only a duration,
+    /// the datetime lies outside,
+    /// or any of the datetimes is invalid.
+    pub fn split_at(&self, datetime: &DateTime<D, GlobalTime>) -> Option<(Interval, Interval)> {
+        let (start, end) = self.bounds()?;
+        let at = to_ymd(datetime);
+        if !(instant(&start)? ..= instant(&end)?).contains(&instant(&at)?) {
+            return None;
+        }
+
+        Some((Interval::StartEnd(start, at), Interval::StartEnd(at, end)))
+    }
+
+    /// The instants that both intervals share.
+    ///
+    /// `None` if they share none,
+    /// either has only a duration,
+    /// or any of the datetimes is invalid.
+    pub fn intersect(&self, other: &Self) -> Option<Interval> {
+        if !self.overlaps(other) {
+            return None;
+        }
+        self.clamp_to(other)
+    }
+
+    /// Moves the start and end into the other interval,
+    /// leaving no time at its nearest bound if they do not overlap.
+    ///
+    /// `None` if either has only a duration
+    /// or any of the datetimes is invalid.
+    pub fn clamp_to(&self, other: &Self) -> Option<Interval> {
+        let (start, end) = self.bounds()?;
+        let (min, max) = other.bounds()?;
+        let clamp = |x: DateTime| -> Option<DateTime> {
+            let i = instant(&x)?;
+            Some(if i < instant(&min)? { min } else if i > instant(&max)? { max } else { x })
+        };
+        Some(Interval::StartEnd(clamp(start)?, clamp(end)?))
+    }
+
+    /// How this interval relates to the other in Allen's interval algebra.
+    ///
+    /// `None` if either has only a duration,
+    /// ends before it starts,
+    /// or any of the datetimes is invalid.
+    pub fn relation(&self, other: &Self) -> Option<Relation> {
+        let (start, end) = self.instants()?;
+        let (other_start, other_end) = other.instants()?;
+        if end < start || other_end < other_start {
+            return None;
+        }
+
+        Some(match (start.cmp(&other_start), end.cmp(&other_end)) {
+            (Ordering::Equal, Ordering::Equal) => Relation::Equals,
+            _ if end < other_start   => Relation::Before,
+            _ if end == other_start  => Relation::Meets,
+            _ if other_end < start   => Relation::After,
+            _ if other_end == start  => Relation::MetBy,
+            (Ordering::Equal,   Ordering::Less)    => Relation::Starts,
+            (Ordering::Equal,   Ordering::Greater) => Relation::StartedBy,
+            (Ordering::Greater, Ordering::Equal)   => Relation::Finishes,
+            (Ordering::Less,    Ordering::Equal)   => Relation::FinishedBy,
+            (Ordering::Greater, Ordering::Less)    => Relation::During,
+            (Ordering::Less,    Ordering::Greater) => Relation::Contains,
+            (Ordering::Less,    Ordering::Less)    => Relation::Overlaps,
+            (Ordering::Greater, Ordering::Greater) => Relation::OverlappedBy
+        })
+    }
+
+    /// Datetimes from the start by the step until the end,
+    /// which is included if `inclusive` and hit exactly.
+    ///
+    /// Each is the start plus a multiple of the step,
+    /// so months clamped to a shorter month do not drift.
+    ///
+    /// `None` if there is only a duration,
+    /// any of the datetimes is invalid
+    /// or the step does not move forward.
+    pub fn iter_by(&self, step: Duration, inclusive: bool) -> Option<Steps> {
+        let start = self.start()?;
+        let (first, end) = self.instants()?;
+        if instant(&start.checked_add_duration(&step)?)? <= first {
+            return None;
+        }
+
+        Some(Steps {
+            start,
+            end,
+            step,
+            count: Some(0),
+            inclusive
+        })
+    }
+
+    fn bounds(&self) -> Option<(DateTime, DateTime)> {
+        Some((self.start()?, self.end()?))
+    }
+
+    fn instants(&self) -> Option<(i128, i128)> {
+        Some((instant(&self.start()?)?, instant(&self.end()?)?))
+    }
+}
+
+/// The thirteen ways two intervals can relate, after James F. Allen,
+/// read as "this interval ... the other", see [`Interval::relation`].
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub enum Relation {
+    /// Ends before the other starts
+    Before,
+    /// Ends where the other starts
+    Meets,
+    /// Starts first and ends within the other
+    Overlaps,
+    /// Starts with the other and ends first
+    Starts,
+    /// Lies strictly within the other
+    During,
+    /// Ends with the other and starts last
+    Finishes,
+    /// Starts and ends with the other
+    Equals,
+    /// Inverse of [`Before`](Self::Before)
+    After,
+    /// Inverse of [`Meets`](Self::Meets)
+    MetBy,
+    /// Inverse of [`Overlaps`](Self::Overlaps)
+    OverlappedBy,
+    /// Inverse of [`Starts`](Self::Starts)
+    StartedBy,
+    /// Inverse of [`During`](Self::During)
+    Contains,
+    /// Inverse of [`Finishes`](Self::Finishes)
+    FinishedBy
+}
+
+impl Relation {
+    /// The relation of the other interval to this one.
+    pub fn inverse(self) -> Self {
+        match self {
+            Relation::Before       => Relation::After,
+            Relation::Meets        => Relation::MetBy,
+            Relation::Overlaps     => Relation::OverlappedBy,
+            Relation::Starts       => Relation::StartedBy,
+            Relation::During       => Relation::Contains,
+            Relation::Finishes     => Relation::FinishedBy,
+            Relation::Equals       => Relation::Equals,
+            Relation::After        => Relation::Before,
+            Relation::MetBy        => Relation::Meets,
+            Relation::OverlappedBy => Relation::Overlaps,
+            Relation::StartedBy    => Relation::Starts,
+            Relation::Contains     => Relation::During,
+            Relation::FinishedBy   => Relation::Finishes
+        }
+    }
+}
+
+/// Iterator over datetimes in an interval, see [`Interval::iter_by`].
+#[derive(Clone, Debug)]
+pub struct Steps {
+    start: DateTime,
+    end: i128,
+    step: Duration,
+    /// `None` once exhausted.
+    count: Option<u32>,
+    inclusive: bool
+}
+
+impl Iterator for Steps {
+    type Item = DateTime;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let count = self.count?;
+        let next = self.step.checked_mul(count)
+            .and_then(|x| self.start.checked_add_duration(&x))
+            .filter(|x| instant(x).is_some_and(|x| x < self.end || self.inclusive && x == self.end));
+        self.count = next.and(count.checked_add(1));
+        next
+    }
+}
+
+impl DateTime {
+    /// Adds months (including years) on the calendar,
+    /// clamping the day to the end of the month,
+    /// then days (including weeks) and time.
+    /// Negative durations are subtracted.
+    ///
+    /// `None` if the datetime is invalid or the year overflows.
+    pub fn checked_add_duration(&self, duration: &Duration) -> Option<Self> {
+        if duration.negative {
+            return self.checked_sub_duration(&-duration.clone());
+        }
+
+        let date = add_months(&self.date, i64::try_from(duration.total_months()).ok()?)?;
+        let local = local_nanos(&DateTime { date, time: self.time })? +
+            i128::from(duration.total_days()) * NANOS_PER_DAY +
+            i128::try_from(duration.total_nanos()).ok()?;
+        from_local_nanos(local, self.time.timezone)
+    }
+
+    /// Subtracts days (including weeks) and time,
+    /// then months (including years) on the calendar,
+    /// clamping the day to the end of the month.
+    /// Negative durations are added.
+    ///
+    /// `None` if the datetime is invalid or the year overflows.
+    pub fn checked_sub_duration(&self, duration: &Duration) -> Option<Self> {
+        if duration.negative {
+            return self.checked_add_duration(&-duration.clone());
+        }
+
+        let local = local_nanos(self)? -
+            i128::from(duration.total_days()) * NANOS_PER_DAY -
+            i128::try_from(duration.total_nanos()).ok()?;
+        let datetime = from_local_nanos(local, self.time.timezone)?;
+        Some(DateTime {
+            date: add_months(&datetime.date, -i64::try_from(duration.total_months()).ok()?)?,
+            time: datetime.time
+        })
+    }
+}
+
+impl Duration {
+    /// The exact time from the start to the end of the duration
+    /// applied to it, see [`DateTime::checked_add_duration`],
+    /// without the sign.
+    ///
+    /// `None` if the start is invalid or the year overflows.
+    pub fn resolve_from(&self, start: &DateTime) -> Option<TimeDuration> {
+        let end = start.checked_add_duration(self)?;
+        TimeDuration::from_nanos((instant(&end)? - instant(start)?).unsigned_abs())
+    }
+}
+
+fn to_ymd<D>(datetime: &DateTime<D, GlobalTime>) -> DateTime
+where D: Datelike + Clone + Into<YmdDate> {
+    DateTime {
+        date: datetime.date.clone().into(),
+        time: datetime.time
+    }
+}
+
+/// Nanoseconds since 1970-01-01T00:00:00 in the datetime's timezone.
+fn local_nanos(datetime: &DateTime) -> Option<i128> {
+    if !datetime.is_valid() {
+        return None;
+    }
+
+    let date = &datetime.date;
+    Some(
+        i128::from(days_from_civil(date.year.into(), date.month, date.day)) * NANOS_PER_DAY +
+        i128::from(datetime.time.nanos_of_day())
+    )
+}
+
+fn from_local_nanos(nanos: i128, timezone: i16) -> Option<DateTime> {
+    let (year, month, day) = civil_from_days(i64::try_from(nanos.div_euclid(NANOS_PER_DAY)).ok()?);
+    let nanos = nanos.rem_euclid(NANOS_PER_DAY);
+    let secs = nanos / NANOS_PER_SEC;
+
+    Some(DateTime {
+        date: YmdDate {
+            year: i16::try_from(year).ok()?,
+            month,
+            day
+        },
+        time: GlobalTime {
+            local: LocalTime {
+                naive: HmsTime {
+                    hour:   (secs / 3600)    as u8,
+                    minute: (secs / 60 % 60) as u8,
+                    second: (secs % 60)      as u8
+                },
+                fraction: fraction_from_nanos((nanos % NANOS_PER_SEC) as u32)
+            },
+            timezone
+        }
+    })
+}
+
+/// Nanoseconds since 1970-01-01T00:00:00Z.
+fn instant(datetime: &DateTime) -> Option<i128> {
+    if datetime.is_valid() {
+        datetime.sort_key()
+    } else {
+        None
+    }
+}
+
+fn nominal_between(start: &DateTime, end: &DateTime) -> Option<Duration> {
+    // view the end on the calendar of the start
+    let end = from_local_nanos(
+        instant(end)? + i128::from(start.time.timezone) * 60 * NANOS_PER_SEC,
+        start.time.timezone
+    )?;
+    let end_local = local_nanos(&end)?;
+
+    let mut months =
+        (i64::from(end.date.year)   * 12 + i64::from(end.date.month)) -
+        (i64::from(start.date.year) * 12 + i64::from(start.date.month));
+    let shifted = loop {
+        if months < 0 {
+            return None;
+        }
+        let shifted = local_nanos(&start.checked_add_duration(&Duration {
+            months: u32::try_from(months).ok()?,
+            ..Default::default()
+        })?)?;
+        match shifted.cmp(&end_local) {
+            Ordering::Greater => months -= 1,
+            _ => break shifted
+        }
+    };
+
+    let nanos = u128::try_from(end_local - shifted).ok()?;
+    Duration::from_totals(
+        u64::try_from(months).ok()?,
+        u64::try_from(nanos / NANOS_PER_DAY as u128).ok()?,
+        nanos % NANOS_PER_DAY as u128
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn i(s: &str) -> Interval<DateTime<Date, GlobalTime>> {
+        Interval::from_str(s).unwrap()
+    }
+
+    fn dt(s: &str) -> DateTime<Date, GlobalTime> {
+        DateTime::from_str(s).unwrap()
+    }
+
+    fn d(s: &str) -> Duration {
+        Duration::from_str(s).unwrap()
+    }
+
+    fn se(start: &str, end: &str) -> Interval {
+        Interval::StartEnd(to_ymd(&dt(start)), to_ymd(&dt(end)))
+    }
+
+    #[test]
+    fn duration() {
+        let interval = i("2007-03-01T13:00:00Z/2008-05-11T15:30:00Z");
+        assert_eq!(interval.duration(true),  Some(d("P1Y2M10DT2H30M")));
+        assert_eq!(interval.duration(false), Some(d("P437DT2H30M")));
+
+        let interval = i("2018-01-31T00:00:00Z/2018-03-01T00:00:00Z");
+        assert_eq!(interval.duration(true),  Some(d("P1M1D")));
+        assert_eq!(interval.duration(false), Some(d("P29D")));
+
+        let interval = i("2018-01-01T00:00:00+01:00/2018-01-31T23:00:00Z");
+        assert_eq!(interval.duration(true),  Some(d("P1M")));
+
+        let interval = i("2018-01-31T00:00:00Z/P1M");
+        assert_eq!(interval.end().unwrap().to_string(), "2018-02-28T00:00:00Z");
+        assert_eq!(interval.duration(true),  Some(d("P1M")));
+        assert_eq!(interval.duration(false), Some(d("P28D")));
+
+        let interval = i("P1DT12H/2018-03-01T00:00:00Z");
+        assert_eq!(interval.start().unwrap().to_string(), "2018-02-27T12:00:00Z");
+        assert_eq!(interval.duration(false), Some(d("P1DT12H")));
+
+        assert_eq!(i("PT36H").duration(false), Some(d("PT36H")));
+        assert_eq!(i("P1M").duration(false), None);
+        assert_eq!(i("2018-03-01T00:00:00Z/2018-01-01T00:00:00Z").duration(true), None);
+    }
+
+    #[test]
+    fn contains() {
+        let interval = i("2018-08-02T12:00:00Z/PT1H");
+        assert!( interval.contains(&dt("2018-08-02T12:00:00Z")));
+        assert!( interval.contains(&dt("2018-08-02T14:59:59+02:00")));
+        assert!(!interval.contains(&dt("2018-08-02T13:00:00Z")));
+        assert!(!interval.contains(&dt("2018-08-02T11:59:59Z")));
+        assert!(!i("PT1H").contains(&dt("2018-08-02T12:00:00Z")));
+    }
+
+    #[test]
+    fn overlaps() {
+        let interval = i("2018-08-02T12:00:00Z/2018-08-02T13:00:00Z");
+        assert!( interval.overlaps(&i("2018-08-02T12:59:59Z/PT1H")));
+        assert!( interval.overlaps(&i("PT1H/2018-08-02T12:00:01Z")));
+        assert!( interval.overlaps(&i("2018-08-02T12:15:00Z/PT15M")));
+        assert!(!interval.overlaps(&i("2018-08-02T13:00:00Z/PT1H")));
+        assert!(!interval.overlaps(&i("PT1H")));
+    }
+
+    #[test]
+    fn split_at() {
+        let interval = i("2018-08-01T00:00:00Z/P1M");
+        assert_eq!(interval.split_at(&dt("2018-08-16T02:00:00+02:00")), Some((
+            se("2018-08-01T00:00:00Z", "2018-08-16T02:00:00+02:00"),
+            se("2018-08-16T02:00:00+02:00", "2018-09-01T00:00:00Z")
+        )));
+        assert!(interval.split_at(&dt("2018-09-01T00:00:00Z")).is_some());
+        assert_eq!(interval.split_at(&dt("2018-09-01T00:00:01Z")), None);
+        assert_eq!(i("P1M").split_at(&dt("2018-08-16T00:00:00Z")), None);
+    }
+
+    #[test]
+    fn intersect_clamp_to() {
+        let period = i("2018-08-01T00:00:00Z/2018-09-01T00:00:00Z");
+        let subscription = i("2018-07-15T00:00:00Z/2018-08-15T00:00:00Z");
+        let expected = Some(se("2018-08-01T00:00:00Z", "2018-08-15T00:00:00Z"));
+        assert_eq!(subscription.intersect(&period), expected);
+        assert_eq!(subscription.clamp_to(&period), expected);
+
+        let later = i("2018-10-01T00:00:00Z/P1D");
+        assert_eq!(later.intersect(&period), None);
+        assert_eq!(later.clamp_to(&period), Some(se("2018-09-01T00:00:00Z", "2018-09-01T00:00:00Z")));
+        assert_eq!(period.intersect(&i("PT1H")), None);
+    }
+
+    #[test]
+    fn relation() {
+        let interval = i("2018-08-02T12:00:00Z/2018-08-02T14:00:00Z");
+        for (other, relation) in &[
+            ("2018-08-02T15:00:00Z/PT1H",                  Relation::Before),
+            ("2018-08-02T14:00:00Z/PT1H",                  Relation::Meets),
+            ("2018-08-02T13:00:00Z/PT2H",                  Relation::Overlaps),
+            ("2018-08-02T12:00:00Z/PT3H",                  Relation::Starts),
+            ("2018-08-02T11:00:00Z/PT4H",                  Relation::During),
+            ("PT3H/2018-08-02T14:00:00Z",                  Relation::Finishes),
+            ("2018-08-02T14:00:00+02:00/PT2H",             Relation::Equals),
+            ("2018-08-02T10:00:00Z/PT1H",                  Relation::After),
+            ("PT1H/2018-08-02T12:00:00Z",                  Relation::MetBy),
+            ("2018-08-02T11:00:00Z/PT2H",                  Relation::OverlappedBy),
+            ("2018-08-02T12:00:00Z/PT1H",                  Relation::StartedBy),
+            ("2018-08-02T12:30:00Z/PT1H",                  Relation::Contains),
+            ("2018-08-02T13:00:00Z/2018-08-02T14:00:00Z",  Relation::FinishedBy)
+        ] {
+            assert_eq!(interval.relation(&i(other)), Some(*relation), "{}", other);
+            assert_eq!(i(other).relation(&interval), Some(relation.inverse()), "{}", other);
+        }
+
+        assert_eq!(interval.relation(&i("PT1H")), None);
+        assert_eq!(interval.relation(&i("2018-08-02T14:00:00Z/2018-08-02T12:00:00Z")), None);
+    }
+
+    #[test]
+    fn concise_end() {
+        assert_eq!(
+            i("2007-11-13T09:00:00Z/15T17:00:00Z"),
+            i("2007-11-13T09:00:00Z/2007-11-15T17:00:00Z")
+        );
+        assert_eq!(
+            Interval::<DateTime<Date, LocalTime<HmTime>>>::from_str("2007-12-14T13:30/15:30").unwrap().to_string(),
+            "2007-12-14T13:30/2007-12-14T15:30"
+        );
+        assert_eq!(
+            Interval::<Date>::from_str("2008-02-15/03-14").unwrap().to_string(),
+            "2008-02-15/2008-03-14"
+        );
+        assert_eq!(
+            Interval::<Date>::from_str("20080215/0314").unwrap().to_string(),
+            "2008-02-15/2008-03-14"
+        );
+        assert!(Interval::<Date>::from_str("2008-02-15/T03").is_err());
+    }
+
+    #[test]
+    fn iter_by() {
+        let strings = |interval: &str, step: &str, inclusive| i(interval)
+            .iter_by(d(step), inclusive)
+            .map(|x| x.map(|x| x.to_string()).collect::<Vec<_>>());
+
+        assert_eq!(strings("2024-01-01T00:00:00Z/2024-01-01T02:00:00Z", "PT1H", false), Some(vec![
+            "2024-01-01T00:00:00Z".to_string(),
+            "2024-01-01T01:00:00Z".to_string()
+        ]));
+        assert_eq!(strings("2024-01-01T00:00:00Z/PT2H", "PT1H", true).map(|x| x.len()), Some(3));
+        assert_eq!(strings("2024-01-31T00:00:00Z/2024-04-30T00:00:00Z", "P1M", true), Some(vec![
+            "2024-01-31T00:00:00Z".to_string(),
+            "2024-02-29T00:00:00Z".to_string(),
+            "2024-03-31T00:00:00Z".to_string(),
+            "2024-04-30T00:00:00Z".to_string()
+        ]));
+        assert_eq!(strings("2024-01-01T00:00:00Z/PT2H", "PT0S", true), None);
+        assert_eq!(strings("PT2H", "PT1H", true), None);
+    }
+
+    #[test]
+    fn resolve_from() {
+        let days = |s: &str, start: &str| d(s).resolve_from(&to_ymd(&dt(start))).map(|x| x.secs / 86_400);
+        assert_eq!(days("P1M", "2024-02-01T00:00:00Z"), Some(29));
+        assert_eq!(days("P1M", "2023-02-01T00:00:00Z"), Some(28));
+        assert_eq!(days("P1M", "2024-01-01T00:00:00Z"), Some(31));
+        assert_eq!(days("-P1M", "2024-03-01T00:00:00Z"), Some(29));
+        assert_eq!(
+            d("PT1H0.5S").resolve_from(&to_ymd(&dt("2024-02-01T00:00:00Z"))),
+            Some(d("PT1H0.5S").exact())
+        );
+    }
+
+    #[test]
+    fn from_local_nanos() {
+        let last = super::from_local_nanos(NANOS_PER_DAY - 1, 60).unwrap();
+        assert!(last.is_valid());
+        assert_eq!(last.to_string().get(..19), Some("1970-01-01T23:59:59"));
+    }
+
+    #[test]
+    fn checked_add_duration() {
+        let datetime = to_ymd(&dt("2020-02-29T23:30:00+01:00"));
+        assert_eq!(datetime.checked_add_duration(&d("P1Y")).unwrap().to_string(), "2021-02-28T23:30:00+01:00");
+        assert_eq!(datetime.checked_add_duration(&d("PT1H")).unwrap().to_string(), "2020-03-01T00:30:00+01:00");
+        assert_eq!(datetime.checked_sub_duration(&d("P1MT24H")).unwrap().to_string(), "2020-01-28T23:30:00+01:00");
+        assert_eq!(datetime.checked_add_duration(&d("-P1MT24H")), datetime.checked_sub_duration(&d("P1MT24H")));
+        assert_eq!(datetime.checked_sub_duration(&d("-P1Y")), datetime.checked_add_duration(&d("P1Y")));
+
+        assert!(DateTime::MIN.is_valid() && DateTime::MAX.is_valid());
+        assert_eq!(DateTime::MAX.checked_add_duration(&d("PT1S")), None);
+        assert_eq!(DateTime::MIN.checked_sub_duration(&d("PT1S")), None);
+        assert_eq!(DateTime::MIN.checked_sub_duration(&d("P1M")), None);
+        assert!(DateTime::MAX.checked_sub_duration(&d("PT1S")).is_some());
+    }
+}
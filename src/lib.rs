@@ -1,5 +1,8 @@
+// errors carry no information beyond their occurrence, like `FromStr::Err`
+#![allow(clippy::result_unit_err)]
+
 // https://github.com/rust-lang/cargo/issues/383#issuecomment-720873790
-#[cfg(doctest)]
+#[cfg(all(doctest, feature = "chrono"))]
 mod test_readme {
     macro_rules! external_doc_test {
         ($x:expr) => {
@@ -11,7 +14,9 @@ mod test_readme {
     external_doc_test!(include_str!("../README.md"));
 }
 
-#[macro_use] extern crate nom;
+extern crate nom;
+#[cfg(feature = "rayon")]
+extern crate rayon;
 
 macro_rules! impl_fromstr_parse {
     ($ty:ty, $func:ident) => {
@@ -19,7 +24,14 @@ macro_rules! impl_fromstr_parse {
             type Err = ();
 
             fn from_str(s: &str) -> Result<Self, Self::Err> {
-                ::parse::$func(s.as_bytes())
+                Self::from_bytes(s.as_bytes())
+            }
+        }
+
+        impl $ty {
+            /// Like `FromStr` but skips UTF-8 validation.
+            pub fn from_bytes(bytes: &[u8]) -> Result<Self, ()> {
+                ::parse::$func(bytes)
                     .map(|x| x.1)
                     .or(Err(()))
             }
@@ -30,13 +42,49 @@ macro_rules! impl_fromstr_parse {
 mod date;
 mod time;
 mod datetime;
-mod parse;
+mod duration;
+mod interval;
+pub mod parse;
+mod system_time;
+mod asn1;
+mod preserving;
+mod spanned;
+mod names;
+mod pattern;
+mod packed;
+mod clock;
+mod format;
+mod utc;
+mod adjust;
 pub mod chrono;
+pub mod bench_input;
+pub mod arrow;
+pub mod calendar;
+pub mod rand;
+pub mod arbitrary;
+pub mod tz;
+pub mod ffi;
+pub mod defmt;
+pub mod test_vectors;
+pub mod test_util;
+pub mod humanize;
+pub mod timescale;
+pub mod verify;
+pub mod retail;
 
 pub use {
     date::*,
     time::*,
-    datetime::*
+    datetime::*,
+    duration::*,
+    interval::*,
+    clock::*,
+    format::*,
+    preserving::*,
+    spanned::*,
+    names::*,
+    pattern::*,
+    utc::*
 };
 
 pub trait Valid {
@@ -0,0 +1,201 @@
+use std::{
+    convert::TryFrom,
+    fmt,
+    str::FromStr
+};
+
+macro_rules! named {
+    ($(#[$attr:meta])* $name:ident, $count:expr, $($variant:ident = $number:expr),+) => {
+        $(#[$attr])*
+        #[repr(u8)]
+        #[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Debug)]
+        pub enum $name {
+            $($variant = $number),+
+        }
+
+        impl $name {
+            pub const ALL: [Self; $count] = [$($name::$variant),+];
+
+            pub fn number(self) -> u8 {
+                self as u8
+            }
+
+            /// English name.
+            pub fn name(self) -> &'static str {
+                match self {
+                    $($name::$variant => stringify!($variant)),+
+                }
+            }
+
+            /// First three letters of the English name.
+            pub fn abbreviation(self) -> &'static str {
+                &self.name()[..3]
+            }
+
+            /// The following one, wrapping around.
+            pub fn succ(self) -> Self {
+                Self::ALL[self as usize % $count]
+            }
+
+            /// The preceding one, wrapping around.
+            pub fn pred(self) -> Self {
+                Self::ALL[(self as usize + $count - 2) % $count]
+            }
+
+            /// All in order, starting with the first.
+            pub fn iter() -> impl Iterator<Item = Self> {
+                Self::ALL.iter().cloned()
+            }
+        }
+
+        impl TryFrom<u8> for $name {
+            type Error = ();
+
+            fn try_from(number: u8) -> Result<Self, Self::Error> {
+                match number {
+                    $($number => Ok($name::$variant),)+
+                    _ => Err(())
+                }
+            }
+        }
+
+        impl From<$name> for u8 {
+            fn from(x: $name) -> Self {
+                x as u8
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str(self.name())
+            }
+        }
+
+        /// Accepts the English name or its abbreviation, ignoring case.
+        impl FromStr for $name {
+            type Err = ();
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::iter()
+                    .find(|x| s.eq_ignore_ascii_case(x.name()) || s.eq_ignore_ascii_case(x.abbreviation()))
+                    .ok_or(())
+            }
+        }
+    }
+}
+
+named!(
+    /// Month of the year, January being 1.
+    Month, 12,
+    January = 1, February = 2, March = 3, April = 4, May = 5, June = 6,
+    July = 7, August = 8, September = 9, October = 10, November = 11, December = 12
+);
+
+named!(
+    /// Day of the week, Monday being 1 (4.1.4.2).
+    Weekday, 7,
+    Monday = 1, Tuesday = 2, Wednesday = 3, Thursday = 4, Friday = 5, Saturday = 6, Sunday = 7
+);
+
+/// Adapters to the numbering from Sunday as 0 to Saturday as 6
+/// of C's `tm_wday`, JavaScript's `Date.getDay()`, and cron.
+impl Weekday {
+    pub fn sunday_zero(self) -> u8 {
+        self as u8 % 7
+    }
+
+    /// Also accepts 7 for Sunday as cron does.
+    pub fn from_sunday_zero(number: u8) -> Option<Self> {
+        match number {
+            0 => Some(Weekday::Sunday),
+            _ => Self::try_from(number).ok()
+        }
+    }
+}
+
+/// Era of the proleptic Gregorian calendar.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Debug)]
+pub enum Era {
+    /// Before the Common Era, years 0 and below
+    Bce,
+    /// Common Era, years 1 and above
+    Ce
+}
+
+impl Era {
+    /// Era and year of the era of an astronomical year,
+    /// in which 1 BCE is year 0.
+    pub fn of_year(year: i64) -> (Self, u64) {
+        if year >= 1 {
+            (Era::Ce, year as u64)
+        } else {
+            (Era::Bce, year.unsigned_abs() + 1)
+        }
+    }
+
+    pub fn abbreviation(self) -> &'static str {
+        match self {
+            Era::Bce => "BCE",
+            Era::Ce  => "CE"
+        }
+    }
+}
+
+impl fmt::Display for Era {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.abbreviation())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn month() {
+        assert_eq!(Month::try_from(8), Ok(Month::August));
+        assert_eq!(Month::try_from(0), Err(()));
+        assert_eq!(Month::try_from(13), Err(()));
+        assert_eq!(u8::from(Month::December), 12);
+        assert_eq!(Month::December.succ(), Month::January);
+        assert_eq!(Month::January.pred(), Month::December);
+        assert_eq!(Month::iter().count(), 12);
+        assert_eq!(Month::September.to_string(), "September");
+        assert_eq!(Month::September.abbreviation(), "Sep");
+        assert_eq!("sep".parse(), Ok(Month::September));
+        assert_eq!("SEPTEMBER".parse(), Ok(Month::September));
+        assert_eq!("Sept".parse::<Month>(), Err(()));
+    }
+
+    #[test]
+    fn weekday() {
+        assert_eq!(Weekday::try_from(7), Ok(Weekday::Sunday));
+        assert_eq!(Weekday::try_from(8), Err(()));
+        assert_eq!(Weekday::Sunday.succ(), Weekday::Monday);
+        assert_eq!(Weekday::Monday.pred(), Weekday::Sunday);
+        assert_eq!(Weekday::iter().map(Weekday::number).collect::<Vec<_>>(), (1..=7).collect::<Vec<_>>());
+        assert_eq!("thu".parse(), Ok(Weekday::Thursday));
+    }
+
+    #[test]
+    fn sunday_zero() {
+        assert_eq!(Weekday::Sunday.sunday_zero(), 0);
+        assert_eq!(Weekday::Monday.sunday_zero(), 1);
+        assert_eq!(Weekday::Saturday.sunday_zero(), 6);
+        for weekday in Weekday::iter() {
+            assert_eq!(Weekday::from_sunday_zero(weekday.sunday_zero()), Some(weekday));
+        }
+        assert_eq!(Weekday::from_sunday_zero(7), Some(Weekday::Sunday));
+        assert_eq!(Weekday::from_sunday_zero(8), None);
+    }
+
+    #[test]
+    fn era() {
+        assert_eq!(Era::of_year(2018), (Era::Ce, 2018));
+        assert_eq!(Era::of_year(1), (Era::Ce, 1));
+        assert_eq!(Era::of_year(0), (Era::Bce, 1));
+        assert_eq!(Era::of_year(-43), (Era::Bce, 44));
+        assert_eq!(Era::of_year(i64::MIN).1, 1 << 63 | 1);
+        assert_eq!(Era::Bce.to_string(), "BCE");
+    }
+}
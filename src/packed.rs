@@ -0,0 +1,186 @@
+use {
+    Valid,
+    date::*,
+    time::*,
+    datetime::*
+};
+
+/// Bit widths of the packed fields, from most to least significant.
+const MONTH:  u32 = 4;
+const DAY:    u32 = 5;
+const HOUR:   u32 = 5;
+const MINUTE: u32 = 6;
+const SECOND: u32 = 6;
+const NANOS:  u32 = 30;
+const MILLIS: u32 = 10;
+const OFFSET: u32 = 12;
+
+/// Packs fields into an integer, `None` if one does not fit its width.
+fn pack(fields: &[(u128, u32)]) -> Option<u128> {
+    fields.iter().try_fold(0, |packed, &(value, width)| {
+        if value >> width == 0 {
+            Some(packed << width | value)
+        } else {
+            None
+        }
+    })
+}
+
+/// Unpacks fields of the given widths, least significant first.
+fn unpack<const N: usize>(mut packed: u128, widths: [u32; N]) -> [u128; N] {
+    let mut fields = [0; N];
+    for (field, width) in fields.iter_mut().zip(widths.iter()) {
+        *field = packed & ((1 << width) - 1);
+        packed >>= width;
+    }
+    fields
+}
+
+// biased so that packed values sort like years and offsets
+fn bias_year(year: i16) -> u128 {
+    (i32::from(year) - i32::from(i16::MIN)) as u128
+}
+
+fn unbias_year(year: u128) -> i16 {
+    (year as i32 + i32::from(i16::MIN)) as i16
+}
+
+fn bias_offset(offset: i16) -> u128 {
+    (i32::from(offset) + (1 << (OFFSET - 1))) as u128
+}
+
+fn unbias_offset(offset: u128) -> i16 {
+    offset as i16 - (1 << (OFFSET - 1))
+}
+
+impl DateTime {
+    fn pack(&self, subsec: u128, subsec_width: u32) -> Option<u128> {
+        let time = &self.time.local.naive;
+        pack(&[
+            (bias_year(self.date.year), 16),
+            (self.date.month.into(), MONTH),
+            (self.date.day.into(),   DAY),
+            (time.hour.into(),       HOUR),
+            (time.minute.into(),     MINUTE),
+            (time.second.into(),     SECOND),
+            (subsec,                 subsec_width),
+            (bias_offset(self.time.timezone), OFFSET)
+        ])
+    }
+
+    /// `None` if bits beyond the fields are set,
+    /// the sub-second field reaches `subsec_per_sec` or the result is not valid.
+    fn unpack(packed: u128, subsec_width: u32, subsec_per_sec: u128) -> Option<Self> {
+        let width = 16 + MONTH + DAY + HOUR + MINUTE + SECOND + subsec_width + OFFSET;
+        if packed >> width != 0 {
+            return None;
+        }
+
+        let [timezone, subsec, second, minute, hour, day, month, year] =
+            unpack(packed, [OFFSET, subsec_width, SECOND, MINUTE, HOUR, DAY, MONTH, 16]);
+        if subsec >= subsec_per_sec {
+            return None;
+        }
+
+        let datetime = DateTime {
+            date: YmdDate {
+                year: unbias_year(year),
+                month: month as u8,
+                day: day as u8
+            },
+            time: GlobalTime {
+                local: LocalTime {
+                    naive: HmsTime {
+                        hour: hour as u8,
+                        minute: minute as u8,
+                        second: second as u8
+                    },
+                    fraction: fraction_from_nanos((subsec * (1_000_000_000 / subsec_per_sec)) as u32)
+                },
+                timezone: unbias_offset(timezone)
+            }
+        };
+        if datetime.is_valid() { Some(datetime) } else { None }
+    }
+
+    /// All fields in 84 bits with nanosecond precision,
+    /// ordered like the fields for values with the same offset.
+    ///
+    /// From the most significant bit, the fields are
+    /// the year plus 32768 in 16 bits, the month in 4, the day in 5,
+    /// the hour in 5, the minute in 6, the second in 6, the nanosecond in 30
+    /// and the offset in minutes plus 2048 in 12.
+    ///
+    /// `None` if a field is out of range or the offset exceeds 34 hours.
+    pub fn to_packed(&self) -> Option<u128> {
+        self.pack(self.time.local.nanosecond().into(), NANOS)
+    }
+
+    /// Inverse of [`to_packed`](Self::to_packed),
+    /// `None` if bits beyond the 84 of the fields are set,
+    /// the nanosecond is not below 1e9 or the datetime is not valid.
+    pub fn from_packed(packed: u128) -> Option<Self> {
+        Self::unpack(packed, NANOS, 1_000_000_000)
+    }
+
+    /// Like [`to_packed`](Self::to_packed)
+    /// but truncated to milliseconds to fit 64 bits,
+    /// with the millisecond in 10 bits instead of the nanosecond in 30.
+    pub fn to_packed_u64(&self) -> Option<u64> {
+        self.pack(u128::from(self.time.local.nanosecond() / 1_000_000), MILLIS)
+            .map(|packed| packed as u64)
+    }
+
+    /// Inverse of [`to_packed_u64`](Self::to_packed_u64),
+    /// `None` if the millisecond is not below 1000 or the datetime is not valid.
+    pub fn from_packed_u64(packed: u64) -> Option<Self> {
+        Self::unpack(packed.into(), MILLIS, 1_000)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn packed() {
+        let datetime: DateTime = "2018-08-02T13:42:02.123456789-02:30"
+            .parse::<DateTime<Date>>()
+            .map(|x| DateTime { date: x.date.into(), time: x.time })
+            .unwrap();
+
+        assert_eq!(DateTime::from_packed(datetime.to_packed().unwrap()), Some(datetime));
+
+        let millis = DateTime::from_packed_u64(datetime.to_packed_u64().unwrap()).unwrap();
+        assert_eq!(millis.time.local.nanosecond(), 123_000_000);
+        assert_eq!(millis.time.timezone, -150);
+        assert_eq!(millis.date, datetime.date);
+
+        let earlier: DateTime = DateTime::from_unix(0, 0);
+        assert!(earlier.to_packed_u64() < datetime.to_packed_u64());
+
+        let mut invalid = datetime;
+        invalid.date.month = 16;
+        assert_eq!(invalid.to_packed(), None);
+    }
+
+    #[test]
+    fn from_packed_invalid() {
+        assert_eq!(DateTime::from_packed(u128::MAX), None);
+        assert_eq!(DateTime::from_packed(u128::MAX >> (128 - 84)), None);
+        assert_eq!(DateTime::from_packed_u64(u64::MAX), None);
+
+        let packed = DateTime::UNIX_EPOCH.to_packed().unwrap();
+        assert_eq!(DateTime::from_packed(packed), Some(DateTime::UNIX_EPOCH));
+        assert_eq!(DateTime::from_packed(packed | 1 << 84), None);
+        // nanosecond 1e9
+        assert_eq!(DateTime::from_packed(packed | 1_000_000_000 << OFFSET), None);
+        assert!(DateTime::from_packed(packed | 999_999_999 << OFFSET).is_some());
+        // month 15
+        assert_eq!(DateTime::from_packed(packed | 15 << (84 - 16 - MONTH)), None);
+
+        let packed = DateTime::UNIX_EPOCH.to_packed_u64().unwrap();
+        assert_eq!(DateTime::from_packed_u64(packed | 1_000 << OFFSET), None);
+        assert_eq!(DateTime::from_packed_u64(packed | 999 << OFFSET).map(|x| x.time.local.nanosecond()), Some(999_000_000));
+    }
+}
@@ -0,0 +1,175 @@
+//! ASN.1 `GeneralizedTime` and `UTCTime` (X.680 46 and 47),
+//! as used by X.509 certificates.
+
+use ::{
+    date::*,
+    time::*,
+    datetime::*
+};
+use super::{
+    buf_to_int,
+    complete::{
+        month,
+        day,
+        hour,
+        minute,
+        second
+    }
+};
+use nom::{
+    IResult,
+    branch::alt,
+    bytes::complete::{
+        take_while1,
+        take_while_m_n
+    },
+    character::{
+        is_digit,
+        complete::{
+            char,
+            one_of
+        }
+    },
+    combinator::{
+        map,
+        map_res,
+        opt,
+        recognize
+    },
+    sequence::{
+        pair,
+        tuple
+    }
+};
+
+fn offset(i: &[u8]) -> IResult<&[u8], i16> {
+    alt((
+        map(char('Z'), |_| 0),
+        map(
+            tuple((one_of("+-"), hour, minute)),
+            |(sign, hour, minute)| {
+                let offset = i16::from(hour) * 60 + i16::from(minute);
+                if sign == '-' { -offset } else { offset }
+            }
+        )
+    ))(i)
+}
+
+fn fraction(i: &[u8]) -> IResult<&[u8], f32> {
+    map_res(
+        recognize(pair(one_of(".,"), take_while1(is_digit))),
+        |x: &[u8]| ::std::str::from_utf8(&x[1..])
+            .ok()
+            .and_then(|x| format!(".{}", x).parse().ok())
+            .ok_or(())
+    )(i)
+}
+
+fn local_time(i: &[u8]) -> IResult<&[u8], LocalTime> {
+    map(
+        tuple((hour, minute, opt(pair(second, opt(fraction))))),
+        |(hour, minute, second)| {
+            let (second, fraction) = second.unwrap_or((0, None));
+            LocalTime {
+                naive: HmsTime { hour, minute, second },
+                fraction: fraction.unwrap_or(0.)
+            }
+        }
+    )(i)
+}
+
+/// `GeneralizedTime`, like `19851106210627.3Z`.
+///
+/// Seconds are optional and a fraction may only follow seconds.
+/// Without an offset the time is local.
+pub fn generalized_time(i: &[u8]) -> IResult<&[u8], DateTime<YmdDate, AnyTime>> {
+    map(
+        tuple((
+            map(take_while_m_n(4, 4, is_digit), buf_to_int),
+            month,
+            day,
+            local_time,
+            opt(offset)
+        )),
+        |(year, month, day, local, timezone)| DateTime {
+            date: YmdDate { year, month, day },
+            time: match timezone {
+                Some(timezone) => AnyTime::Global(GlobalTime { local, timezone }),
+                None => AnyTime::Local(local)
+            }
+        }
+    )(i)
+}
+
+/// `UTCTime`, like `851106210627Z`.
+///
+/// Two-digit years from 50 are in the 20th century,
+/// the others in the 21st (RFC 5280 4.1.2.5.1).
+pub fn utc_time(i: &[u8]) -> IResult<&[u8], DateTime> {
+    map(
+        tuple((
+            map(take_while_m_n(2, 2, is_digit), buf_to_int::<i16>),
+            month,
+            day,
+            hour,
+            minute,
+            opt(second),
+            offset
+        )),
+        |(year, month, day, hour, minute, second, timezone)| DateTime {
+            date: YmdDate {
+                year: if year >= 50 { 1900 + year } else { 2000 + year },
+                month,
+                day
+            },
+            time: GlobalTime {
+                local: LocalTime {
+                    naive: HmsTime { hour, minute, second: second.unwrap_or(0) },
+                    fraction: 0.
+                },
+                timezone
+            }
+        }
+    )(i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generalized_time() {
+        assert_eq!(super::generalized_time(b"19851106210627.3Z"), Ok((&[][..], DateTime {
+            date: YmdDate { year: 1985, month: 11, day: 6 },
+            time: AnyTime::Global(GlobalTime {
+                local: LocalTime {
+                    naive: HmsTime { hour: 21, minute: 6, second: 27 },
+                    fraction: 0.3
+                },
+                timezone: 0
+            })
+        })));
+        assert_eq!(super::generalized_time(b"198511062106-0500"), Ok((&[][..], DateTime {
+            date: YmdDate { year: 1985, month: 11, day: 6 },
+            time: AnyTime::Global(GlobalTime {
+                local: LocalTime {
+                    naive: HmsTime { hour: 21, minute: 6, second: 0 },
+                    fraction: 0.
+                },
+                timezone: -5 * 60
+            })
+        })));
+        assert_eq!(super::generalized_time(b"19851106210627,5").map(|x| x.1.time), Ok(AnyTime::Local(LocalTime {
+            naive: HmsTime { hour: 21, minute: 6, second: 27 },
+            fraction: 0.5
+        })));
+    }
+
+    #[test]
+    fn utc_time() {
+        assert_eq!(super::utc_time(b"851106210627Z").map(|x| x.1.date), Ok(YmdDate { year: 1985, month: 11, day: 6 }));
+        assert_eq!(super::utc_time(b"4912312359Z").map(|x| x.1.date),  Ok(YmdDate { year: 2049, month: 12, day: 31 }));
+        assert_eq!(super::utc_time(b"500101000000+0100").map(|x| x.1.time.timezone), Ok(60));
+        assert!(super::utc_time(b"851106210627").is_err());
+    }
+}
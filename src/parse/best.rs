@@ -0,0 +1,80 @@
+use ::{
+    Valid,
+    date::*,
+    datetime::*,
+    time::*
+};
+use super::{
+    datetime_any_hms_with,
+    is_valid_rfc3339,
+    ParseError,
+    Profile
+};
+
+/// Which grammar a best effort parse matched.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Debug)]
+pub enum Conformance {
+    /// Strict ISO 8601.
+    Iso8601,
+    /// RFC 3339, which also permits lowercase designators
+    /// and a space between date and time.
+    Rfc3339,
+    /// Only with all deviations of [`Profile::Lenient`].
+    Lenient
+}
+
+/// Parses the whole input into a valid date and time
+/// by the strictest grammar that matches it.
+///
+/// [`Conformance::Rfc3339`] also requires the shape of RFC 3339,
+/// see [`is_valid_rfc3339`].
+///
+/// The error is that of the lenient grammar
+/// or, if the input matches it, for the invalid value.
+pub fn datetime_best(i: &[u8]) -> Result<(DateTime<Date, AnyTime>, Conformance), ParseError> {
+    let value = ParseError::from_result(i, datetime_any_hms_with(Profile::Lenient.into())(i))?;
+    if !value.is_valid() {
+        return Err(ParseError::invalid_value(i, 0..i.len()));
+    }
+
+    let matches = |profile: Profile| ParseError::from_result(i, datetime_any_hms_with(profile.into())(i)).is_ok();
+    let conformance = if matches(Profile::Iso8601_2019Strict) {
+        Conformance::Iso8601
+    } else if matches(Profile::Rfc3339) && ::std::str::from_utf8(i).is_ok_and(is_valid_rfc3339) {
+        Conformance::Rfc3339
+    } else {
+        Conformance::Lenient
+    };
+    Ok((value, conformance))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::ParseErrorKind;
+
+    #[test]
+    fn datetime_best() {
+        let conformance = |i: &[u8]| super::datetime_best(i).map(|x| x.1);
+        assert_eq!(conformance(b"2018-08-02T13:42:02Z"),  Ok(Conformance::Iso8601));
+        assert_eq!(conformance(b"20180802T134202"),       Ok(Conformance::Iso8601));
+        assert_eq!(conformance(b"2018-08-02 13:42:02Z"),  Ok(Conformance::Rfc3339));
+        assert_eq!(conformance(b"2018-08-02t13:42:02z"),  Ok(Conformance::Rfc3339));
+        assert_eq!(conformance(b"2018-08-02T1342:02Z"),   Ok(Conformance::Lenient));
+        assert!(conformance(b"2018-08-02T13:42:02Z x").is_err());
+
+        // RFC 3339 only allows the extended format with an offset
+        assert_eq!(conformance(b"20180802t134202z"),      Ok(Conformance::Lenient));
+        assert_eq!(conformance(b"2018-08-02 13:42:02"),   Ok(Conformance::Lenient));
+        assert_eq!(conformance(b"2018-08-02 13:42:02+05"), Ok(Conformance::Lenient));
+
+        for invalid in &[&b"2018-400T00:00:00Z"[..], b"2018-02-30T00:00:00Z", b"2018-08-02t25:00:00z"] {
+            assert_eq!(super::datetime_best(invalid).map_err(|e| e.kind), Err(ParseErrorKind::Invalid));
+        }
+
+        assert_eq!(
+            super::datetime_best(b"2018-08-02 13:42:02Z").map(|x| x.0),
+            super::datetime_best(b"2018-08-02T13:42:02Z").map(|x| x.0)
+        );
+    }
+}
@@ -0,0 +1,144 @@
+//! Yes-or-no validation of whole strings,
+//! as needed by JSON Schema's `format` keyword.
+
+use ::{
+    Valid,
+    date::days_in_month
+};
+use super::{
+    date,
+    datetime_any_hms,
+    fast
+};
+
+/// Whether the whole input is a valid complete calendar, week or ordinal date.
+pub fn is_valid_iso_date(s: &str) -> bool {
+    match date(s.as_bytes()) {
+        Ok((rest, date)) => rest.is_empty() && date.is_valid(),
+        Err(_) => false
+    }
+}
+
+/// Whether the whole input is a valid complete date and time of day,
+/// with or without a difference from UTC.
+pub fn is_valid_iso_datetime(s: &str) -> bool {
+    let i = s.as_bytes();
+    match fast::datetime_global_hms(i) {
+        Some((rest, datetime)) => rest.is_empty() && datetime.is_valid(),
+        None => match datetime_any_hms(i) {
+            Ok((rest, datetime)) => rest.is_empty() && datetime.is_valid(),
+            Err(_) => false
+        }
+    }
+}
+
+/// Whether the whole input is a valid RFC 3339 `date-time`,
+/// which is what JSON Schema's `format: date-time` refers to.
+pub fn is_valid_rfc3339(s: &str) -> bool {
+    rfc3339(s.as_bytes()).is_some()
+}
+
+fn number(i: &[u8], at: usize, len: usize, max: u16) -> Option<u16> {
+    let mut n = 0u16;
+    for c in i.get(at..at + len)? {
+        if !c.is_ascii_digit() {
+            return None;
+        }
+        n = n * 10 + u16::from(c - b'0');
+    }
+    if n <= max { Some(n) } else { None }
+}
+
+fn rfc3339(i: &[u8]) -> Option<()> {
+    if i.len() < 20 || i[4] != b'-' || i[7] != b'-' || i[13] != b':' || i[16] != b':' {
+        return None;
+    }
+    if !matches!(i[10], b'T' | b't' | b' ') {
+        return None;
+    }
+
+    let year  = number(i, 0, 4, 9999)?;
+    let month = number(i, 5, 2, 12)? as u8;
+    let day   = number(i, 8, 2, 31)? as u8;
+    if day == 0 || day > days_in_month(year as i16, month)? {
+        return None;
+    }
+    number(i, 11, 2, 23)?;
+    number(i, 14, 2, 59)?;
+    // leap seconds are accepted on any day
+    number(i, 17, 2, 60)?;
+
+    let mut at = 19;
+    if i[at] == b'.' {
+        let digits = i[at + 1..].iter().take_while(|c| c.is_ascii_digit()).count();
+        if digits == 0 {
+            return None;
+        }
+        at += 1 + digits;
+    }
+
+    match i.get(at)? {
+        b'Z' | b'z' => at += 1,
+        b'+' | b'-' => {
+            if i.get(at + 3) != Some(&b':') {
+                return None;
+            }
+            number(i, at + 1, 2, 23)?;
+            number(i, at + 4, 2, 59)?;
+            at += 6;
+        }
+        _ => return None
+    }
+
+    if at == i.len() { Some(()) } else { None }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn is_valid_iso_date() {
+        assert!(super::is_valid_iso_date("2018-08-02"));
+        assert!(super::is_valid_iso_date("2018-W31-4"));
+        assert!(super::is_valid_iso_date("2018214"));
+        assert!(!super::is_valid_iso_date("2018-02-30"));
+        assert!(!super::is_valid_iso_date("2018-08-02T13:42:02Z"));
+    }
+
+    #[test]
+    fn is_valid_iso_datetime() {
+        assert!(super::is_valid_iso_datetime("2018-08-02T13:42:02Z"));
+        assert!(super::is_valid_iso_datetime("2018-W31-4T13:42:02.5+02:00"));
+        assert!(super::is_valid_iso_datetime("20180802T134202"));
+        assert!(!super::is_valid_iso_datetime("2018-02-30T13:42:02Z"));
+        assert!(!super::is_valid_iso_datetime("2018-08-02T13:42:02Z "));
+        assert!(!super::is_valid_iso_datetime("2018-08-02 13:42:02Z"));
+    }
+
+    #[test]
+    fn is_valid_rfc3339() {
+        for input in &[
+            "2018-08-02T13:42:02Z",
+            "2018-08-02t13:42:02.123z",
+            "2018-08-02 13:42:02-02:30",
+            "2016-12-31T23:59:60Z",
+            "2016-02-29T00:00:00+00:00"
+        ] {
+            assert!(super::is_valid_rfc3339(input), "{}", input);
+        }
+
+        for input in &[
+            "2018-08-02T13:42:02",
+            "2018-08-02T13:42:02.Z",
+            "2018-08-02T13:42:02+0200",
+            "2018-08-02T24:00:00Z",
+            "2017-02-29T00:00:00Z",
+            "2018-00-02T13:42:02Z",
+            "2018-08-00T13:42:02Z",
+            "2018-08-02T13:42:02Z ",
+            "20180802T134202Z",
+            "2018-W31-4T13:42:02Z"
+        ] {
+            assert!(!super::is_valid_rfc3339(input), "{}", input);
+        }
+    }
+}
@@ -0,0 +1,70 @@
+//! Grammar productions that treat the end of input as final.
+
+productions!(complete);
+
+#[cfg(test)]
+mod tests {
+    use nom::Err;
+
+    #[test]
+    fn year() {
+        assert_eq!(super::year(b"2018"),  Ok((&[][..],  2018)));
+        assert_eq!(super::year(b"+2018"), Ok((&[][..],  2018)));
+        assert_eq!(super::year(b"-2018"), Ok((&[][..], -2018)));
+        assert_eq!(super::year(b"-0333"), Ok((&[][..],  -333)));
+        assert!(matches!(super::year(b"201"), Err(Err::Error(_))));
+    }
+
+    #[test]
+    fn month() {
+        assert_eq!(super::month(b"06"), Ok((&[][..],  6)));
+        assert_eq!(super::month(b"12"), Ok((&[][..], 12)));
+    }
+
+    #[test]
+    fn day() {
+        assert_eq!(super::day(b"18"), Ok((&[][..], 18)));
+    }
+
+    #[test]
+    fn week() {
+        assert_eq!(super::week(b"01"), Ok((&[][..], 1)));
+    }
+
+    #[test]
+    fn ordinal() {
+        assert_eq!(super::ordinal(b"001"),  Ok((&[][..],     1)));
+        assert_eq!(super::ordinal(b"011"),  Ok((&[][..],    11)));
+        assert_eq!(super::ordinal(b"111"),  Ok((&[][..],   111)));
+        assert_eq!(super::ordinal(b"1111"), Ok((&b"1"[..], 111)));
+        assert!(matches!(super::ordinal(b"11"), Err(Err::Error(_))));
+    }
+
+    #[test]
+    fn weekday() {
+        assert_eq!(super::weekday(b"1"), Ok((&[][..], 1)));
+        assert_eq!(super::weekday(b"2"), Ok((&[][..], 2)));
+        assert_eq!(super::weekday(b"3"), Ok((&[][..], 3)));
+        assert_eq!(super::weekday(b"4"), Ok((&[][..], 4)));
+        assert_eq!(super::weekday(b"5"), Ok((&[][..], 5)));
+        assert_eq!(super::weekday(b"6"), Ok((&[][..], 6)));
+        assert_eq!(super::weekday(b"7"), Ok((&[][..], 7)));
+    }
+
+    #[test]
+    fn fraction() {
+        assert_eq!(super::fraction(b".5 "), Ok((&b" "[..], 0.5)));
+        assert_eq!(super::fraction(b".5"),  Ok((&[][..],   0.5)));
+        assert!(super::fraction(b",5").is_err());
+    }
+
+    #[test]
+    fn offset() {
+        assert_eq!(super::offset(b"+23:59"), Ok((&[][..],  23 * 60 + 59)));
+        assert_eq!(super::offset(b"+2359"),  Ok((&[][..],  23 * 60 + 59)));
+        assert_eq!(super::offset(b"-23"),    Ok((&[][..], -23 * 60)));
+        assert_eq!(super::offset(b"+05"),    Ok((&[][..],   5 * 60)));
+        assert_eq!(super::offset(b"Z"),      Ok((&[][..],   0)));
+        assert!(super::offset(b"z").is_err());
+    }
+}
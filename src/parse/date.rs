@@ -1,204 +1,217 @@
-use ::date::*;
-use super::*;
-use nom::character::is_digit;
-
-named!(positive_century <u8>, map!(
-    take_while_m_n!(2, 2, is_digit),
-    buf_to_int
-));
-
-named!(century <i8>, do_parse!(
-    sign: opt!(sign) >>
-    century: positive_century >>
-    (sign.unwrap_or(1) * century as i8)
-));
-
-// TODO support expanded year
-named!(positive_year <u16>, map!(
-    take_while_m_n!(4, 4, is_digit),
-    buf_to_int
-));
-
-named!(year <i16>, do_parse!(
-    sign: opt!(sign) >>
-    year: positive_year >>
-    (sign.unwrap_or(1) as i16 * year as i16)
-));
-
-named!(month <u8>, map!(
-    take_while_m_n!(2, 2, is_digit),
-    buf_to_int
-));
-
-named!(day <u8>, map!(
-    take_while_m_n!(2, 2, is_digit),
-    buf_to_int
-));
-
-named!(year_week <u8>, map!(
-    take_while_m_n!(2, 2, is_digit),
-    buf_to_int
-));
-
-named!(year_day <u8>, map!(
-    take_while_m_n!(3, 3, is_digit),
-    buf_to_int
-));
-
-named!(week_day <u16>, map!(
-    take_while_m_n!(1, 1, is_digit),
-    buf_to_int
-));
-
-named_args!(date_ymd_format(extended: bool) <YmdDate>, do_parse!(
-    year: year >>
-    cond!(extended, char!('-')) >>
-    month: month >>
-    cond!(extended, char!('-')) >>
-    day: day >>
-    (YmdDate { year, month, day })
-));
-named!(date_ymd_basic    <YmdDate>, call!(date_ymd_format, false));
-named!(date_ymd_extended <YmdDate>, call!(date_ymd_format, true));
-
-named!(pub date_ymd <YmdDate>, alt!(
-    complete!(date_ymd_extended) |
-    complete!(date_ymd_basic)
-));
-
-named_args!(date_wd_format(extended: bool) <WdDate>, do_parse!(
-    year: year >>
-    cond!(extended, char!('-')) >>
-    char!('W') >>
-    week: year_week >>
-    cond!(extended, char!('-')) >>
-    day: week_day >>
-    (WdDate { year, week, day })
-));
-named!(date_wd_basic    <WdDate>, call!(date_wd_format, false));
-named!(date_wd_extended <WdDate>, call!(date_wd_format, true));
-
-named!(pub date_wd <WdDate>, alt!(
-   date_wd_extended |
-   date_wd_basic
-));
-
-named_args!(date_o_format(extended: bool) <ODate>, do_parse!(
-    year: year >>
-    cond!(extended, char!('-')) >>
-    day: year_day >>
-    (ODate {
-        year,
-        day: day.into()
-    })
-));
-named!(date_o_basic    <ODate>, call!(date_o_format, false));
-named!(date_o_extended <ODate>, call!(date_o_format, true));
-
-named!(pub date_o <ODate>, alt!(
-    date_o_extended |
-    date_o_basic
-));
-
-named!(pub date <Date>, alt!(
-    complete!(map!(date_wd, Date::WD)) |
-    complete!(map!(date_ymd_extended, Date::YMD)) |
-    complete!(map!(date_o_extended, Date::O)) |
-    complete!(map!(date_ymd_basic, Date::YMD)) |
-    complete!(map!(date_o_basic, Date::O))
-));
-
-named_args!(date_w_format(extended: bool) <WDate>, do_parse!(
-    year: year >>
-    cond!(extended, char!('-')) >>
-    char!('W') >>
-    week: year_week >>
-    (WDate { year, week })
-));
-named!(date_w_basic    <WDate>, call!(date_w_format, false));
-named!(date_w_extended <WDate>, call!(date_w_format, true));
-
-named!(pub date_w <WDate>, alt!(
-    date_w_extended |
-    date_w_basic
-));
-
-named_args!(date_ym_format(extended: bool) <YmDate>, do_parse!(
-    year: year >>
-    cond!(extended, char!('-')) >>
-    month: month >>
-    (YmDate { year, month })
-));
-named!(date_ym_basic    <YmDate>, call!(date_ym_format, false));
-named!(date_ym_extended <YmDate>, call!(date_ym_format, true));
-
-named!(pub date_ym <YmDate>, alt!(
-    date_ym_extended |
-    date_ym_basic
-));
-
-named!(pub date_y <YDate>, map!(year, |year| YDate { year }));
-
-named!(pub date_c <CDate>, map!(century, |century| CDate { century }));
-
-named!(pub date_approx <ApproxDate>, alt!(
-    complete!(map!(date, |x| x.into())) |
-    complete!(map!(date_w, ApproxDate::W)) |
-    complete!(map!(date_ym, ApproxDate::YM)) |
-    complete!(map!(date_y, ApproxDate::Y)) |
-    complete!(map!(date_c, ApproxDate::C))
-));
+use ::{
+    date::*,
+    datetime::OrdinalDateTime
+};
+use super::complete::{
+    century,
+    year,
+    month,
+    day,
+    week,
+    weekday,
+    ordinal
+};
+use super::{
+    buf_to_int,
+    ParseOptions,
+    SevenDigitDate
+};
+use nom::{
+    IResult,
+    branch::alt,
+    bytes::complete::{
+        take_while1,
+        take_while_m_n
+    },
+    character::{
+        is_digit,
+        complete::char
+    },
+    combinator::{
+        cond,
+        map,
+        not,
+        opt,
+        verify
+    },
+    sequence::{
+        pair,
+        preceded,
+        terminated,
+        tuple
+    }
+};
+
+fn date_ymd_format<'a>(extended: bool) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], YmdDate> {
+    map(
+        tuple((
+            year,
+            cond(extended, char('-')),
+            month,
+            cond(extended, char('-')),
+            day
+        )),
+        |(year, _, month, _, day)| YmdDate { year, month, day }
+    )
+}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub fn date_ymd(i: &[u8]) -> IResult<&[u8], YmdDate> {
+    alt((
+        date_ymd_format(true),
+        date_ymd_format(false)
+    ))(i)
+}
 
-    #[test]
-    fn positive_year() {
-        assert_eq!(super::positive_year(b"2018"), Ok((&[][..], 2018)));
-    }
+fn date_wd_format<'a>(extended: bool) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], WdDate> {
+    map(
+        tuple((
+            year,
+            cond(extended, char('-')),
+            char('W'),
+            week,
+            cond(extended, char('-')),
+            weekday
+        )),
+        |(year, _, _, week, _, day)| WdDate { year, week, day }
+    )
+}
 
-    #[test]
-    fn year() {
-        assert_eq!(super::year(b"2018" ), Ok((&[][..],  2018)));
-        assert_eq!(super::year(b"+2018"), Ok((&[][..],  2018)));
-        assert_eq!(super::year(b"-2018"), Ok((&[][..], -2018)));
-    }
+pub fn date_wd(i: &[u8]) -> IResult<&[u8], WdDate> {
+    alt((
+        date_wd_format(true),
+        date_wd_format(false)
+    ))(i)
+}
 
-    #[test]
-    fn month() {
-        assert_eq!(super::month(b"06"), Ok((&[][..],  6)));
-        assert_eq!(super::month(b"12"), Ok((&[][..], 12)));
-    }
+fn date_o_format<'a>(extended: bool) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], ODate> {
+    map(
+        tuple((
+            year,
+            cond(extended, char('-')),
+            ordinal
+        )),
+        |(year, _, day)| ODate { year, day }
+    )
+}
 
-    #[test]
-    fn year_week() {
-        assert_eq!(super::year_week(b"01"), Ok((&[][..], 1)));
-    }
+pub fn date_o(i: &[u8]) -> IResult<&[u8], ODate> {
+    alt((
+        date_o_format(true),
+        date_o_format(false)
+    ))(i)
+}
 
-    #[test]
-    fn year_day() {
-        assert_eq!(super::year_day(b"001"),  Ok((&[][..],     1)));
-        assert_eq!(super::year_day(b"011"),  Ok((&[][..],    11)));
-        assert_eq!(super::year_day(b"111"),  Ok((&[][..],   111)));
-        assert_eq!(super::year_day(b"1111"), Ok((&b"1"[..], 111)));
-    }
+/// A day of the year without the year, like `-123` or `123`.
+pub fn date_day_of_year(i: &[u8]) -> IResult<&[u8], DayOfYear> {
+    map(preceded(opt(char('-')), ordinal), DayOfYear)(i)
+}
 
-    #[test]
-    fn day() {
-        assert_eq!(super::day(b"18"), Ok((&[][..], 18)));
-    }
+// year, month and a day of one digit in basic format
+fn date_ymd_unpadded(i: &[u8]) -> IResult<&[u8], YmdDate> {
+    map(
+        terminated(
+            tuple((year, month, take_while_m_n(1, 1, is_digit))),
+            not(take_while_m_n(1, 1, is_digit))
+        ),
+        |(year, month, day): (_, _, &[u8])| YmdDate { year, month, day: day[0] - b'0' }
+    )(i)
+}
 
-    #[test]
-    fn week_day() {
-        assert_eq!(super::week_day(b"1"), Ok((&[][..], 1)));
-        assert_eq!(super::week_day(b"2"), Ok((&[][..], 2)));
-        assert_eq!(super::week_day(b"3"), Ok((&[][..], 3)));
-        assert_eq!(super::week_day(b"4"), Ok((&[][..], 4)));
-        assert_eq!(super::week_day(b"5"), Ok((&[][..], 5)));
-        assert_eq!(super::week_day(b"6"), Ok((&[][..], 6)));
-        assert_eq!(super::week_day(b"7"), Ok((&[][..], 7)));
-    }
+pub fn date_with<'a>(options: ParseOptions) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], Date> {
+    alt((
+        map(date_wd,                Date::WD),
+        map(date_ymd_format(true),  Date::YMD),
+        map(date_o_format(true),    Date::O),
+        map(date_ymd_format(false), Date::YMD),
+        map(
+            verify(date_ymd_unpadded, move |_| options.seven_digit_date == SevenDigitDate::YearMonthDay),
+            Date::YMD
+        ),
+        map(date_o_format(false),   Date::O)
+    ))
+}
+
+pub fn date(i: &[u8]) -> IResult<&[u8], Date> {
+    date_with(ParseOptions::default())(i)
+}
+
+fn date_w_format<'a>(extended: bool) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], WDate> {
+    map(
+        tuple((
+            year,
+            cond(extended, char('-')),
+            char('W'),
+            week
+        )),
+        |(year, _, _, week)| WDate { year, week }
+    )
+}
+
+pub fn date_w(i: &[u8]) -> IResult<&[u8], WDate> {
+    alt((
+        date_w_format(true),
+        date_w_format(false)
+    ))(i)
+}
+
+fn date_ym_format<'a>(extended: bool) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], YmDate> {
+    map(
+        tuple((
+            year,
+            cond(extended, char('-')),
+            month
+        )),
+        |(year, _, month)| YmDate { year, month }
+    )
+}
+
+pub fn date_ym(i: &[u8]) -> IResult<&[u8], YmDate> {
+    alt((
+        date_ym_format(true),
+        date_ym_format(false)
+    ))(i)
+}
+
+pub fn date_y(i: &[u8]) -> IResult<&[u8], YDate> {
+    map(year, |year| YDate { year })(i)
+}
+
+pub fn date_c(i: &[u8]) -> IResult<&[u8], CDate> {
+    map(century, |century| CDate { century })(i)
+}
+
+pub fn date_approx_with<'a>(options: ParseOptions) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], ApproxDate> {
+    alt((
+        map(date_with(options), |x| x.into()),
+        map(date_w,             ApproxDate::W),
+        map(date_ym,            ApproxDate::YM),
+        map(date_y,             ApproxDate::Y),
+        map(date_c,             ApproxDate::C)
+    ))
+}
+
+pub fn date_approx(i: &[u8]) -> IResult<&[u8], ApproxDate> {
+    date_approx_with(ParseOptions::default())(i)
+}
+
+/// Ordinal date with a decimal fraction of the day like `2024-123.75`,
+/// which is not part of ISO 8601.
+pub fn ordinal_decimal_day(i: &[u8]) -> IResult<&[u8], OrdinalDateTime> {
+    map(
+        pair(
+            date_o,
+            preceded(char('.'), take_while1(is_digit))
+        ),
+        |(date, digits): (_, &[u8])| OrdinalDateTime {
+            date,
+            fraction: buf_to_int::<f64>(digits) / 10f64.powi(digits.len() as i32)
+        }
+    )(i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
     #[test]
     fn date_ymd() {
@@ -208,7 +221,7 @@ mod tests {
                 month: 7,
                 day: 16
             };
-            assert_eq!(super::date_ymd(b"2015-07-16"), Ok((&[][..], value.clone())));
+            assert_eq!(super::date_ymd(b"2015-07-16"), Ok((&[][..], value)));
             assert_eq!(super::date_ymd(b"20150716"),   Ok((&[][..], value        )));
         }
         {
@@ -217,7 +230,7 @@ mod tests {
                 month: 6,
                 day: 11
             };
-            assert_eq!(super::date_ymd(b"-0333-06-11"), Ok((&[][..], value.clone())));
+            assert_eq!(super::date_ymd(b"-0333-06-11"), Ok((&[][..], value)));
             assert_eq!(super::date_ymd(b"-03330611"),   Ok((&[][..], value        )));
         }
         assert_eq!(super::date_ymd(b"2016-02-29"), Ok((&[][..], YmdDate {
@@ -278,9 +291,9 @@ mod tests {
             year: 2020,
             week: 53
         };
-        assert_eq!(super::date_w(b"2020-W53 "), Ok((&b" "[..], value.clone())));
-        assert_eq!(super::date_w(b"2020-W53"),  Ok((&[][..],   value.clone())));
-        assert_eq!(super::date_w(b"2020W53 "),  Ok((&b" "[..], value.clone())));
+        assert_eq!(super::date_w(b"2020-W53 "), Ok((&b" "[..], value)));
+        assert_eq!(super::date_w(b"2020-W53"),  Ok((&[][..],   value)));
+        assert_eq!(super::date_w(b"2020W53 "),  Ok((&b" "[..], value)));
         assert_eq!(super::date_w(b"2020W53"),   Ok((&[][..],   value        )));
     }
 
@@ -290,7 +303,7 @@ mod tests {
             year: 1985,
             day: 102
         };
-        assert_eq!(super::date_o(b"1985-102"), Ok((&[][..], value.clone())));
+        assert_eq!(super::date_o(b"1985-102"), Ok((&[][..], value)));
         assert_eq!(super::date_o(b"1985102"),  Ok((&[][..], value        )));
     }
 
@@ -302,7 +315,7 @@ mod tests {
                 month: 2,
                 day: 12
             });
-            assert_eq!(super::date(b"2018-02-12"),  Ok((&[][..],   value.clone())));
+            assert_eq!(super::date(b"2018-02-12"),  Ok((&[][..],   value)));
             assert_eq!(super::date(b"2018-02-12 "), Ok((&b" "[..], value        )));
         }
 
@@ -312,7 +325,7 @@ mod tests {
                 week: 2,
                 day: 2
             });
-            assert_eq!(super::date(b"2018-W02-2"),  Ok((&[][..],   value.clone())));
+            assert_eq!(super::date(b"2018-W02-2"),  Ok((&[][..],   value)));
             assert_eq!(super::date(b"2018-W02-2 "), Ok((&b" "[..], value        )));
         }
 
@@ -321,11 +334,24 @@ mod tests {
                 year: 2018,
                 day: 102
             });
-            assert_eq!(super::date(b"2018-102"),  Ok((&[][..],   value.clone())));
+            assert_eq!(super::date(b"2018-102"),  Ok((&[][..],   value)));
             assert_eq!(super::date(b"2018-102 "), Ok((&b" "[..], value        )));
         }
     }
 
+    #[test]
+    fn seven_digit_date() {
+        let ordinal = Date::O(ODate { year: 1985, day: 102 });
+        let ymd = Date::YMD(YmdDate { year: 1985, month: 10, day: 2 });
+        let options = ParseOptions::default().seven_digit_date(SevenDigitDate::YearMonthDay);
+
+        assert_eq!(super::date(b"1985102"),           Ok((&[][..], ordinal)));
+        assert_eq!(date_with(options)(b"1985102"),    Ok((&[][..], ymd)));
+        assert_eq!(date_with(options)(b"1985-102"),   Ok((&[][..], ordinal)));
+        assert_eq!(date_with(options)(b"19851002"),   Ok((&[][..], ymd)));
+        assert_eq!(date_with(options)(b"1985-10-02"), Ok((&[][..], ymd)));
+    }
+
     #[test]
     fn date_approx() {
         {
@@ -334,9 +360,9 @@ mod tests {
                 month: 5,
                 day: 5
             });
-            assert_eq!(super::date_approx(b"2000-05-05 "), Ok((&b" "[..], value.clone())));
-            assert_eq!(super::date_approx(b"20000505 "),   Ok((&b" "[..], value.clone())));
-            assert_eq!(super::date_approx(b"2000-05-05"),  Ok((&[][..],   value.clone())));
+            assert_eq!(super::date_approx(b"2000-05-05 "), Ok((&b" "[..], value)));
+            assert_eq!(super::date_approx(b"20000505 "),   Ok((&b" "[..], value)));
+            assert_eq!(super::date_approx(b"2000-05-05"),  Ok((&[][..],   value)));
             assert_eq!(super::date_approx(b"20000505"),    Ok((&[][..],   value        )));
         }
         {
@@ -344,21 +370,21 @@ mod tests {
                 year: 2000,
                 month: 5
             });
-            assert_eq!(super::date_approx(b"2000-05 "), Ok((&b" "[..], value.clone())));
+            assert_eq!(super::date_approx(b"2000-05 "), Ok((&b" "[..], value)));
             assert_eq!(super::date_approx(b"2000-05"),  Ok((&[][..],   value        )));
         }
         {
             let value = ApproxDate::Y(YDate {
                 year: 2000
             });
-            assert_eq!(super::date_approx(b"2000 "), Ok((&b" "[..], value.clone())));
+            assert_eq!(super::date_approx(b"2000 "), Ok((&b" "[..], value)));
             assert_eq!(super::date_approx(b"2000"),  Ok((&[][..],   value        )));
         }
         {
             let value = ApproxDate::C(CDate {
                 century: 20
             });
-            assert_eq!(super::date_approx(b"20 "), Ok((&b" "[..], value.clone())));
+            assert_eq!(super::date_approx(b"20 "), Ok((&b" "[..], value)));
             assert_eq!(super::date_approx(b"20"),  Ok((&[][..],   value        )));
         }
 
@@ -368,9 +394,9 @@ mod tests {
                 week: 5,
                 day: 5
             });
-            assert_eq!(super::date_approx(b"2000-W05-5 "), Ok((&b" "[..], value.clone())));
-            assert_eq!(super::date_approx(b"2000-W05-5"),  Ok((&[][..],   value.clone())));
-            assert_eq!(super::date_approx(b"2000W055 "),   Ok((&b" "[..], value.clone())));
+            assert_eq!(super::date_approx(b"2000-W05-5 "), Ok((&b" "[..], value)));
+            assert_eq!(super::date_approx(b"2000-W05-5"),  Ok((&[][..],   value)));
+            assert_eq!(super::date_approx(b"2000W055 "),   Ok((&b" "[..], value)));
             assert_eq!(super::date_approx(b"2000W055"),    Ok((&[][..],   value        )));
         }
         {
@@ -378,9 +404,9 @@ mod tests {
                 year: 2000,
                 week: 5
             });
-            assert_eq!(super::date_approx(b"2000-W05 "), Ok((&b" "[..], value.clone())));
-            assert_eq!(super::date_approx(b"2000-W05"),  Ok((&[][..],   value.clone())));
-            assert_eq!(super::date_approx(b"2000W05 "),  Ok((&b" "[..], value.clone())));
+            assert_eq!(super::date_approx(b"2000-W05 "), Ok((&b" "[..], value)));
+            assert_eq!(super::date_approx(b"2000-W05"),  Ok((&[][..],   value)));
+            assert_eq!(super::date_approx(b"2000W05 "),  Ok((&b" "[..], value)));
             assert_eq!(super::date_approx(b"2000W05"),   Ok((&[][..],   value        )));
         }
 
@@ -389,9 +415,9 @@ mod tests {
                 year: 2000,
                 day: 5
             });
-            assert_eq!(super::date_approx(b"2000-005 "), Ok((&b" "[..], value.clone())));
-            assert_eq!(super::date_approx(b"2000-005"),  Ok((&[][..],   value.clone())));
-            assert_eq!(super::date_approx(b"2000005 "),  Ok((&b" "[..], value.clone())));
+            assert_eq!(super::date_approx(b"2000-005 "), Ok((&b" "[..], value)));
+            assert_eq!(super::date_approx(b"2000-005"),  Ok((&[][..],   value)));
+            assert_eq!(super::date_approx(b"2000005 "),  Ok((&b" "[..], value)));
             assert_eq!(super::date_approx(b"2000005"),   Ok((&[][..],   value        )));
         }
     }
@@ -1,64 +1,277 @@
 use ::{
     datetime::*,
     date::*,
-    time::*
+    time::*,
+    format::StampFormat
 };
 use super::*;
+use super::complete::{
+    fraction,
+    hour,
+    minute,
+    second,
+    sign
+};
+use nom::{
+    IResult,
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::char,
+    combinator::{
+        cond,
+        map,
+        opt,
+        verify
+    },
+    error::{
+        Error,
+        ErrorKind
+    },
+    sequence::tuple
+};
+
+/// The time designator `T` or, if allowed, a space.
+fn datetime_separator<'a>(options: ParseOptions) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], char> {
+    alt((
+        designator('T', options),
+        verify(char(' '), move |_| options.allow_space_separator)
+    ))
+}
 
 macro_rules! datetime {
-    (pub $name:ident, $date:ty, $date_parser:ident, $time:ty, $time_parser:ident) => {
-        named!(pub $name <DateTime<$date, $time>>, do_parse!(
-            date: $date_parser >>
-            char!('T') >>
-            peek!(not!(char!('T'))) >>
-            time: $time_parser >>
-            (DateTime { date, time })
-        ));
+    ($vis:vis $name:ident, $name_with:ident, $date:ty, $date_parser:ident, $time:ty, $time_parser:ident) => {
+        datetime!($vis $name_with, $date, $date_parser, $time, $time_parser);
+
+        $vis fn $name(i: &[u8]) -> IResult<&[u8], DateTime<$date, $time>> {
+            $name_with(ParseOptions::default())(i)
+        }
+    };
+    ($vis:vis $name_with:ident, $date:ty, $date_parser:ident, $time:ty, $time_parser:ident) => {
+        $vis fn $name_with<'a>(options: ParseOptions) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], DateTime<$date, $time>> {
+            map(
+                tuple((
+                    $date_parser(options),
+                    datetime_separator(options),
+                    $time_parser(options.time_designator(TimeDesignator::Forbidden))
+                )),
+                |(date, _, time)| DateTime { date, time }
+            )
+        }
     }
 }
-datetime!(pub datetime_global_hms,           Date,       date,        GlobalTime<HmsTime>, time_global_hms);
-datetime!(pub datetime_global_hm,            Date,       date,        GlobalTime<HmTime>,  time_global_hm);
-datetime!(pub datetime_global_h,             Date,       date,        GlobalTime<HTime>,   time_global_h);
-datetime!(pub datetime_local_hms,            Date,       date,        LocalTime<HmsTime>,  time_local_hms);
-datetime!(pub datetime_local_hm,             Date,       date,        LocalTime<HmTime>,   time_local_hm);
-datetime!(pub datetime_local_h,              Date,       date,        LocalTime<HTime>,    time_local_h);
-datetime!(pub datetime_any_hms,              Date,       date,        AnyTime<HmsTime>,    time_any_hms);
-datetime!(pub datetime_any_hm,               Date,       date,        AnyTime<HmTime>,     time_any_hm);
-datetime!(pub datetime_any_h,                Date,       date,        AnyTime<HTime>,      time_any_h);
-datetime!(pub datetime_global_approx,        Date,       date,        ApproxGlobalTime,    time_global_approx);
-datetime!(pub datetime_local_approx,         Date,       date,        ApproxLocalTime,     time_local_approx);
-datetime!(pub datetime_any_approx,           Date,       date,        ApproxAnyTime,       time_any_approx);
-datetime!(pub datetime_approx_global_hms,    ApproxDate, date_approx, GlobalTime<HmsTime>, time_global_hms);
-datetime!(pub datetime_approx_global_hm,     ApproxDate, date_approx, GlobalTime<HmTime>,  time_global_hm);
-datetime!(pub datetime_approx_global_h,      ApproxDate, date_approx, GlobalTime<HTime>,   time_global_h);
-datetime!(pub datetime_approx_local_hms,     ApproxDate, date_approx, LocalTime<HmsTime>,  time_local_hms);
-datetime!(pub datetime_approx_local_hm,      ApproxDate, date_approx, LocalTime<HmTime>,   time_local_hm);
-datetime!(pub datetime_approx_local_h,       ApproxDate, date_approx, LocalTime<HTime>,    time_local_h);
-datetime!(pub datetime_approx_any_hms,       ApproxDate, date_approx, AnyTime<HmsTime>,    time_any_hms);
-datetime!(pub datetime_approx_any_hm,        ApproxDate, date_approx, AnyTime<HmTime>,     time_any_hm);
-datetime!(pub datetime_approx_any_h,         ApproxDate, date_approx, AnyTime<HTime>,      time_any_h);
-datetime!(pub datetime_approx_global_approx, ApproxDate, date_approx, ApproxGlobalTime,    time_global_approx);
-datetime!(pub datetime_approx_local_approx,  ApproxDate, date_approx, ApproxLocalTime,     time_local_approx);
-datetime!(pub datetime_approx_any_approx,    ApproxDate, date_approx, ApproxAnyTime,       time_any_approx);
-
-named!(pub partial_datetime_approx_any_approx <PartialDateTime<ApproxDate, ApproxAnyTime>>, do_parse!(
-    has_date: opt!(peek!(re_bytes_match!("^(.+T.*|[^T:]*)$"))) >>
-    date: cond!(has_date.is_some(), date_approx) >>
-    opt!(complete!(char!('T'))) >>
-    opt!(complete!(peek!(not!(char!('T'))))) >>
-    time: opt!(time_any_approx) >>
-    (match (date, time) {
-        (None, None) => return Err(nom::Err::Incomplete(nom::Needed::Unknown)),
+datetime!(pub(super) datetime_global_hms_grammar_with,                                Date,       date_with,        GlobalTime<HmsTime>, time_global_hms_with);
+datetime!(pub datetime_global_hm,                 datetime_global_hm_with,            Date,       date_with,        GlobalTime<HmTime>,  time_global_hm_with);
+datetime!(pub datetime_global_h,                  datetime_global_h_with,             Date,       date_with,        GlobalTime<HTime>,   time_global_h_with);
+datetime!(pub datetime_local_hms,                 datetime_local_hms_with,            Date,       date_with,        LocalTime<HmsTime>,  time_local_hms_with);
+datetime!(pub datetime_local_hm,                  datetime_local_hm_with,             Date,       date_with,        LocalTime<HmTime>,   time_local_hm_with);
+datetime!(pub datetime_local_h,                   datetime_local_h_with,              Date,       date_with,        LocalTime<HTime>,    time_local_h_with);
+datetime!(pub(super) datetime_any_hms_grammar_with,                                   Date,       date_with,        AnyTime<HmsTime>,    time_any_hms_with);
+datetime!(pub datetime_any_hm,                    datetime_any_hm_with,               Date,       date_with,        AnyTime<HmTime>,     time_any_hm_with);
+datetime!(pub datetime_any_h,                     datetime_any_h_with,                Date,       date_with,        AnyTime<HTime>,      time_any_h_with);
+datetime!(pub datetime_global_approx,             datetime_global_approx_with,        Date,       date_with,        ApproxGlobalTime,    time_global_approx_with);
+datetime!(pub datetime_local_approx,              datetime_local_approx_with,         Date,       date_with,        ApproxLocalTime,     time_local_approx_with);
+datetime!(pub datetime_any_approx,                datetime_any_approx_with,           Date,       date_with,        ApproxAnyTime,       time_any_approx_with);
+datetime!(pub datetime_approx_global_hms,         datetime_approx_global_hms_with,    ApproxDate, date_approx_with, GlobalTime<HmsTime>, time_global_hms_with);
+datetime!(pub datetime_approx_global_hm,          datetime_approx_global_hm_with,     ApproxDate, date_approx_with, GlobalTime<HmTime>,  time_global_hm_with);
+datetime!(pub datetime_approx_global_h,           datetime_approx_global_h_with,      ApproxDate, date_approx_with, GlobalTime<HTime>,   time_global_h_with);
+datetime!(pub datetime_approx_local_hms,          datetime_approx_local_hms_with,     ApproxDate, date_approx_with, LocalTime<HmsTime>,  time_local_hms_with);
+datetime!(pub datetime_approx_local_hm,           datetime_approx_local_hm_with,      ApproxDate, date_approx_with, LocalTime<HmTime>,   time_local_hm_with);
+datetime!(pub datetime_approx_local_h,            datetime_approx_local_h_with,       ApproxDate, date_approx_with, LocalTime<HTime>,    time_local_h_with);
+datetime!(pub datetime_approx_any_hms,            datetime_approx_any_hms_with,       ApproxDate, date_approx_with, AnyTime<HmsTime>,    time_any_hms_with);
+datetime!(pub datetime_approx_any_hm,             datetime_approx_any_hm_with,        ApproxDate, date_approx_with, AnyTime<HmTime>,     time_any_hm_with);
+datetime!(pub datetime_approx_any_h,              datetime_approx_any_h_with,         ApproxDate, date_approx_with, AnyTime<HTime>,      time_any_h_with);
+datetime!(pub datetime_approx_global_approx,      datetime_approx_global_approx_with, ApproxDate, date_approx_with, ApproxGlobalTime,    time_global_approx_with);
+datetime!(pub datetime_approx_local_approx,       datetime_approx_local_approx_with,  ApproxDate, date_approx_with, ApproxLocalTime,     time_local_approx_with);
+datetime!(pub datetime_approx_any_approx,         datetime_approx_any_approx_with,    ApproxDate, date_approx_with, ApproxAnyTime,       time_any_approx_with);
+
+/// The fast path, unless the options change the value.
+fn fast_datetime_global_hms(i: &[u8], options: ParseOptions) -> Option<(&[u8], fast::GlobalHmsDateTime)> {
+    if options.max_fraction_digits.is_some() {
+        return None;
+    }
+    fast::datetime_global_hms(i)
+}
+
+// tries the fast path for the common `YYYY-MM-DDThh:mm:ss[.f](Z|±hh:mm)` first
+pub fn datetime_global_hms_with<'a>(options: ParseOptions) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], DateTime<Date, GlobalTime<HmsTime>>> {
+    move |i| match fast_datetime_global_hms(i, options) {
+        Some(result) => Ok(result),
+        None => datetime_global_hms_grammar_with(options)(i)
+    }
+}
+
+pub fn datetime_global_hms(i: &[u8]) -> IResult<&[u8], DateTime<Date, GlobalTime<HmsTime>>> {
+    datetime_global_hms_with(ParseOptions::default())(i)
+}
+
+// see `datetime_global_hms_with`
+pub fn datetime_any_hms_with<'a>(options: ParseOptions) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], DateTime<Date, AnyTime<HmsTime>>> {
+    move |i| match fast_datetime_global_hms(i, options) {
+        Some((i, DateTime { date, time })) => Ok((i, DateTime { date, time: AnyTime::Global(time) })),
+        None => datetime_any_hms_grammar_with(options)(i)
+    }
+}
+
+pub fn datetime_any_hms(i: &[u8]) -> IResult<&[u8], DateTime<Date, AnyTime<HmsTime>>> {
+    datetime_any_hms_with(ParseOptions::default())(i)
+}
+
+/// The separator of a filename stamp, if it has one.
+fn stamp_separator<'a>(separator: Option<char>) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], Option<char>> {
+    let mut buf = [0; 4];
+    let len = separator.map_or(0, |separator| separator.len_utf8());
+    if let Some(separator) = separator {
+        separator.encode_utf8(&mut buf);
+    }
+    move |i| map(tag(&buf[..len]), |_| separator)(i)
+}
+
+/// A stamp written by [`DateTime::to_filename_stamp_with`] in the given format.
+pub fn filename_stamp_with<'a>(format: StampFormat) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], DateTime> {
+    let separator = match format {
+        StampFormat::Basic => None,
+        StampFormat::Extended(separator) => Some(separator)
+    };
+    map(
+        tuple((
+            date_ymd,
+            char('T'),
+            hour,
+            stamp_separator(separator),
+            minute,
+            stamp_separator(separator),
+            second,
+            opt(fraction),
+            alt((
+                map(char('Z'), |_| 0),
+                map(
+                    tuple((sign, hour, stamp_separator(separator), minute)),
+                    |(sign, hour, _, minute)| i16::from(sign) * (i16::from(hour) * 60 + i16::from(minute))
+                )
+            ))
+        )),
+        |(date, _, hour, _, minute, _, second, fraction, timezone)| DateTime {
+            date,
+            time: GlobalTime {
+                local: LocalTime {
+                    naive: HmsTime { hour, minute, second },
+                    fraction: fraction.unwrap_or(0.)
+                },
+                timezone
+            }
+        }
+    )
+}
+
+/// A stamp written by [`DateTime::to_filename_stamp`].
+pub fn filename_stamp(i: &[u8]) -> IResult<&[u8], DateTime> {
+    filename_stamp_with(StampFormat::Basic)(i)
+}
+
+/// Whether the input starts with a date:
+/// it has a time designator that is not its first character,
+/// or neither a time designator nor a time separator.
+fn partial_datetime_has_date(i: &[u8]) -> bool {
+    i.iter().skip(1).any(|&c| c == b'T') ||
+    !i.iter().any(|&c| c == b'T' || c == b':')
+}
+
+pub fn partial_datetime_approx_any_approx(i: &[u8]) -> IResult<&[u8], PartialDateTime<ApproxDate, ApproxAnyTime>> {
+    let (i, date) = cond(partial_datetime_has_date(i), date_approx)(i)?;
+    let (i, _) = opt(char('T'))(i)?;
+    let (i, time) = opt(time_any_approx_with(ParseOptions::default().time_designator(TimeDesignator::Forbidden)))(i)?;
+    Ok((i, match (date, time) {
+        (None, None) => return Err(nom::Err::Error(Error::new(i, ErrorKind::Alt))),
         (Some(date), None) => PartialDateTime::Date(date),
         (None, Some(time)) => PartialDateTime::Time(time),
-        (Some(date), Some(time)) => PartialDateTime::DateTime(DateTime { date, time }),
-    })
-));
+        (Some(date), Some(time)) => PartialDateTime::DateTime(DateTime { date, time })
+    }))
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn space_separator() {
+        let options = ParseOptions::default().allow_space_separator(true);
+        let expected = datetime_global_hms(b"2024-05-01T12:00:00+02:00").unwrap().1;
+        assert_eq!(datetime_global_hms_with(options)(b"2024-05-01 12:00:00+02"),   Ok((&[][..], expected)));
+        assert_eq!(datetime_global_hms_with(options)(b"2024-05-01 12:00:00+0200"), Ok((&[][..], expected)));
+        assert!(datetime_global_hms(b"2024-05-01 12:00:00+02").is_err());
+        assert!(datetime_local_hm_with(options)(b"2024-05-01  12:00").is_err());
+    }
+
+    #[test]
+    fn lowercase() {
+        let options = ParseOptions::default().allow_lowercase(true);
+        let expected = datetime_global_hms(b"2024-05-01T12:00:00Z").unwrap().1;
+        assert_eq!(datetime_global_hms_with(options)(b"2024-05-01t12:00:00z"), Ok((&[][..], expected)));
+        assert_eq!(datetime_global_hms_with(options)(b"2024-05-01T12:00:00z"), Ok((&[][..], expected)));
+        assert!(datetime_global_hms(b"2024-05-01t12:00:00Z").is_err());
+        assert!(datetime_global_hms(b"2024-05-01T12:00:00z").is_err());
+        assert!(datetime_local_hms_with(options)(b"2024-05-01tt12:00:00").is_err());
+    }
+
+    #[test]
+    fn max_fraction_digits() {
+        let parse = |s: &[u8], digits| datetime_global_hms_with(ParseOptions::default().max_fraction_digits(digits))(s).unwrap().1;
+        let fraction = |s: &[u8], digits| parse(s, digits).time.local.fraction;
+
+        assert_eq!(fraction(b"2018-08-02T13:42:02.123456789Z", Some(3)), 0.123);
+        assert_eq!(fraction(b"2018-08-02T13:42:02.123456789Z", Some(0)), 0.);
+        assert_eq!(fraction(b"2018-08-02T13:42:02.7Z", Some(1)),         0.7);
+        assert_eq!(fraction(b"2018-08-02T13:42:02.29Z", Some(2)),        0.29);
+        assert_eq!(fraction(b"2018-08-02T13:42:02.5Z", Some(3)),         0.5);
+        assert_eq!(fraction(b"2018-08-02T13:42:02Z", Some(3)),           0.);
+        assert_eq!(
+            parse(b"2018-08-02T13:42:02.999999+02:00", Some(3)),
+            parse(b"2018-08-02T13:42:02.999+02:00", None)
+        );
+
+        let options = ParseOptions::default().max_fraction_digits(Some(3));
+        assert_eq!(datetime_local_hm_with(options)(b"2018-08-02T13:42.12345").unwrap().1.time.fraction, 0.123);
+    }
+
+    #[test]
+    fn filename_stamp() {
+        let dt = DateTime {
+            date: YmdDate { year: 2024, month: 5, day: 1 },
+            time: GlobalTime { local: LocalTime { naive: HmsTime { hour: 12, minute: 30, second: 0 }, fraction: 0. }, timezone: 0 }
+        };
+        assert_eq!(dt.to_filename_stamp().unwrap(), "20240501T123000Z");
+        assert_eq!(super::filename_stamp(b"20240501T123000Z"), Ok((&[][..], dt)));
+
+        let dash = StampFormat::Extended('-');
+        assert_eq!(dt.to_filename_stamp_with(dash).unwrap(), "2024-05-01T12-30-00Z");
+        assert_eq!(filename_stamp_with(dash)(b"2024-05-01T12-30-00Z"), Ok((&[][..], dt)));
+        assert!(super::filename_stamp(b"2024-05-01T12-30-00Z").is_err());
+
+        let dt = DateTime { time: GlobalTime { timezone: -330, local: LocalTime { fraction: 0.25, ..dt.time.local } }, ..dt };
+        for &format in &[StampFormat::Basic, dash, StampFormat::Extended('_'), StampFormat::Extended('꞉')] {
+            let stamp = dt.to_filename_stamp_with(format).unwrap();
+            assert!(!stamp.contains(':'), "{}", stamp);
+            assert_eq!(filename_stamp_with(format)(stamp.as_bytes()), Ok((&[][..], dt)), "{}", stamp);
+        }
+        assert_eq!(dt.to_filename_stamp().unwrap(), "20240501T123000.25-0530");
+        assert_eq!(dt.to_filename_stamp_with(dash).unwrap(), "2024-05-01T12-30-00.25-05-30");
+
+        let invalid: DateTime<Date> = "2024-400T12:30:00Z".parse().unwrap();
+        assert_eq!(invalid.to_filename_stamp(), None);
+    }
+
+    #[test]
+    fn time_designator() {
+        let expected = datetime_local_hm(b"2024-05-01T12:00").unwrap().1;
+        for &designator in &[TimeDesignator::Optional, TimeDesignator::Required, TimeDesignator::Forbidden] {
+            let options = ParseOptions::default().time_designator(designator).allow_space_separator(true);
+            assert_eq!(datetime_local_hm_with(options)(b"2024-05-01T12:00"), Ok((&[][..], expected)));
+            assert_eq!(datetime_local_hm_with(options)(b"2024-05-01 12:00"), Ok((&[][..], expected)));
+            assert!(datetime_local_hm_with(options)(b"2024-05-01 T12:00").is_err());
+        }
+        assert_eq!(partial_datetime_approx_any_approx(b"2024-05-01TT12:00").unwrap().0, &b"T12:00"[..]);
+    }
+
     #[test]
     #[should_panic]
     fn tt() {
@@ -137,7 +350,7 @@ mod tests {
             fraction: 0.,
         })));
 
-        assert_eq!(partial_datetime_approx_any_approx(b"T12:30"), Ok((&[][..], result.clone())));
+        assert_eq!(partial_datetime_approx_any_approx(b"T12:30"), Ok((&[][..], result)));
         assert_eq!(partial_datetime_approx_any_approx(b"12:30"),  Ok((&[][..], result        )));
     }
 
@@ -164,7 +377,7 @@ mod tests {
             fraction: 0.,
         })));
 
-        assert_eq!(partial_datetime_approx_any_approx(b"T12:30:15"), Ok((&[][..], result.clone())));
+        assert_eq!(partial_datetime_approx_any_approx(b"T12:30:15"), Ok((&[][..], result)));
         assert_eq!(partial_datetime_approx_any_approx(b"12:30:15"),  Ok((&[][..], result        )));
     }
 
@@ -191,7 +404,7 @@ mod tests {
             fraction: 0.2,
         })));
 
-        assert_eq!(partial_datetime_approx_any_approx(b"T12:30:15.2"), Ok((&[][..], result.clone())));
+        assert_eq!(partial_datetime_approx_any_approx(b"T12:30:15.2"), Ok((&[][..], result)));
         assert_eq!(partial_datetime_approx_any_approx(b"12:30:15.2"),  Ok((&[][..], result        )));
     }
 
@@ -213,7 +426,7 @@ mod tests {
             }))
         });
 
-        assert_eq!(partial_datetime_approx_any_approx(b"2018-08-02T12:30:15.2"), Ok((&[][..], result.clone())));
+        assert_eq!(partial_datetime_approx_any_approx(b"2018-08-02T12:30:15.2"), Ok((&[][..], result)));
         assert_eq!(partial_datetime_approx_any_approx(b"20180802T123015.2"),     Ok((&[][..], result        )));
     }
 }
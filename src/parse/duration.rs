@@ -0,0 +1,289 @@
+use ::duration::*;
+use super::*;
+use nom::{
+    IResult,
+    branch::alt,
+    bytes::complete::{
+        take_while1,
+        take_while_m_n
+    },
+    character::{
+        is_digit,
+        complete::{
+            char,
+            one_of
+        }
+    },
+    combinator::{
+        cond,
+        map,
+        map_opt,
+        not,
+        opt
+    },
+    sequence::{
+        delimited,
+        pair,
+        preceded,
+        terminated,
+        tuple
+    }
+};
+
+type DurationNumber = (u32, Option<f64>);
+type DurationAlternativeTime = (u32, u32, u32, Option<f64>);
+
+fn duration_fraction(i: &[u8]) -> IResult<&[u8], f64> {
+    map(
+        preceded(one_of(".,"), take_while1(is_digit)),
+        |digits: &[u8]| buf_to_int::<f64>(digits) / 10f64.powi(digits.len() as i32)
+    )(i)
+}
+
+fn duration_number(i: &[u8]) -> IResult<&[u8], DurationNumber> {
+    pair(
+        map(take_while_m_n(1, 9, is_digit), buf_to_int),
+        opt(duration_fraction)
+    )(i)
+}
+
+fn duration_component<'a>(designator: char) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], Option<DurationNumber>> {
+    opt(terminated(duration_number, char(designator)))
+}
+
+fn duration_time(i: &[u8]) -> IResult<&[u8], [Option<DurationNumber>; 3]> {
+    map(
+        preceded(char('T'), tuple((
+            duration_component('H'),
+            duration_component('M'),
+            duration_component('S')
+        ))),
+        |(hours, minutes, seconds)| [hours, minutes, seconds]
+    )(i)
+}
+
+fn duration_weeks(i: &[u8]) -> IResult<&[u8], Duration> {
+    map_opt(
+        delimited(
+            char('P'),
+            terminated(duration_number, char('W')),
+            // weeks may not be combined with other components
+            not(one_of("0123456789T"))
+        ),
+        |(weeks, fraction)| Duration::from_weeks(weeks).checked_add(
+            &match fraction.map(|x| x * 7.) {
+                Some(days) => Duration::from_parts([
+                    None, None, Some((days.trunc() as u32, Some(days.fract()))), None, None, None
+                ])?,
+                None => Duration::default()
+            }
+        )
+    )(i)
+}
+
+fn duration_designators(i: &[u8]) -> IResult<&[u8], Duration> {
+    map_opt(
+        tuple((
+            char('P'),
+            duration_component('Y'),
+            duration_component('M'),
+            duration_component('D'),
+            opt(duration_time),
+            // such as weeks following other components
+            not(one_of("0123456789"))
+        )),
+        |(_, years, months, days, time, _)| match time {
+            // the time designator must be followed by a time component
+            Some([None, None, None]) => None,
+            _ => Duration::from_parts([
+                years,
+                months,
+                days,
+                time.and_then(|x| x[0]),
+                time.and_then(|x| x[1]),
+                time.and_then(|x| x[2])
+            ])
+        }
+    )(i)
+}
+
+fn duration_digits<'a>(n: usize) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], u32> {
+    map(take_while_m_n(n, n, is_digit), buf_to_int)
+}
+
+fn duration_alternative_time<'a>(extended: bool) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], DurationAlternativeTime> {
+    map(
+        tuple((
+            char('T'),
+            duration_digits(2),
+            cond(extended, char(':')),
+            duration_digits(2),
+            cond(extended, char(':')),
+            duration_digits(2),
+            opt(duration_fraction)
+        )),
+        |(_, hours, _, minutes, _, seconds, fraction)| (hours, minutes, seconds, fraction)
+    )
+}
+
+// alternative format (4.4.3.3)
+fn duration_alternative_format<'a>(extended: bool) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], Duration> {
+    map_opt(
+        tuple((
+            char('P'),
+            duration_digits(4),
+            cond(extended, char('-')),
+            duration_digits(2),
+            cond(extended, char('-')),
+            duration_digits(2),
+            opt(duration_alternative_time(extended))
+        )),
+        |(_, years, _, months, _, days, time)| {
+            let (hours, minutes, seconds, fraction) = time.unwrap_or_default();
+
+            // values may not exceed their carry-over points
+            if months > 12 || days > 30 || hours > 24 || minutes > 59 || seconds > 59 {
+                return None;
+            }
+
+            Duration::from_parts([
+                Some((years,   None)),
+                Some((months,  None)),
+                Some((days,    None)),
+                Some((hours,   None)),
+                Some((minutes, None)),
+                Some((seconds, fraction))
+            ])
+        }
+    )
+}
+
+/// Duration that is not signed, as in intervals.
+pub(super) fn duration_unsigned(i: &[u8]) -> IResult<&[u8], Duration> {
+    alt((
+        duration_weeks,
+        duration_designators,
+        duration_alternative_format(true),
+        duration_alternative_format(false)
+    ))(i)
+}
+
+/// Duration that may be negative as in ISO 8601-2, such as `-P1D`.
+pub fn duration(i: &[u8]) -> IResult<&[u8], Duration> {
+    map(
+        pair(opt(char('-')), duration_unsigned),
+        |(sign, duration)| Duration {
+            negative: sign.is_some(),
+            ..duration
+        }
+    )(i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn duration_number() {
+        assert_eq!(super::duration_number(b"12Y"),   Ok((&b"Y"[..], (12, None))));
+        assert_eq!(super::duration_number(b"1,5Y"),  Ok((&b"Y"[..], (1, Some(0.5)))));
+        assert_eq!(super::duration_number(b"0.25S"), Ok((&b"S"[..], (0, Some(0.25)))));
+    }
+
+    #[test]
+    fn duration() {
+        assert_eq!(super::duration(b"P1Y2M3DT4H5M6S"), Ok((&[][..], Duration {
+            years: 1,
+            months: 2,
+            weeks: 0,
+            days: 3,
+            hours: 4,
+            minutes: 5,
+            seconds: 6,
+            nanoseconds: 0,
+            negative: false
+        })));
+        assert_eq!(super::duration(b"P2M "), Ok((&b" "[..], Duration {
+            months: 2,
+            ..Default::default()
+        })));
+        assert_eq!(super::duration(b"PT2M"), Ok((&[][..], Duration {
+            minutes: 2,
+            ..Default::default()
+        })));
+        assert_eq!(super::duration(b"PT1.5H"), Ok((&[][..], Duration {
+            hours: 1,
+            minutes: 30,
+            ..Default::default()
+        })));
+        assert_eq!(super::duration(b"P0.5Y"), Ok((&[][..], Duration {
+            months: 6,
+            ..Default::default()
+        })));
+        assert_eq!(super::duration(b"P0.5D"), Ok((&[][..], Duration {
+            hours: 12,
+            ..Default::default()
+        })));
+        assert_eq!(super::duration(b"PT0,000000001S"), Ok((&[][..], Duration {
+            nanoseconds: 1,
+            ..Default::default()
+        })));
+
+        let alternative = Duration {
+            years: 3,
+            months: 6,
+            days: 4,
+            hours: 12,
+            minutes: 30,
+            seconds: 5,
+            ..Default::default()
+        };
+        assert_eq!(super::duration(b"P0003-06-04T12:30:05"), Ok((&[][..], alternative.clone())));
+        assert_eq!(super::duration(b"P00030604T123005"),     Ok((&[][..], alternative)));
+        assert_eq!(super::duration(b"P0000-00-01"), Ok((&[][..], Duration {
+            days: 1,
+            ..Default::default()
+        })));
+        assert_eq!(super::duration(b"P00000000T000000,5"), Ok((&[][..], Duration {
+            nanoseconds: 500_000_000,
+            ..Default::default()
+        })));
+
+        assert_eq!(super::duration(b"P4W"), Ok((&[][..], Duration {
+            weeks: 4,
+            ..Default::default()
+        })));
+        assert_eq!(super::duration(b"P1.5W"), Ok((&[][..], Duration {
+            weeks: 1,
+            days: 3,
+            hours: 12,
+            ..Default::default()
+        })));
+
+        assert_eq!(super::duration(b"-P1DT2H"), Ok((&[][..], Duration {
+            days: 1,
+            hours: 2,
+            negative: true,
+            ..Default::default()
+        })));
+        assert_eq!(super::duration(b"-P0001-00-00"), Ok((&[][..], Duration {
+            years: 1,
+            negative: true,
+            ..Default::default()
+        })));
+
+        assert!(super::duration(b"P").is_err());
+        assert!(super::duration(b"--P1D").is_err());
+        assert!(super::duration(b"+P1D").is_err());
+        assert!(super::duration(b"P0000-13-00").is_err());
+        assert!(super::duration(b"P00000000T000060").is_err());
+        assert!(super::duration(b"P1W1D").is_err());
+        assert!(super::duration(b"P1Y1W").is_err());
+        assert!(super::duration(b"P1WT1H").is_err());
+        assert!(super::duration(b"PT").is_err());
+        assert!(super::duration(b"P1DT").is_err());
+        assert!(super::duration(b"P1.5DT1H").is_err());
+        assert!(super::duration(b"P0.5M").is_err());
+        assert!(super::duration(b"P0.1Y").is_err());
+    }
+}
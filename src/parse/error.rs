@@ -0,0 +1,216 @@
+use std::{
+    error::Error,
+    fmt,
+    ops::Range
+};
+use nom::{
+    self,
+    IResult
+};
+
+/// Why an input could not be parsed.
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub enum ParseErrorKind {
+    /// The input does not match the representation.
+    Invalid,
+    /// The input ends before the representation is complete.
+    Incomplete,
+    /// The representation is followed by more input.
+    Trailing
+}
+
+/// Why and where an input could not be parsed.
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub struct ParseError {
+    pub kind: ParseErrorKind,
+    /// Byte offset into the input.
+    pub position: usize,
+    len: usize,
+    suggestion: Option<&'static str>
+}
+
+impl ParseError {
+    /// Requires the parser to have consumed the whole input.
+    pub(crate) fn from_result<T>(input: &[u8], result: IResult<&[u8], T>) -> Result<T, Self> {
+        let (kind, rest) = match result {
+            Ok(([], value)) => return Ok(value),
+            Ok((rest, _)) => (ParseErrorKind::Trailing, rest),
+            Err(nom::Err::Incomplete(_)) => (ParseErrorKind::Incomplete, &[][..]),
+            Err(nom::Err::Error(e)) |
+            Err(nom::Err::Failure(e)) => (ParseErrorKind::Invalid, e.input)
+        };
+        Err(ParseError {
+            kind,
+            position: input.len() - rest.len(),
+            len: match kind {
+                // the offending component, a run of digits or a single character
+                ParseErrorKind::Invalid => match rest.iter().take_while(|c| c.is_ascii_digit()).count() {
+                    0 => rest.len().min(1),
+                    n => n
+                },
+                _ => rest.len()
+            },
+            suggestion: match kind {
+                ParseErrorKind::Invalid => suggest(input),
+                _ => None
+            }
+        })
+    }
+
+    /// For input that matches the grammar but has an invalid value,
+    /// such as day 400 of a year, within the given part of the input.
+    pub(crate) fn invalid_value(input: &[u8], span: Range<usize>) -> Self {
+        ParseError {
+            kind: ParseErrorKind::Invalid,
+            position: span.start,
+            len: span.len(),
+            suggestion: suggest(input)
+        }
+    }
+
+    /// Byte range of the offending part of the input.
+    pub fn span(&self) -> Range<usize> {
+        self.position..self.position + self.len
+    }
+
+    /// Renders the input with the span underlined, for humans.
+    pub fn annotate<'a>(&'a self, input: &'a str) -> Annotated<'a> {
+        Annotated {
+            error: self,
+            input
+        }
+    }
+
+    /// A hint for humans on how to fix the input,
+    /// if the error looks like a common mistake.
+    pub fn suggestion(&self) -> Option<&'static str> {
+        self.suggestion
+    }
+}
+
+/// Guesses the mistake from the input as a whole,
+/// since the position may be where the last alternative failed.
+fn suggest(input: &[u8]) -> Option<&'static str> {
+    let digits = |i: &[u8]| i.iter().take_while(|c| c.is_ascii_digit()).count();
+    let in_range = |i: &[u8], max| {
+        let n = digits(i);
+        n == 2 && (1..=max).contains(&((i[0] - b'0') * 10 + (i[1] - b'0')))
+    };
+
+    let date = match input.first() {
+        Some(b'+') | Some(b'-') => &input[1..],
+        _ => input
+    };
+    let time = input.iter().position(|&c| c == b'T' || c == b't' || c == b' ').map(|i| &input[i + 1..]);
+
+    Some(if input.windows(2).any(|x| x[0].is_ascii_digit() && x[1] == b' ') {
+        "missing 'T' separator \u{2014} use parse option allow_space_separator"
+    } else if input.iter().any(|&c| c == b't' || c == b'z') {
+        "designators must be uppercase \u{2014} use parse option allow_lowercase"
+    } else if digits(date) == 4 && date.get(4) == Some(&b'-') && date.get(5) != Some(&b'W') && digits(&date[5..]) != 3 && !in_range(&date[5..], 12) {
+        "month must be 01\u{2013}12"
+    } else if digits(date) == 4 && date.get(7) == Some(&b'-') && !in_range(&date[8..], 31) {
+        "day must be 01\u{2013}31"
+    } else if time.is_some_and(|x| digits(x) == 1 || digits(x) == 3) {
+        "hour must be two digits, 00\u{2013}24"
+    } else if input.contains(&b',') {
+        "this parser only accepts '.' as the decimal sign"
+    } else {
+        return None;
+    })
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            ParseErrorKind::Invalid    => write!(f, "invalid input at byte {}", self.position),
+            ParseErrorKind::Incomplete => write!(f, "incomplete input"),
+            ParseErrorKind::Trailing   => write!(f, "trailing input at byte {}", self.position)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// A [`ParseError`] along with its input,
+/// displayed as a caret diagnostic:
+///
+/// ```text
+/// 2018-08-02 13:42:02Z
+///           ^ invalid input at byte 10
+///   missing 'T' separator — use parse option allow_space_separator
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Annotated<'a> {
+    error: &'a ParseError,
+    input: &'a str
+}
+
+impl<'a> fmt::Display for Annotated<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let span = self.error.span();
+        // columns count characters, not bytes
+        let column = |i: usize| self.input.get(..i).map_or(i, |x| x.chars().count());
+        let (start, end) = (column(span.start), column(span.end));
+
+        writeln!(f, "{}", self.input)?;
+        writeln!(f, "{:start$}{} {}", "", "^".repeat((end - start).max(1)), self.error, start = start)?;
+        if let Some(suggestion) = self.error.suggestion() {
+            writeln!(f, "  {}", suggestion)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::{
+        date,
+        datetime_global_hms
+    };
+
+    #[test]
+    fn from_result() {
+        assert!(ParseError::from_result(b"2018-08-02", date(b"2018-08-02")).is_ok());
+        assert_eq!(ParseError::from_result(b"2018-08-02 ", date(b"2018-08-02 ")), Err(ParseError {
+            kind: ParseErrorKind::Trailing,
+            position: 10,
+            len: 1,
+            suggestion: None
+        }));
+        assert_eq!(ParseError::from_result(b"x", date(b"x")), Err(ParseError {
+            kind: ParseErrorKind::Invalid,
+            position: 0,
+            len: 1,
+            suggestion: None
+        }));
+    }
+
+    #[test]
+    fn span() {
+        let error = |i: &[u8]| ParseError::from_result(i, datetime_global_hms(i)).unwrap_err();
+        assert_eq!(error(b"2018-08-02T13:42:02Z ").span(), 20..21);
+        assert_eq!(error(b"2018-08-02T13:42:02Z trailing").span(), 20..29);
+
+        let input = "2018-08-02 13:42:02Z";
+        assert_eq!(error(input.as_bytes()).annotate(input).to_string(), concat!(
+            "2018-08-02 13:42:02Z\n",
+            "          ^ invalid input at byte 10\n",
+            "  missing 'T' separator \u{2014} use parse option allow_space_separator\n"
+        ));
+    }
+
+    #[test]
+    fn suggestion() {
+        let suggestion = |i: &[u8]| ParseError::from_result(i, datetime_global_hms(i)).unwrap_err().suggestion();
+        assert_eq!(suggestion(b"2018-08-02 13:42:02Z"), Some("missing 'T' separator \u{2014} use parse option allow_space_separator"));
+        assert_eq!(suggestion(b"2018-08-02t13:42:02Z"), Some("designators must be uppercase \u{2014} use parse option allow_lowercase"));
+        assert_eq!(suggestion(b"2018-08-02T13:42:02z"), Some("designators must be uppercase \u{2014} use parse option allow_lowercase"));
+        assert_eq!(suggestion(b"2018-8-02T13:42:02Z"),  Some("month must be 01\u{2013}12"));
+        assert_eq!(suggestion(b"2018-08-2T13:42:02Z"),  Some("day must be 01\u{2013}31"));
+        assert_eq!(suggestion(b"2018-08-02T1:42:02Z"),  Some("hour must be two digits, 00\u{2013}24"));
+        assert_eq!(suggestion(b"2018-08-02T13:42:02,5Z"), Some("this parser only accepts '.' as the decimal sign"));
+        assert_eq!(suggestion(b"x"), None);
+    }
+}
@@ -0,0 +1,125 @@
+//! Fast path for the most common shape of timestamps,
+//! `YYYY-MM-DDThh:mm:ss[.f](Z|±hh:mm)`,
+//! checking digits at fixed offsets instead of walking the grammar.
+
+use ::{
+    date::*,
+    time::*,
+    datetime::*
+};
+
+pub(super) type GlobalHmsDateTime = DateTime<Date, GlobalTime<HmsTime>>;
+
+fn digit(i: &[u8], at: usize) -> Option<u8> {
+    match i.get(at) {
+        Some(c) if c.is_ascii_digit() => Some(c - b'0'),
+        _ => None
+    }
+}
+
+fn two_digits(i: &[u8], at: usize) -> Option<u8> {
+    Some(digit(i, at)? * 10 + digit(i, at + 1)?)
+}
+
+/// `None` if the input does not have exactly the supported shape,
+/// in which case it must be left to the general grammar.
+/// Otherwise the result is the same as the general grammar's.
+pub(crate) fn datetime_global_hms(i: &[u8]) -> Option<(&[u8], GlobalHmsDateTime)> {
+    if i.len() < 20 ||
+        i[4]  != b'-' ||
+        i[7]  != b'-' ||
+        i[10] != b'T' ||
+        i[13] != b':' ||
+        i[16] != b':'
+    {
+        return None;
+    }
+
+    let year = i16::from(two_digits(i, 0)?) * 100 + i16::from(two_digits(i, 2)?);
+    let month  = two_digits(i, 5)?;
+    let day    = two_digits(i, 8)?;
+    let hour   = two_digits(i, 11)?;
+    let minute = two_digits(i, 14)?;
+    let second = two_digits(i, 17)?;
+
+    let mut at = 19;
+    let fraction = if i[at] == b'.' {
+        let digits = i[at + 1..].iter().take_while(|c| c.is_ascii_digit()).count();
+        if digits == 0 {
+            return None;
+        }
+        at += 1 + digits;
+        // leave exponents to the general grammar
+        if let Some(b'e') | Some(b'E') = i.get(at) {
+            return None;
+        }
+        ::std::str::from_utf8(&i[19..at]).ok()?.parse().ok()?
+    } else {
+        0.
+    };
+
+    let timezone = match *i.get(at)? {
+        b'Z' => {
+            at += 1;
+            0
+        }
+        sign @ b'+' | sign @ b'-' => {
+            if i.get(at + 3) != Some(&b':') {
+                return None;
+            }
+            let offset = i16::from(two_digits(i, at + 1)?) * 60 + i16::from(two_digits(i, at + 4)?);
+            at += 6;
+            if sign == b'-' { -offset } else { offset }
+        }
+        _ => return None
+    };
+
+    Some((&i[at..], DateTime {
+        date: Date::YMD(YmdDate { year, month, day }),
+        time: GlobalTime {
+            local: LocalTime {
+                naive: HmsTime { hour, minute, second },
+                fraction
+            },
+            timezone
+        }
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{
+        ParseOptions,
+        datetime_global_hms_grammar_with
+    };
+
+    #[test]
+    fn datetime_global_hms() {
+        for input in &[
+            &b"2018-08-02T13:42:02Z"[..],
+            b"2018-08-02T13:42:02+02:00",
+            b"2018-08-02T13:42:02-02:30 ",
+            b"2018-08-02T13:42:02.5Z",
+            b"2018-08-02T13:42:02.123456789+01:00",
+            b"0000-01-01T00:00:00Z/P1D"
+        ] {
+            let result = super::datetime_global_hms(input);
+            assert!(result.is_some());
+            assert_eq!(result, datetime_global_hms_grammar_with(ParseOptions::default())(input).ok());
+        }
+
+        for input in &[
+            &b"20180802T134202Z"[..],
+            b"+2018-08-02T13:42:02Z",
+            b"2018-08-02T13:42:02",
+            b"2018-08-02T13:42:02.Z",
+            b"2018-08-02T13:42:02.5e1Z",
+            b"2018-08-02T13:42:02+02",
+            b"2018-08-02T13:42:02+0200",
+            b"2018-08-02T13:42:02,5Z",
+            b"2018-W31-4T13:42:02Z"
+        ] {
+            assert_eq!(super::datetime_global_hms(input), None);
+        }
+    }
+}
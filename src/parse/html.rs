@@ -0,0 +1,354 @@
+//! Microsyntaxes of the WHATWG HTML standard,
+//! as allowed in the `datetime` attribute of the `time` element.
+
+use ::{
+    Valid,
+    date::*,
+    time::*,
+    datetime::*,
+    duration::*
+};
+use super::{
+    buf_to_int,
+    complete::{
+        month,
+        day,
+        week,
+        hour,
+        minute,
+        second
+    }
+};
+use nom::{
+    IResult,
+    branch::alt,
+    bytes::complete::{
+        tag,
+        take_while1,
+        take_while_m_n
+    },
+    character::{
+        is_digit,
+        complete::{
+            char,
+            one_of,
+            space0
+        }
+    },
+    combinator::{
+        all_consuming,
+        map,
+        map_opt,
+        opt,
+        verify
+    },
+    multi::many1,
+    sequence::{
+        pair,
+        preceded,
+        separated_pair,
+        terminated,
+        tuple
+    }
+};
+
+/// What the `datetime` attribute of an HTML `time` element represents.
+#[derive(PartialEq, Clone, Debug)]
+pub enum HtmlDateTime {
+    Year(YDate),
+    Month(YmDate),
+    Date(YmdDate),
+    YearlessDate { month: u8, day: u8 },
+    Week(WDate),
+    Time(LocalTime),
+    LocalDateTime(DateTime<YmdDate, LocalTime>),
+    TimeZoneOffset(i16),
+    GlobalDateTime(DateTime),
+    Duration(Duration)
+}
+
+/// Four or more digits of a year greater than zero.
+fn html_year(i: &[u8]) -> IResult<&[u8], i16> {
+    map_opt(take_while1(is_digit), |digits: &[u8]| if digits.len() < 4 {
+        None
+    } else {
+        ::std::str::from_utf8(digits).ok()?
+            .parse().ok()
+            .filter(|&year| year > 0)
+    })(i)
+}
+
+fn html_month(i: &[u8]) -> IResult<&[u8], YmDate> {
+    verify(
+        map(
+            separated_pair(html_year, char('-'), month),
+            |(year, month)| YmDate { year, month }
+        ),
+        Valid::is_valid
+    )(i)
+}
+
+fn html_date(i: &[u8]) -> IResult<&[u8], YmdDate> {
+    verify(
+        map(
+            separated_pair(html_month, char('-'), day),
+            |(YmDate { year, month }, day)| YmdDate { year, month, day }
+        ),
+        Valid::is_valid
+    )(i)
+}
+
+fn html_yearless_date(i: &[u8]) -> IResult<&[u8], (u8, u8)> {
+    verify(
+        preceded(opt(tag("--")), separated_pair(month, char('-'), day)),
+        // in a leap year so that February 29 is allowed
+        |&(month, day)| YmdDate { year: 4, month, day }.is_valid()
+    )(i)
+}
+
+fn html_week(i: &[u8]) -> IResult<&[u8], WDate> {
+    verify(
+        map(
+            separated_pair(html_year, tag("-W"), week),
+            |(year, week)| WDate { year, week }
+        ),
+        Valid::is_valid
+    )(i)
+}
+
+/// One to three digits after a full stop.
+fn html_fraction(i: &[u8]) -> IResult<&[u8], (u32, usize)> {
+    map(
+        preceded(char('.'), take_while_m_n(1, 3, is_digit)),
+        |digits: &[u8]| (buf_to_int(digits), digits.len())
+    )(i)
+}
+
+fn html_time(i: &[u8]) -> IResult<&[u8], LocalTime> {
+    map(
+        verify(
+            tuple((hour, char(':'), minute, opt(preceded(char(':'), pair(second, opt(html_fraction)))))),
+            |&(hour, _, minute, second)| hour < 24 && minute < 60 && second.is_none_or(|(second, _)| second < 60)
+        ),
+        |(hour, _, minute, second)| {
+            let (second, fraction) = second.unwrap_or((0, None));
+            LocalTime {
+                naive: HmsTime { hour, minute, second },
+                fraction: fraction.map_or(0., |(x, digits)| x as f32 / 10f32.powi(digits as i32))
+            }
+        }
+    )(i)
+}
+
+fn html_local_datetime(i: &[u8]) -> IResult<&[u8], DateTime<YmdDate, LocalTime>> {
+    map(
+        separated_pair(html_date, one_of("T "), html_time),
+        |(date, time)| DateTime { date, time }
+    )(i)
+}
+
+fn html_offset(i: &[u8]) -> IResult<&[u8], i16> {
+    alt((
+        map(char('Z'), |_| 0),
+        map(
+            verify(
+                tuple((one_of("+-"), hour, opt(char(':')), minute)),
+                |&(_, hour, _, minute)| hour < 24 && minute < 60
+            ),
+            |(sign, hour, _, minute)| {
+                let offset = i16::from(hour) * 60 + i16::from(minute);
+                if sign == '-' { -offset } else { offset }
+            }
+        )
+    ))(i)
+}
+
+fn html_global_datetime(i: &[u8]) -> IResult<&[u8], DateTime> {
+    map(
+        pair(html_local_datetime, html_offset),
+        |(DateTime { date, time }, timezone)| DateTime {
+            date,
+            time: GlobalTime { local: time, timezone }
+        }
+    )(i)
+}
+
+fn html_duration_number(i: &[u8]) -> IResult<&[u8], u32> {
+    map(take_while_m_n(1, 9, is_digit), buf_to_int)(i)
+}
+
+fn html_duration_seconds(i: &[u8]) -> IResult<&[u8], (u32, u32)> {
+    map(
+        pair(html_duration_number, opt(html_fraction)),
+        |(seconds, fraction)| (seconds, fraction.map_or(0, |(x, digits)| x * 10u32.pow(9 - digits as u32)))
+    )(i)
+}
+
+fn html_duration_iso(i: &[u8]) -> IResult<&[u8], Duration> {
+    map(
+        preceded(char('P'), verify(
+            pair(
+                opt(terminated(html_duration_number, char('D'))),
+                opt(preceded(char('T'), verify(
+                    tuple((
+                        opt(terminated(html_duration_number,  char('H'))),
+                        opt(terminated(html_duration_number,  char('M'))),
+                        opt(terminated(html_duration_seconds, char('S')))
+                    )),
+                    |time| *time != (None, None, None)
+                )))
+            ),
+            |duration| *duration != (None, None)
+        )),
+        |(days, time)| {
+            let (hours, minutes, seconds) = time.unwrap_or((None, None, None));
+            let (seconds, nanoseconds) = seconds.unwrap_or((0, 0));
+            Duration {
+                days: days.unwrap_or(0),
+                hours: hours.unwrap_or(0),
+                minutes: minutes.unwrap_or(0),
+                seconds,
+                nanoseconds,
+                ..Default::default()
+            }
+        }
+    )(i)
+}
+
+fn html_duration_component(i: &[u8]) -> IResult<&[u8], (char, u32, u32)> {
+    alt((
+        map(
+            pair(html_duration_seconds, one_of("sS")),
+            |((seconds, nanoseconds), _)| ('s', seconds, nanoseconds)
+        ),
+        map(
+            pair(html_duration_number, one_of("wWdDhHmM")),
+            |(x, scale)| (scale.to_ascii_lowercase(), x, 0)
+        )
+    ))(i)
+}
+
+/// Components like `4h 18m 3s` in any order, each at most once.
+fn html_duration_informal(i: &[u8]) -> IResult<&[u8], Duration> {
+    map_opt(
+        preceded(space0, many1(terminated(html_duration_component, space0))),
+        |components| {
+            let mut duration = Duration::default();
+            let mut scales = Vec::with_capacity(components.len());
+            for (scale, x, nanoseconds) in components {
+                if scales.contains(&scale) {
+                    return None;
+                }
+                scales.push(scale);
+
+                match scale {
+                    'w' => duration.weeks   = x,
+                    'd' => duration.days    = x,
+                    'h' => duration.hours   = x,
+                    'm' => duration.minutes = x,
+                    _   => {
+                        duration.seconds     = x;
+                        duration.nanoseconds = nanoseconds;
+                    }
+                }
+            }
+            Some(duration)
+        }
+    )(i)
+}
+
+/// The whole value of a `datetime` attribute,
+/// in any of the microsyntaxes the HTML standard allows there.
+pub fn html_datetime(i: &[u8]) -> IResult<&[u8], HtmlDateTime> {
+    alt((
+        map(all_consuming(html_global_datetime),   HtmlDateTime::GlobalDateTime),
+        map(all_consuming(html_local_datetime),    HtmlDateTime::LocalDateTime),
+        map(all_consuming(html_date),              HtmlDateTime::Date),
+        map(all_consuming(html_month),             HtmlDateTime::Month),
+        map(all_consuming(html_week),              HtmlDateTime::Week),
+        map(all_consuming(html_year),              |year| HtmlDateTime::Year(YDate { year })),
+        map(all_consuming(html_yearless_date),     |(month, day)| HtmlDateTime::YearlessDate { month, day }),
+        map(all_consuming(html_time),              HtmlDateTime::Time),
+        map(all_consuming(html_offset),            HtmlDateTime::TimeZoneOffset),
+        map(all_consuming(html_duration_iso),      HtmlDateTime::Duration),
+        map(all_consuming(html_duration_informal), HtmlDateTime::Duration)
+    ))(i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ok(i: &[u8]) -> HtmlDateTime {
+        super::html_datetime(i).unwrap().1
+    }
+
+    #[test]
+    fn html_datetime() {
+        assert_eq!(ok(b"2011"),     HtmlDateTime::Year(YDate { year: 2011 }));
+        assert_eq!(ok(b"2011-11"),  HtmlDateTime::Month(YmDate { year: 2011, month: 11 }));
+        assert_eq!(ok(b"2011-11-18"), HtmlDateTime::Date(YmdDate { year: 2011, month: 11, day: 18 }));
+        assert_eq!(ok(b"--02-29"),  HtmlDateTime::YearlessDate { month: 2, day: 29 });
+        assert_eq!(ok(b"11-18"),    HtmlDateTime::YearlessDate { month: 11, day: 18 });
+        assert_eq!(ok(b"2011-W47"), HtmlDateTime::Week(WDate { year: 2011, week: 47 }));
+        assert_eq!(ok(b"14:54:39.929"), HtmlDateTime::Time(LocalTime {
+            naive: HmsTime { hour: 14, minute: 54, second: 39 },
+            fraction: 0.929
+        }));
+        assert_eq!(ok(b"2011-11-18 14:54"), HtmlDateTime::LocalDateTime(DateTime {
+            date: YmdDate { year: 2011, month: 11, day: 18 },
+            time: LocalTime {
+                naive: HmsTime { hour: 14, minute: 54, second: 0 },
+                fraction: 0.
+            }
+        }));
+        assert_eq!(ok(b"-0800"), HtmlDateTime::TimeZoneOffset(-8 * 60));
+        assert_eq!(ok(b"2011-11-18T14:54:39+05:30"), HtmlDateTime::GlobalDateTime(DateTime {
+            date: YmdDate { year: 2011, month: 11, day: 18 },
+            time: GlobalTime {
+                local: LocalTime {
+                    naive: HmsTime { hour: 14, minute: 54, second: 39 },
+                    fraction: 0.
+                },
+                timezone: 5 * 60 + 30
+            }
+        }));
+        assert_eq!(ok(b"PT4H18M3S"), HtmlDateTime::Duration(Duration {
+            hours: 4,
+            minutes: 18,
+            seconds: 3,
+            ..Default::default()
+        }));
+        assert_eq!(ok(b" 1w 3s "), HtmlDateTime::Duration(Duration {
+            weeks: 1,
+            seconds: 3,
+            ..Default::default()
+        }));
+        assert_eq!(ok(b"4h 18m 3.25S"), HtmlDateTime::Duration(Duration {
+            hours: 4,
+            minutes: 18,
+            seconds: 3,
+            nanoseconds: 250_000_000,
+            ..Default::default()
+        }));
+
+        for input in &[
+            &b"201"[..],
+            b"0000",
+            b"2011-13",
+            b"2011-02-29",
+            b"--02-30",
+            b"2011-W53",
+            b"24:00",
+            b"14:54:39.9291",
+            b"2011-11-18T14:54+24:00",
+            b"2011-11-18T14:54+05",
+            b"P1Y",
+            b"PT",
+            b"1h 2h",
+            b"2011-11-18 "
+        ] {
+            assert!(super::html_datetime(input).is_err(), "{:?}", input);
+        }
+    }
+}
@@ -0,0 +1,232 @@
+use ::{
+    date::*,
+    time::*,
+    datetime::*,
+    interval::*
+};
+use super::*;
+use nom::{
+    IResult,
+    branch::alt,
+    bytes::complete::{
+        tag,
+        take_till
+    },
+    character::is_space,
+    combinator::{
+        consumed,
+        map,
+        map_opt
+    },
+    sequence::separated_pair
+};
+
+fn interval_separator(i: &[u8]) -> IResult<&[u8], &[u8]> {
+    alt((
+        tag("/"),
+        tag("--")
+    ))(i)
+}
+
+/// Splits a representation at the time designator.
+fn split_time(repr: &[u8]) -> (&[u8], Option<&[u8]>) {
+    match repr.iter().position(|&c| c == b'T' || c == b't') {
+        Some(t) => (&repr[..t], Some(&repr[t + 1..])),
+        None => (repr, None)
+    }
+}
+
+/// Splits a time of day from its offset, which may be empty.
+fn split_offset(time: &[u8]) -> (&[u8], &[u8]) {
+    // 0xE2 starts the non-ASCII minus signs
+    let offset = time.iter().position(|c| b"Zz+-\xE2".contains(c)).unwrap_or(time.len());
+    time.split_at(offset)
+}
+
+/// Whether the date of a concise end consists of the day or month and day
+/// in the layout of the calendar date of the start, as `14` or `03-14` for `2008-02-15`.
+fn concise_date(start: &[u8], end: &[u8]) -> bool {
+    match date(start) {
+        Ok(([], Date::YMD(_))) => (),
+        _ => return false
+    }
+
+    match end.len() {
+        2 | 4 | 5 => end.iter()
+            .zip(&start[start.len() - end.len()..])
+            .all(|(e, s)| if s.is_ascii_digit() { e.is_ascii_digit() } else { e == s }),
+        _ => false
+    }
+}
+
+/// Completes a concise representation of the end (4.4.5)
+/// with the omitted higher order components of the start.
+/// The end consists of a day, month and day, or time of day,
+/// or a day or month and day followed by a time of day,
+/// and has the offset of the start unless it gives its own.
+fn interval_concise_end<P>(start: &[u8], end: &[u8], endpoint: fn(&[u8]) -> IResult<&[u8], P>) -> Option<P> {
+    let (start_date, start_time) = split_time(start);
+    let (end_date, end_time) = match split_time(end) {
+        // without a date, the end is a time if the start has one
+        (time, None) if start_time.is_some() => (&[][..], Some(time)),
+        split => split
+    };
+
+    let mut full = start_date.to_vec();
+    if !end_date.is_empty() {
+        if !concise_date(start_date, end_date) {
+            return None;
+        }
+        let len = full.len();
+        full[len - end_date.len()..].copy_from_slice(end_date);
+    }
+    if let Some(end_time) = end_time {
+        let (_, start_offset) = split_offset(start_time?);
+        let (time, offset) = split_offset(end_time);
+        if time.is_empty() {
+            return None;
+        }
+        full.push(b'T');
+        full.extend_from_slice(time);
+        full.extend_from_slice(if offset.is_empty() { start_offset } else { offset });
+    } else if end_date.is_empty() {
+        return None;
+    }
+
+    match endpoint(&full) {
+        Ok(([], end)) => Some(end),
+        _ => None
+    }
+}
+
+macro_rules! interval {
+    (pub $name:ident, $endpoint_ty:ty, $endpoint:ident) => {
+        pub fn $name(i: &[u8]) -> IResult<&[u8], Interval<$endpoint_ty>> {
+            alt((
+                map(
+                    separated_pair($endpoint, interval_separator, duration_unsigned),
+                    |(start, duration)| Interval::StartDuration(start, duration)
+                ),
+                |i| {
+                    let (i, (start_repr, start)) = consumed($endpoint)(i)?;
+                    let (i, _) = interval_separator(i)?;
+                    let (i, end) = alt((
+                        $endpoint,
+                        map_opt(take_till(is_space), |end| interval_concise_end(start_repr, end, $endpoint))
+                    ))(i)?;
+                    Ok((i, Interval::StartEnd(start, end)))
+                },
+                map(
+                    separated_pair(duration_unsigned, interval_separator, $endpoint),
+                    |(duration, end)| Interval::DurationEnd(duration, end)
+                ),
+                map(duration_unsigned, Interval::Duration)
+            ))(i)
+        }
+    }
+}
+interval!(pub interval_date,          Date,                                date);
+interval!(pub interval_global_hms,    DateTime<Date, GlobalTime<HmsTime>>, datetime_global_hms);
+interval!(pub interval_global_hm,     DateTime<Date, GlobalTime<HmTime>>,  datetime_global_hm);
+interval!(pub interval_global_h,      DateTime<Date, GlobalTime<HTime>>,   datetime_global_h);
+interval!(pub interval_local_hms,     DateTime<Date, LocalTime<HmsTime>>,  datetime_local_hms);
+interval!(pub interval_local_hm,      DateTime<Date, LocalTime<HmTime>>,   datetime_local_hm);
+interval!(pub interval_local_h,       DateTime<Date, LocalTime<HTime>>,    datetime_local_h);
+interval!(pub interval_any_hms,       DateTime<Date, AnyTime<HmsTime>>,    datetime_any_hms);
+interval!(pub interval_any_hm,        DateTime<Date, AnyTime<HmTime>>,     datetime_any_hm);
+interval!(pub interval_any_h,         DateTime<Date, AnyTime<HTime>>,      datetime_any_h);
+interval!(pub interval_global_approx, DateTime<Date, ApproxGlobalTime>,    datetime_global_approx);
+interval!(pub interval_local_approx,  DateTime<Date, ApproxLocalTime>,     datetime_local_approx);
+interval!(pub interval_any_approx,    DateTime<Date, ApproxAnyTime>,       datetime_any_approx);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::duration::*;
+
+    #[test]
+    fn interval() {
+        let start = DateTime {
+            date: Date::YMD(YmdDate {
+                year: 2018,
+                month: 8,
+                day: 2
+            }),
+            time: GlobalTime {
+                local: LocalTime {
+                    naive: HmsTime {
+                        hour: 13,
+                        minute: 42,
+                        second: 2
+                    },
+                    fraction: 0.
+                },
+                timezone: 0
+            }
+        };
+        let duration = Duration {
+            hours: 1,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            interval_global_hms(b"2018-08-02T13:42:02Z/2018-08-02T13:42:02Z"),
+            Ok((&[][..], Interval::StartEnd(start, start)))
+        );
+        assert_eq!(
+            interval_global_hms(b"20180802T134202Z--PT1H"),
+            Ok((&[][..], Interval::StartDuration(start, duration.clone())))
+        );
+        assert_eq!(
+            interval_global_hms(b"PT1H/2018-08-02T13:42:02Z"),
+            Ok((&[][..], Interval::DurationEnd(duration.clone(), start)))
+        );
+        assert_eq!(
+            interval_global_hms(b"PT1H"),
+            Ok((&[][..], Interval::Duration(duration)))
+        );
+    }
+
+    #[test]
+    fn interval_concise_end() {
+        let date = |month, day| Date::YMD(YmdDate {
+            year: 2008,
+            month,
+            day
+        });
+
+        assert_eq!(
+            interval_date(b"2008-02-15/03-14"),
+            Ok((&[][..], Interval::StartEnd(date(2, 15), date(3, 14))))
+        );
+        assert_eq!(
+            interval_date(b"2008-02-15/14 "),
+            Ok((&b" "[..], Interval::StartEnd(date(2, 15), date(2, 14))))
+        );
+        assert_eq!(
+            interval_date(b"20080215/0314"),
+            Ok((&[][..], Interval::StartEnd(date(2, 15), date(3, 14))))
+        );
+        assert!(interval_date(b"2008-02-15/x").is_err());
+        assert!(interval_date(b"2008-02-15/6").is_err());
+        assert!(interval_date(b"2008-02-15/8-03-14").is_err());
+        assert!(interval_date(b"2008-02-15/0314").is_err());
+        assert!(interval_date(b"2008-W07-5/14").is_err());
+
+        assert_eq!(
+            interval_global_hm(b"2007-12-14T13:30+01:00/15:30").unwrap(),
+            interval_global_hm(b"2007-12-14T13:30+01:00/2007-12-14T15:30+01:00").unwrap()
+        );
+        assert_eq!(
+            interval_global_hm(b"2007-12-14T13:30+01:00/15:30Z").unwrap(),
+            interval_global_hm(b"2007-12-14T13:30+01:00/2007-12-14T15:30Z").unwrap()
+        );
+        assert_eq!(
+            interval_global_hm(b"2007-11-13T09:00Z/15T17:00").unwrap(),
+            interval_global_hm(b"2007-11-13T09:00Z/2007-11-15T17:00Z").unwrap()
+        );
+        assert!(interval_global_hms(b"2007-12-14T13:30:00Z/5:30:00Z").is_err());
+        assert!(interval_global_hm(b"2007-12-14T13:30Z/15").is_err());
+        assert!(interval_date(b"2008-02-15/14T15:30").is_err());
+    }
+}
@@ -0,0 +1,195 @@
+use ::format::*;
+use super::*;
+use super::complete::sign;
+#[cfg(feature = "names")]
+use ::names::*;
+
+fn digits(input: &[u8], n: usize) -> Result<(&[u8], &[u8]), ()> {
+    if input.len() >= n && input[..n].iter().all(u8::is_ascii_digit) {
+        Ok((&input[n..], &input[..n]))
+    } else {
+        Err(())
+    }
+}
+
+fn int<T>(input: &[u8], n: usize) -> Result<(&[u8], T), ()>
+where T: AddAssign + MulAssign + From<u8> {
+    digits(input, n).map(|(rest, buf)| (rest, buf_to_int(buf)))
+}
+
+fn opt_sign(input: &[u8]) -> (&[u8], Option<i8>) {
+    match sign(input) {
+        Ok((rest, sign)) => (rest, Some(sign)),
+        Err(_) => (input, None)
+    }
+}
+
+fn year(input: &[u8]) -> Result<(&[u8], i64), ()> {
+    let (input, sign) = opt_sign(input);
+    let len = match sign {
+        // only signed years may be expanded
+        Some(_) => input.iter().take_while(|c| c.is_ascii_digit()).count().max(4),
+        None => 4
+    };
+    let (input, year) = digits(input, len)?;
+    let year = ::std::str::from_utf8(year).unwrap()
+        .parse::<i64>().or(Err(()))?;
+    Ok((input, i64::from(sign.unwrap_or(1)) * year))
+}
+
+fn fraction(input: &[u8], n: Option<usize>) -> Result<(&[u8], f32), ()> {
+    match input.first() {
+        Some(b'.') | Some(b',') => {}
+        _ => return Err(())
+    }
+    let len = n.unwrap_or_else(|| input[1..].iter().take_while(|c| c.is_ascii_digit()).count());
+    if len == 0 {
+        return Err(());
+    }
+    let (rest, buf) = digits(&input[1..], len)?;
+    let fraction = format!("0.{}", ::std::str::from_utf8(buf).unwrap())
+        .parse().or(Err(()))?;
+    Ok((rest, fraction))
+}
+
+fn offset(input: &[u8], format: OffsetFormat) -> Result<(&[u8], i16), ()> {
+    if format == OffsetFormat::Utc && input.first() == Some(&b'Z') {
+        return Ok((&input[1..], 0));
+    }
+
+    let (input, sign) = sign(input).or(Err(()))?;
+    let (input, hour) = int::<i16>(input, 2)?;
+    let input = match (format, input.first()) {
+        (OffsetFormat::Basic, _) => input,
+        (_, Some(b':')) => &input[1..],
+        _ => return Err(())
+    };
+    let (input, minute) = int::<i16>(input, 2)?;
+    Ok((input, i16::from(sign) * (hour * 60 + minute)))
+}
+
+/// One of the given values by a name of it, ignoring case.
+#[cfg(feature = "names")]
+fn named<'a, T: Copy>(input: &'a [u8], all: &[T], name: impl Fn(T) -> &'static str) -> Result<(&'a [u8], T), ()> {
+    all.iter()
+        .find_map(|&x| {
+            let name = name(x).as_bytes();
+            input.get(..name.len())
+                .filter(|prefix| prefix.eq_ignore_ascii_case(name))
+                .map(|_| (&input[name.len()..], x))
+        })
+        .ok_or(())
+}
+
+/// Matches the entire input against exactly the given items.
+pub fn fields<'a, I, B>(mut input: &[u8], items: I) -> Result<Fields, ()> where
+    I: IntoIterator<Item = B>,
+    B: ::std::borrow::Borrow<Item<'a>>
+{
+    let mut fields = Fields::default();
+    for item in items {
+        input = match *item.borrow() {
+            Item::Literal(s) => if input.starts_with(s.as_bytes()) {
+                &input[s.len()..]
+            } else {
+                return Err(());
+            },
+            Item::Year     => { let (i, x) = year(input)?;     fields.year      = Some(x); i }
+            Item::WeekYear => { let (i, x) = year(input)?;     fields.week_year = Some(x); i }
+            Item::Century  => {
+                let (i, sign) = opt_sign(input);
+                let (i, x) = int::<u8>(i, 2)?;
+                fields.century = Some(sign.unwrap_or(1) * x as i8);
+                i
+            }
+            Item::Month    => { let (i, x) = int(input, 2)?;   fields.month     = Some(x); i }
+            Item::Day      => { let (i, x) = int(input, 2)?;   fields.day       = Some(x); i }
+            Item::Week     => { let (i, x) = int(input, 2)?;   fields.week      = Some(x); i }
+            Item::Weekday  => { let (i, x) = int(input, 1)?;   fields.weekday   = Some(x); i }
+            #[cfg(feature = "names")]
+            Item::WeekdayAbbreviation => { let (i, x) = named(input, &Weekday::ALL, Weekday::abbreviation)?; fields.weekday = Some(x.into()); i }
+            #[cfg(feature = "names")]
+            Item::WeekdayName         => { let (i, x) = named(input, &Weekday::ALL, Weekday::name)?;         fields.weekday = Some(x.into()); i }
+            #[cfg(feature = "names")]
+            Item::MonthAbbreviation   => { let (i, x) = named(input, &Month::ALL,   Month::abbreviation)?;   fields.month   = Some(x.into()); i }
+            #[cfg(feature = "names")]
+            Item::MonthName           => { let (i, x) = named(input, &Month::ALL,   Month::name)?;           fields.month   = Some(x.into()); i }
+            Item::Ordinal  => { let (i, x) = int(input, 3)?;   fields.ordinal   = Some(x); i }
+            Item::Hour     => { let (i, x) = int(input, 2)?;   fields.hour      = Some(x); i }
+            Item::Minute   => { let (i, x) = int(input, 2)?;   fields.minute    = Some(x); i }
+            Item::Second   => { let (i, x) = int(input, 2)?;   fields.second    = Some(x); i }
+            Item::Fraction(n) => match fraction(input, n) {
+                Ok((i, x)) => { fields.fraction = Some(x); i }
+                // a fraction of unspecified length may be omitted
+                Err(_) if n.is_none() => input,
+                Err(e) => return Err(e)
+            },
+            Item::Offset(format) => { let (i, x) = offset(input, format)?; fields.timezone = Some(x); i }
+            Item::Error => return Err(())
+        };
+    }
+
+    if input.is_empty() {
+        Ok(fields)
+    } else {
+        Err(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fields() {
+        assert_eq!(
+            super::fields(b"20180802T134202Z", StrftimeItems::new("%Y%m%dT%H%M%S%Z")),
+            Ok(Fields {
+                year: Some(2018),
+                month: Some(8),
+                day: Some(2),
+                hour: Some(13),
+                minute: Some(42),
+                second: Some(2),
+                timezone: Some(0),
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            super::fields(b"+12345-001T12,5-02:30", StrftimeItems::new("%Y-%jT%H%.f%:z")),
+            Ok(Fields {
+                year: Some(12345),
+                ordinal: Some(1),
+                hour: Some(12),
+                fraction: Some(0.5),
+                timezone: Some(-150),
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            super::fields(b"-05", [Item::Century]),
+            Ok(Fields {
+                century: Some(-5),
+                ..Default::default()
+            })
+        );
+
+        #[cfg(feature = "names")]
+        assert_eq!(
+            super::fields(b"THU 02 aug 2018, Thursday August", StrftimeItems::new("%a %d %b %Y, %A %B")),
+            Ok(Fields {
+                year: Some(2018),
+                month: Some(8),
+                day: Some(2),
+                weekday: Some(4),
+                ..Default::default()
+            })
+        );
+
+        assert_eq!(super::fields(b"2018-08-02 ", StrftimeItems::new("%Y-%m-%d")), Err(()));
+        assert_eq!(super::fields(b"2018-08",     StrftimeItems::new("%Y-%m-%d")), Err(()));
+        assert_eq!(super::fields(b"20180802",    StrftimeItems::new("%Y-%m-%d")), Err(()));
+        assert_eq!(super::fields(b"12.5",        StrftimeItems::new("%H%.3f")),   Err(()));
+        assert_eq!(super::fields(b"12+0100",     StrftimeItems::new("%H%:z")),    Err(()));
+    }
+}
@@ -0,0 +1,157 @@
+use ::{
+    Valid,
+    date::*,
+    datetime::*,
+    std::ops::Range
+};
+use time::AnyTime;
+use Spanned;
+use super::{
+    datetime_any_hms,
+    datetime_global_hms,
+    spanned,
+    ParseError
+};
+
+/// Rejects invalid dates, which the grammar accepts,
+/// rather than converting them to calendar dates.
+fn datetime_one(i: &[u8]) -> Result<DateTime, ParseError> {
+    let DateTime { date, time } = ParseError::from_result(i, datetime_global_hms(i))?;
+    if !date.is_valid() {
+        let end = i.iter().position(|&c| c == b'T').unwrap_or(i.len());
+        return Err(ParseError::invalid_value(i, 0..end));
+    }
+    Ok(DateTime {
+        date: YmdDate::from(date),
+        time
+    })
+}
+
+/// Parses each input as a whole into a date and time,
+/// as for columns of CSV or Arrow data.
+pub fn datetime_many<'a, I>(inputs: I) -> Vec<Result<DateTime, ParseError>>
+where I: Iterator<Item = &'a [u8]> {
+    inputs.map(datetime_one).collect()
+}
+
+/// Like [`datetime_many`] but parses in parallel.
+#[cfg(feature = "rayon")]
+pub fn par_datetime_many<I>(inputs: &[I]) -> Vec<Result<DateTime, ParseError>>
+where I: AsRef<[u8]> + Sync {
+    use rayon::prelude::*;
+
+    inputs.par_iter()
+        .map(|x| datetime_one(x.as_ref()))
+        .collect()
+}
+
+/// Finds the dates and times in a buffer, such as a log file,
+/// along with their positions.
+///
+/// Candidates must start at a word boundary.
+/// Those that do not parse are skipped.
+pub fn datetimes_spanned(buffer: &[u8]) -> impl Iterator<Item = Spanned<DateTime<Date, AnyTime>>> + '_ {
+    let mut at = 0;
+    ::std::iter::from_fn(move || {
+        while at < buffer.len() {
+            let boundary = at == 0 || !buffer[at - 1].is_ascii_alphanumeric();
+            if boundary && buffer[at].is_ascii_digit() {
+                if let Ok((_, x)) = spanned(buffer, datetime_any_hms)(&buffer[at..]) {
+                    at = x.source.end;
+                    return Some(x);
+                }
+            }
+            at += 1;
+        }
+        None
+    })
+}
+
+/// Finds the valid dates and times with offsets in text, such as a log file,
+/// along with their byte ranges.
+///
+/// Candidates must start at a word boundary with four digits
+/// followed by a hyphen or another digit.
+pub fn find_iso_datetimes(text: &str) -> impl Iterator<Item = (Range<usize>, DateTime)> + '_ {
+    let buffer = text.as_bytes();
+    let mut at = 0;
+    ::std::iter::from_fn(move || {
+        while at < buffer.len() {
+            if is_candidate(buffer, at) {
+                match spanned(buffer, datetime_global_hms)(&buffer[at..]) {
+                    Ok((_, x)) if x.date.is_valid() => {
+                        at = x.source.end;
+                        let DateTime { date, time } = x.value;
+                        return Some((x.source, DateTime { date: date.into(), time }));
+                    }
+                    _ => {}
+                }
+            }
+            at += 1;
+        }
+        None
+    })
+}
+
+/// Cheap check for the shape of a year at a word boundary
+/// before running the parser.
+fn is_candidate(buffer: &[u8], at: usize) -> bool {
+    (at == 0 || !buffer[at - 1].is_ascii_alphanumeric()) &&
+    buffer.len() - at >= 5 &&
+    buffer[at..at + 4].iter().all(u8::is_ascii_digit) &&
+    (buffer[at + 4] == b'-' || buffer[at + 4].is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::ParseErrorKind;
+
+    #[test]
+    fn datetime_many() {
+        let inputs = [
+            &b"2018-08-02T13:42:02Z"[..],
+            b"2018-W31-4T13:42:02+02:00",
+            b"2018-08-02T13:42:02Z ",
+            b"",
+            b"2018-400T00:00:00Z",
+            b"2018-W60-1T00:00:00Z"
+        ];
+        let result = super::datetime_many(inputs.iter().cloned());
+        assert_eq!(result.len(), 6);
+        assert_eq!(result[4].as_ref().map_err(|e| (e.kind, e.span())), Err((ParseErrorKind::Invalid, 0..8)));
+        assert_eq!(result[5].as_ref().map_err(|e| (e.kind, e.span())), Err((ParseErrorKind::Invalid, 0..10)));
+        assert_eq!(result[0].as_ref().map(|x| x.date), Ok(YmdDate { year: 2018, month: 8, day: 2 }));
+        assert_eq!(result[1].as_ref().map(|x| x.time.timezone), Ok(120));
+        assert_eq!(result[2].as_ref().map_err(|e| e.kind), Err(ParseErrorKind::Trailing));
+        assert!(result[3].is_err());
+
+        #[cfg(feature = "rayon")]
+        assert_eq!(super::par_datetime_many(&inputs), result);
+    }
+
+    #[test]
+    fn datetimes_spanned() {
+        let buffer = b"2018-08-02T13:42:02Z start\nid=12018-08-02T13:42:03Z\n[2018-W31-4T13:42:04+02:00] stop 2018-08-02";
+        let found: Vec<_> = super::datetimes_spanned(buffer)
+            .map(|x| (x.source.clone(), x.time))
+            .collect();
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].0, 0..20);
+        assert_eq!(found[1].0, 53..78);
+        assert_eq!(&buffer[found[1].0.clone()], b"2018-W31-4T13:42:04+02:00");
+    }
+
+    #[test]
+    fn find_iso_datetimes() {
+        let text = "2018-08-02T13:42:02Z start, retry at 20180802T134203+0200 \u{2014} id=12018-08-02T13:42:03Z\n\
+            local 2018-08-02T13:42:04, invalid 2018-02-30T13:42:05Z, week 2018-W31-4T13:42:06\u{2212}01:00.";
+        let found: Vec<_> = super::find_iso_datetimes(text).collect();
+        assert_eq!(found.len(), 3);
+        assert_eq!(&text[found[0].0.clone()], "2018-08-02T13:42:02Z");
+        assert_eq!(&text[found[1].0.clone()], "20180802T134203+0200");
+        assert_eq!(found[1].1.time.timezone, 120);
+        assert_eq!(&text[found[2].0.clone()], "2018-W31-4T13:42:06\u{2212}01:00");
+        assert_eq!(found[2].1.date, YmdDate { year: 2018, month: 8, day: 2 });
+    }
+}
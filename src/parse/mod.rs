@@ -1,21 +1,225 @@
+//! Parsers for all representations, built on [nom](https://docs.rs/nom).
+//!
+//! The grammar productions that the representations are composed of
+//! are available on their own in two flavors:
+//! those in [`streaming`] ask for more input when they reach its end,
+//! which suits incrementally filled buffers,
+//! while those in [`complete`] treat the end of input as final.
+//!
+//! Parsers suffixed `_with` take [`ParseOptions`]
+//! to accept common deviations from the standard,
+//! or those of a well-known [`Profile`] converted into them.
+
+macro_rules! productions {
+    ($flavor:ident) => {
+        use {
+            std::ops::{
+                AddAssign,
+                MulAssign
+            },
+            nom::{
+                IResult,
+                branch::alt,
+                bytes::$flavor::{
+                    tag,
+                    take_while_m_n
+                },
+                character::{
+                    is_digit,
+                    $flavor::{
+                        char
+                    }
+                },
+                combinator::{
+                    map,
+                    map_res,
+                    opt,
+                    peek
+                },
+                number::$flavor::recognize_float,
+                sequence::{
+                    pair,
+                    preceded,
+                    tuple
+                }
+            },
+            super::buf_to_int
+        };
+
+        fn digits<'a, T>(n: usize) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], T>
+        where T: AddAssign + MulAssign + From<u8> {
+            map(take_while_m_n(n, n, is_digit), buf_to_int)
+        }
+
+        /// Plus or minus sign,
+        /// also accepting the minus sign (U+2212) and hyphen (U+2010).
+        pub fn sign(input: &[u8]) -> IResult<&[u8], i8> {
+            alt((
+                map(alt((tag("-"), tag("\u{2212}"), tag("\u{2010}"))), |_| -1),
+                map(char('+'), |_| 1)
+            ))(input)
+        }
+
+        /// Optionally signed century of two digits (4.1.2.3b).
+        pub fn century(input: &[u8]) -> IResult<&[u8], i8> {
+            map(
+                pair(opt(sign), digits::<u8>(2)),
+                |(sign, century)| sign.unwrap_or(1) * century as i8
+            )(input)
+        }
+
+        /// Optionally signed year of four digits (4.1.2.2).
+        pub fn year(input: &[u8]) -> IResult<&[u8], i16> {
+            map(
+                pair(opt(sign), digits::<u16>(4)),
+                |(sign, year)| sign.unwrap_or(1) as i16 * year as i16
+            )(input)
+        }
+
+        /// Calendar month of two digits (4.1.2.2).
+        pub fn month(input: &[u8]) -> IResult<&[u8], u8> {
+            digits(2)(input)
+        }
+
+        /// Day of the month of two digits (4.1.2.2).
+        pub fn day(input: &[u8]) -> IResult<&[u8], u8> {
+            digits(2)(input)
+        }
+
+        /// Calendar week of two digits, without the designator (4.1.4.2).
+        pub fn week(input: &[u8]) -> IResult<&[u8], u8> {
+            digits(2)(input)
+        }
+
+        /// Day of the week of one digit (4.1.4.2).
+        pub fn weekday(input: &[u8]) -> IResult<&[u8], u8> {
+            digits(1)(input)
+        }
+
+        /// Day of the year of three digits (4.1.3.2).
+        pub fn ordinal(input: &[u8]) -> IResult<&[u8], u16> {
+            digits(3)(input)
+        }
+
+        /// Hour of two digits (4.2.2.2).
+        pub fn hour(input: &[u8]) -> IResult<&[u8], u8> {
+            digits(2)(input)
+        }
+
+        /// Minute of two digits (4.2.2.2).
+        pub fn minute(input: &[u8]) -> IResult<&[u8], u8> {
+            digits(2)(input)
+        }
+
+        /// Second of two digits (4.2.2.2).
+        pub fn second(input: &[u8]) -> IResult<&[u8], u8> {
+            digits(2)(input)
+        }
+
+        /// Decimal fraction including its separator (4.2.2.4).
+        pub fn fraction(input: &[u8]) -> IResult<&[u8], f32> {
+            preceded(
+                peek(char('.')),
+                map_res(recognize_float, |x: &[u8]| ::std::str::from_utf8(x)
+                    .ok()
+                    .and_then(|x| x.parse().ok())
+                    .ok_or(()))
+            )(input)
+        }
+
+        /// Difference from UTC in minutes,
+        /// either `Z` or the basic or extended format
+        /// with an optional minute (4.2.5.1).
+        pub fn offset(input: &[u8]) -> IResult<&[u8], i16> {
+            alt((
+                map(char('Z'), |_| 0),
+                map(
+                    tuple((sign, hour, opt(preceded(opt(char(':')), minute)))),
+                    |(sign, hour, minute)| sign as i16 * (hour as i16 * 60 + minute.unwrap_or(0) as i16)
+                )
+            ))(input)
+        }
+    }
+}
+
+pub mod streaming;
+pub mod complete;
+pub mod rfc5545;
+pub mod xsd;
+pub mod asn1;
+
 mod date;
 mod time;
 mod datetime;
+mod duration;
+mod interval;
+mod items;
+mod fast;
+mod error;
+mod many;
+mod scanner;
+mod options;
+mod html;
+mod best;
+mod check;
 
 pub use self::{
     date::*,
     time::*,
-    datetime::*
+    datetime::*,
+    duration::*,
+    interval::*,
+    items::*,
+    error::*,
+    many::*,
+    scanner::*,
+    options::*,
+    html::*,
+    best::*,
+    check::*
 };
 
+use std::ops::{
+    AddAssign,
+    MulAssign
+};
+use nom::{
+    IResult,
+    Offset,
+    combinator::{
+        consumed,
+        map_res
+    }
+};
 use {
-    std::ops::{
-        AddAssign,
-        MulAssign
-    },
-    nom
+    Preserving,
+    Spanned
 };
 
+/// Keeps the text that the parser consumed along with its value.
+pub fn preserving<'a, T, P>(parser: P) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], Preserving<T>>
+where P: FnMut(&'a [u8]) -> IResult<&'a [u8], T> {
+    map_res(consumed(parser), |(repr, value)| ::std::str::from_utf8(repr)
+        .map(|repr| Preserving::with_repr(value, repr.to_string())))
+}
+
+/// Keeps the position of the text that the parser consumed along with its value,
+/// counted in bytes from the start of the buffer that the input is a part of.
+///
+/// The input must be a subslice of the buffer.
+pub fn spanned<'a, T, P>(buffer: &'a [u8], parser: P) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], Spanned<T>>
+where P: FnMut(&'a [u8]) -> IResult<&'a [u8], T> {
+    let mut parser = consumed(parser);
+    move |i| {
+        let (rest, (repr, value)) = parser(i)?;
+        let start = buffer.offset(repr);
+        Ok((rest, Spanned {
+            value,
+            source: start..start + repr.len()
+        }))
+    }
+}
+
 fn buf_to_int<T>(buf: &[u8]) -> T
 where T: AddAssign + MulAssign + From<u8> {
     let mut sum = T::from(0);
@@ -26,17 +230,6 @@ where T: AddAssign + MulAssign + From<u8> {
     sum
 }
 
-named!(sign <i8>, alt!(
-    one_of!("-\u{2212}\u{2010}") => { |_| -1 } |
-    char!('+')                   => { |_|  1 }
-));
-
-named!(frac32 <f32>, do_parse!(
-    peek!(char!('.')) >>
-    fraction: flat_map!(nom::number::complete::recognize_float, parse_to!(f32)) >>
-    (fraction)
-));
-
 #[cfg(test)]
 mod tests {
     use {
@@ -45,17 +238,35 @@ mod tests {
             Err,
             error::{
                 Error,
-                ErrorKind::Alt
+                ErrorKind::Char
             },
             Needed::Size
         }
     };
 
+    #[test]
+    fn preserving() {
+        let (rest, x) = super::preserving(super::datetime_global_hms)(b"2018-08-02T13:42:02.50+00:00 ").unwrap();
+        assert_eq!(rest, b" ");
+        assert_eq!(x.as_str(), "2018-08-02T13:42:02.50+00:00");
+    }
+
+    #[test]
+    fn spanned() {
+        let buffer = &b"at 2018-08-02T13:42:02Z:"[..];
+        let (rest, x) = super::spanned(buffer, super::datetime_global_hms)(&buffer[3..]).unwrap();
+        assert_eq!(rest, b":");
+        assert_eq!(x.source, 3..23);
+        assert_eq!(x.source_in(buffer), b"2018-08-02T13:42:02Z");
+    }
+
     #[test]
     fn sign() {
-        assert_eq!(super::sign(b"-"), Ok((&[][..], -1)));
-        assert_eq!(super::sign(b"+"), Ok((&[][..],  1)));
-        assert_eq!(super::sign(b"" ), Err(Err::Incomplete(Size(NonZeroUsize::new(1).unwrap()))));
-        assert_eq!(super::sign(b" "), Err(Err::Error(Error { input: &b" "[..], code: Alt })));
+        assert_eq!(super::streaming::sign(b"-"), Ok((&[][..], -1)));
+        assert_eq!(super::streaming::sign(b"+"), Ok((&[][..],  1)));
+        assert_eq!(super::complete::sign("\u{2212}1".as_bytes()), Ok((&b"1"[..], -1)));
+        assert_eq!(super::complete::sign("\u{2010}".as_bytes()),  Ok((&[][..],  -1)));
+        assert_eq!(super::streaming::sign(b"" ), Err(Err::Incomplete(Size(NonZeroUsize::new(1).unwrap()))));
+        assert_eq!(super::streaming::sign(b" "), Err(Err::Error(Error { input: &b" "[..], code: Char })));
     }
 }
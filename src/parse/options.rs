@@ -0,0 +1,161 @@
+/// Deviations from the standard that parsers may accept.
+///
+/// The default is strict ISO 8601.
+#[derive(Eq, PartialEq, Clone, Copy, Default, Debug)]
+pub struct ParseOptions {
+    /// Accept a space instead of the time designator `T`
+    /// between date and time, as in SQL and Postgres timestamps.
+    pub allow_space_separator: bool,
+    /// Accept the designators `t` and `z` in lowercase,
+    /// as RFC 3339 permits.
+    pub allow_lowercase: bool,
+    /// Accept times of day that mix basic and extended format like `1213:14`.
+    pub allow_mixed_time_format: bool,
+    /// How to read a date of seven digits in basic format.
+    pub seven_digit_date: SevenDigitDate,
+    /// Whether a time of day on its own starts with the time designator `T`.
+    ///
+    /// Parsers of datetimes ignore this, as their separator is the designator.
+    pub time_designator: TimeDesignator,
+    /// Keep at most this many digits of the decimal fraction of a time of day,
+    /// dropping the rest, so `Some(3)` keeps milliseconds and `Some(0)` drops fractions.
+    ///
+    /// This deviates from the value written, not from the grammar.
+    pub max_fraction_digits: Option<u8>,
+    /// Accept offsets from UTC only in extended format with minutes like `+05:30`,
+    /// rejecting `+05` and `+0530`, as RFC 3339 requires.
+    pub require_extended_offset: bool
+}
+
+/// Readings of a date of seven digits in basic format like `1985102`.
+#[derive(Eq, PartialEq, Clone, Copy, Default, Debug)]
+pub enum SevenDigitDate {
+    /// Year and day of the year, `1985-102`, as ISO 8601 defines (4.1.3.2).
+    #[default]
+    Ordinal,
+    /// Year, month and a day of one digit, `1985-10-02`,
+    /// as written by tools that do not pad the day.
+    YearMonthDay
+}
+
+/// Whether a time of day on its own starts with the time designator `T`,
+/// as in `T14:30` rather than `14:30`.
+#[derive(Eq, PartialEq, Clone, Copy, Default, Debug)]
+pub enum TimeDesignator {
+    /// With or without, as ISO 8601 allows it to be omitted
+    /// where a time cannot be taken for a date (4.2.2.5).
+    #[default]
+    Optional,
+    /// Only with, so that a time cannot be taken for a date
+    /// where it is embedded in another grammar.
+    Required,
+    /// Only without, as in RFC 3339 partial times.
+    Forbidden
+}
+
+/// Well-known sets of deviations to accept.
+#[derive(Eq, PartialEq, Clone, Copy, Default, Debug)]
+pub enum Profile {
+    /// No deviations from ISO 8601-1:2019
+    #[default]
+    Iso8601_2019Strict,
+    /// Lowercase designators and a space between date and time (RFC 3339 5.6),
+    /// but only offsets in extended format like `+05:30`
+    Rfc3339,
+    /// A space between date and time, as the HTML standard allows
+    /// for global and local dates and times
+    Html,
+    /// No deviations, as iCalendar only allows a subset of ISO 8601,
+    /// see [`rfc5545`](super::rfc5545) for parsers restricted to it
+    Rfc5545,
+    /// All deviations that do not change the reading of valid ISO 8601
+    Lenient
+}
+
+impl From<Profile> for ParseOptions {
+    fn from(profile: Profile) -> Self {
+        let strict = Self::default();
+        match profile {
+            Profile::Iso8601_2019Strict |
+            Profile::Rfc5545 => strict,
+            Profile::Rfc3339 => strict
+                .allow_lowercase(true)
+                .allow_space_separator(true)
+                .require_extended_offset(true),
+            Profile::Html => strict
+                .allow_space_separator(true),
+            Profile::Lenient => Self::from(Profile::Rfc3339)
+                .allow_mixed_time_format(true)
+                .require_extended_offset(false)
+        }
+    }
+}
+
+impl ParseOptions {
+    pub fn allow_space_separator(mut self, allow: bool) -> Self {
+        self.allow_space_separator = allow;
+        self
+    }
+
+    pub fn allow_lowercase(mut self, allow: bool) -> Self {
+        self.allow_lowercase = allow;
+        self
+    }
+
+    pub fn allow_mixed_time_format(mut self, allow: bool) -> Self {
+        self.allow_mixed_time_format = allow;
+        self
+    }
+
+    pub fn seven_digit_date(mut self, reading: SevenDigitDate) -> Self {
+        self.seven_digit_date = reading;
+        self
+    }
+
+    pub fn time_designator(mut self, designator: TimeDesignator) -> Self {
+        self.time_designator = designator;
+        self
+    }
+
+    pub fn max_fraction_digits(mut self, digits: Option<u8>) -> Self {
+        self.max_fraction_digits = digits;
+        self
+    }
+
+    pub fn require_extended_offset(mut self, require: bool) -> Self {
+        self.require_extended_offset = require;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn profile() {
+        assert_eq!(ParseOptions::from(Profile::default()), ParseOptions::default());
+        assert_eq!(ParseOptions::from(Profile::Rfc5545), ParseOptions::default());
+        assert_eq!(
+            ParseOptions::from(Profile::Lenient),
+            ParseOptions {
+                allow_space_separator: true,
+                allow_lowercase: true,
+                allow_mixed_time_format: true,
+                seven_digit_date: SevenDigitDate::Ordinal,
+                time_designator: TimeDesignator::Optional,
+                max_fraction_digits: None,
+                require_extended_offset: false
+            }
+        );
+        assert_eq!(
+            ParseOptions::from(Profile::Rfc3339),
+            ParseOptions {
+                allow_space_separator: true,
+                allow_lowercase: true,
+                require_extended_offset: true,
+                ..ParseOptions::default()
+            }
+        );
+    }
+}
@@ -0,0 +1,193 @@
+//! Restricted forms used by iCalendar (RFC 5545 3.3),
+//! which only allows the basic format.
+
+use ::{
+    date::*,
+    time::*,
+    datetime::*,
+    duration::*
+};
+use super::{
+    buf_to_int,
+    complete::{
+        month,
+        day,
+        hour,
+        minute,
+        second
+    }
+};
+use nom::{
+    IResult,
+    branch::alt,
+    bytes::complete::take_while_m_n,
+    character::{
+        is_digit,
+        complete::{
+            char,
+            one_of
+        }
+    },
+    combinator::{
+        map,
+        opt
+    },
+    sequence::{
+        pair,
+        preceded,
+        separated_pair,
+        terminated,
+        tuple
+    }
+};
+
+fn year(i: &[u8]) -> IResult<&[u8], i16> {
+    map(take_while_m_n(4, 4, is_digit), buf_to_int)(i)
+}
+
+/// `DATE` value, like `19970714`.
+pub fn date(i: &[u8]) -> IResult<&[u8], YmdDate> {
+    map(
+        tuple((year, month, day)),
+        |(year, month, day)| YmdDate { year, month, day }
+    )(i)
+}
+
+/// `TIME` value, like `133000` for floating or `133000Z` for UTC time.
+pub fn time(i: &[u8]) -> IResult<&[u8], AnyTime> {
+    map(
+        pair(tuple((hour, minute, second)), opt(char('Z'))),
+        |((hour, minute, second), utc)| {
+            let local = LocalTime {
+                naive: HmsTime { hour, minute, second },
+                fraction: 0.
+            };
+            match utc {
+                Some(_) => AnyTime::Global(GlobalTime { local, timezone: 0 }),
+                None => AnyTime::Local(local)
+            }
+        }
+    )(i)
+}
+
+/// `DATE-TIME` value, like `19970714T133000Z`.
+pub fn datetime(i: &[u8]) -> IResult<&[u8], DateTime<YmdDate, AnyTime>> {
+    map(
+        separated_pair(date, char('T'), time),
+        |(date, time)| DateTime { date, time }
+    )(i)
+}
+
+/// `UTC-OFFSET` value in minutes, like `-0500`.
+/// Seconds are allowed if zero.
+pub fn utc_offset(i: &[u8]) -> IResult<&[u8], i16> {
+    map(
+        tuple((one_of("+-"), hour, minute, opt(preceded(char('0'), char('0'))))),
+        |(sign, hour, minute, _)| {
+            let offset = i16::from(hour) * 60 + i16::from(minute);
+            if sign == '-' { -offset } else { offset }
+        }
+    )(i)
+}
+
+fn duration_number<'a>(designator: char) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], u32> {
+    terminated(map(take_while_m_n(1, 9, is_digit), buf_to_int), char(designator))
+}
+
+fn duration_time(i: &[u8]) -> IResult<&[u8], (u32, u32, u32)> {
+    preceded(char('T'), alt((
+        map(
+            pair(duration_number('H'), opt(pair(duration_number('M'), opt(duration_number('S'))))),
+            |(hours, rest)| match rest {
+                Some((minutes, seconds)) => (hours, minutes, seconds.unwrap_or(0)),
+                None => (hours, 0, 0)
+            }
+        ),
+        map(
+            pair(duration_number('M'), opt(duration_number('S'))),
+            |(minutes, seconds)| (0, minutes, seconds.unwrap_or(0))
+        ),
+        map(duration_number('S'), |seconds| (0, 0, seconds))
+    )))(i)
+}
+
+/// `DURATION` value, like `P15DT5H0M20S` or `-PT15M`,
+/// with the sign separately since durations are unsigned.
+pub fn duration(i: &[u8]) -> IResult<&[u8], (i8, Duration)> {
+    pair(
+        map(opt(one_of("+-")), |sign| if sign == Some('-') { -1 } else { 1 }),
+        preceded(char('P'), alt((
+            map(duration_number('W'), Duration::from_weeks),
+            map(
+                pair(duration_number('D'), opt(duration_time)),
+                |(days, time)| {
+                    let (hours, minutes, seconds) = time.unwrap_or_default();
+                    Duration { days, hours, minutes, seconds, ..Default::default() }
+                }
+            ),
+            map(duration_time, |(hours, minutes, seconds)| Duration {
+                hours,
+                minutes,
+                seconds,
+                ..Default::default()
+            })
+        )))
+    )(i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn datetime() {
+        assert_eq!(super::datetime(b"19970714T133000Z"), Ok((&[][..], DateTime {
+            date: YmdDate { year: 1997, month: 7, day: 14 },
+            time: AnyTime::Global(GlobalTime {
+                local: LocalTime {
+                    naive: HmsTime { hour: 13, minute: 30, second: 0 },
+                    fraction: 0.
+                },
+                timezone: 0
+            })
+        })));
+        assert_eq!(super::datetime(b"19980118T230000"), Ok((&[][..], DateTime {
+            date: YmdDate { year: 1998, month: 1, day: 18 },
+            time: AnyTime::Local(LocalTime {
+                naive: HmsTime { hour: 23, minute: 0, second: 0 },
+                fraction: 0.
+            })
+        })));
+        assert!(super::datetime(b"1997-07-14T13:30:00Z").is_err());
+        assert!(super::datetime(b"19970714T1330Z").is_err());
+    }
+
+    #[test]
+    fn utc_offset() {
+        assert_eq!(super::utc_offset(b"-0500"),   Ok((&[][..], -5 * 60)));
+        assert_eq!(super::utc_offset(b"+013000"), Ok((&[][..], 90)));
+        assert!(super::utc_offset(b"+01:00").is_err());
+    }
+
+    #[test]
+    fn duration() {
+        assert_eq!(super::duration(b"P15DT5H0M20S"), Ok((&[][..], (1, Duration {
+            days: 15,
+            hours: 5,
+            seconds: 20,
+            ..Default::default()
+        }))));
+        assert_eq!(super::duration(b"-PT15M"), Ok((&[][..], (-1, Duration {
+            minutes: 15,
+            ..Default::default()
+        }))));
+        assert_eq!(super::duration(b"P7W"), Ok((&[][..], (1, Duration::from_weeks(7)))));
+        assert!(super::duration(b"P1Y").is_err());
+        assert!(super::duration(b"PT").is_err());
+        // seconds may only follow minutes
+        assert_eq!(super::duration(b"PT1H1S"), Ok((&b"1S"[..], (1, Duration {
+            hours: 1,
+            ..Default::default()
+        }))));
+    }
+}
@@ -0,0 +1,174 @@
+use ::{
+    date::*,
+    datetime::*
+};
+use time::AnyTime;
+use Spanned;
+use super::datetimes_spanned;
+
+/// Longest timestamp that a [`Scanner`] finds, in bytes.
+pub const SCANNER_MAX_LEN: usize = 64;
+
+/// What a [`Scanner`] saw in the bytes fed to it.
+#[derive(PartialEq, Clone, Debug)]
+pub enum ScanEvent {
+    /// Timestamps that ended within the bytes, in order,
+    /// with their positions counted from the start of the stream.
+    Found(Vec<Spanned<DateTime<Date, AnyTime>>>),
+    /// No timestamp ended, but one may still be in progress.
+    Pending,
+    /// No timestamp ended and none is in progress.
+    Idle
+}
+
+/// Finds timestamps in a stream of bytes fed in arbitrary chunks,
+/// like [`datetimes_spanned`] does in a whole buffer.
+///
+/// Only keeps up to twice [`SCANNER_MAX_LEN`] bytes
+/// of the run of bytes that may belong to a timestamp.
+#[derive(Eq, PartialEq, Clone, Default, Debug)]
+pub struct Scanner {
+    run: Vec<u8>,
+    /// Position of the start of the run in the stream
+    start: usize,
+    /// Whether the run starts at a word boundary
+    boundary: bool,
+    /// Position of the next byte in the stream
+    position: usize,
+    last_alphanumeric: bool
+}
+
+impl Scanner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn feed(&mut self, bytes: &[u8]) -> ScanEvent {
+        let mut found = vec![];
+        for &byte in bytes {
+            if may_be_in_timestamp(byte) {
+                if self.run.is_empty() {
+                    self.start = self.position;
+                    self.boundary = !self.last_alphanumeric;
+                }
+                self.run.push(byte);
+                if self.run.len() == 2 * SCANNER_MAX_LEN {
+                    self.drain(SCANNER_MAX_LEN, &mut found);
+                }
+            } else {
+                self.drain(0, &mut found);
+            }
+            self.last_alphanumeric = byte.is_ascii_alphanumeric();
+            self.position += 1;
+        }
+        self.event(found)
+    }
+
+    /// Ends the stream, reporting a timestamp at its very end.
+    ///
+    /// The scanner can then be fed a new stream.
+    pub fn finish(&mut self) -> ScanEvent {
+        let mut found = vec![];
+        self.drain(0, &mut found);
+        *self = Self::default();
+        self.event(found)
+    }
+
+    fn event(&self, found: Vec<Spanned<DateTime<Date, AnyTime>>>) -> ScanEvent {
+        if !found.is_empty() {
+            ScanEvent::Found(found)
+        } else if !self.run.is_empty() {
+            ScanEvent::Pending
+        } else {
+            ScanEvent::Idle
+        }
+    }
+
+    /// Reports the timestamps in the run, except those that may be continued
+    /// in the last `keep` bytes, and drops the bytes before them.
+    fn drain(&mut self, keep: usize, found: &mut Vec<Spanned<DateTime<Date, AnyTime>>>) {
+        let mut cut = self.run.len() - keep;
+
+        // like a buffer that continues a word cannot start a candidate
+        let skip = if self.boundary {
+            0
+        } else {
+            self.run.iter().take_while(|x| x.is_ascii_alphanumeric()).count()
+        };
+        for x in datetimes_spanned(&self.run[skip..]) {
+            let source = x.source.start + skip..x.source.end + skip;
+            if source.start >= cut {
+                break;
+            }
+            if keep > 0 && source.end == self.run.len() {
+                // longer than the maximum, so skip it like one that does not parse
+                continue;
+            }
+            cut = cut.max(source.end);
+            found.push(Spanned {
+                value: x.value,
+                source: self.start + source.start..self.start + source.end
+            });
+        }
+
+        if cut > 0 {
+            self.boundary = !self.run[cut - 1].is_ascii_alphanumeric();
+        }
+        self.run.drain(..cut);
+        self.start += cut;
+    }
+}
+
+/// Whether the byte may be part of a representation,
+/// including the bytes of non-ASCII minus signs.
+fn may_be_in_timestamp(byte: u8) -> bool {
+    byte.is_ascii_digit() || b"-+:.,TWZ".contains(&byte) || !byte.is_ascii()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scan(buffer: &[u8], chunk: usize) -> Vec<Spanned<DateTime<Date, AnyTime>>> {
+        let mut scanner = Scanner::new();
+        let mut events: Vec<_> = buffer.chunks(chunk).map(|x| scanner.feed(x)).collect();
+        events.push(scanner.finish());
+        events.into_iter()
+            .flat_map(|event| match event {
+                ScanEvent::Found(x) => x,
+                _ => vec![]
+            })
+            .collect()
+    }
+
+    #[test]
+    fn feed() {
+        let buffer = b"2018-08-02T13:42:02Z start\nid=12018-08-02T13:42:03Z\n[2018-W31-4T13:42:04+02:00] stop x2018-08-02T13:42:05Z 2018-08-02T13:42:06.5";
+        let expected: Vec<_> = datetimes_spanned(buffer).collect();
+        assert_eq!(expected.len(), 3);
+        for chunk in 1..=buffer.len() {
+            assert_eq!(scan(buffer, chunk), expected, "chunk {}", chunk);
+        }
+
+        let mut scanner = Scanner::new();
+        assert_eq!(scanner.feed(b"at "), ScanEvent::Idle);
+        assert_eq!(scanner.feed(b"2018-08-02T13:42:0"), ScanEvent::Pending);
+        match scanner.feed(b"2Z\n") {
+            ScanEvent::Found(x) => assert_eq!(x[0].source, 3..23),
+            event => panic!("{:?}", event)
+        }
+    }
+
+    #[test]
+    fn long_runs() {
+        let mut buffer = vec![b'1'; 1000];
+        buffer.extend_from_slice(b"-2018-08-02T13:42:02Z");
+        buffer.extend_from_slice(&[b'0'; 1000]);
+        buffer.extend_from_slice(b":2018-08-02T13:42:02Z");
+
+        let found = scan(&buffer, 7);
+        assert_eq!(found, datetimes_spanned(&buffer).collect::<Vec<_>>());
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[1].source, 2022..2042);
+    }
+}
@@ -0,0 +1,48 @@
+//! Grammar productions that fail with [`nom::Err::Incomplete`]
+//! if the input ends before they can decide whether they match.
+
+productions!(streaming);
+
+#[cfg(test)]
+mod tests {
+    use nom::Err;
+
+    #[test]
+    fn year() {
+        assert_eq!(super::year(b"2018"),  Ok((&[][..],  2018)));
+        assert_eq!(super::year(b"-0333"), Ok((&[][..],  -333)));
+        assert!(matches!(super::year(b"201"), Err(Err::Incomplete(_))));
+    }
+
+    #[test]
+    fn ordinal() {
+        assert_eq!(super::ordinal(b"1111"), Ok((&b"1"[..], 111)));
+        assert_eq!(super::ordinal(b"111"),  Ok((&[][..],   111)));
+        assert!(matches!(super::ordinal(b"11"), Err(Err::Incomplete(_))));
+    }
+
+    #[test]
+    fn week_weekday() {
+        assert_eq!(super::week(b"01"), Ok((&[][..], 1)));
+        assert!(matches!(super::week(b"0"), Err(Err::Incomplete(_))));
+        assert_eq!(super::weekday(b"7"), Ok((&[][..], 7)));
+        assert!(matches!(super::weekday(b""), Err(Err::Incomplete(_))));
+    }
+
+    #[test]
+    fn fraction() {
+        assert_eq!(super::fraction(b".5 "), Ok((&b" "[..], 0.5)));
+        assert!(matches!(super::fraction(b".5"), Err(Err::Incomplete(_))));
+    }
+
+    #[test]
+    fn offset() {
+        assert_eq!(super::offset(b"+23:59 "), Ok((&b" "[..],  23 * 60 + 59)));
+        assert_eq!(super::offset(b"+2359 "),  Ok((&b" "[..],  23 * 60 + 59)));
+        assert_eq!(super::offset(b"-23 "),    Ok((&b" "[..], -23 * 60)));
+        assert_eq!(super::offset(b"Z"),       Ok((&[][..],   0)));
+        assert!(matches!(super::offset(b"-23"), Err(Err::Incomplete(_))));
+        assert!(matches!(super::offset(b"+05"), Err(Err::Incomplete(_))));
+        assert!(matches!(super::offset(b"+05:"), Err(Err::Incomplete(_))));
+    }
+}
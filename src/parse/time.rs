@@ -1,151 +1,324 @@
 use ::time::*;
-use super::*;
-use nom::character::is_digit;
-
-named!(hour <u8>, map!(
-    take_while_m_n!(2, 2, is_digit),
-    buf_to_int
-));
-
-named!(minute <u8>, map!(
-    take_while_m_n!(2, 2, is_digit),
-    buf_to_int
-));
-
-named!(second <u8>, map!(
-    take_while_m_n!(2, 2, is_digit),
-    buf_to_int
-));
-
-named_args!(time_hms_format(extended: bool) <HmsTime>, do_parse!(
-    hour: hour >>
-    cond!(extended, char!(':')) >>
-    minute: minute >>
-    cond!(extended, char!(':')) >>
-    second: second >>
-    (HmsTime { hour, minute, second })
-));
-named!(time_hms_basic    <HmsTime>, call!(time_hms_format, false));
-named!(time_hms_extended <HmsTime>, call!(time_hms_format, true));
-
-named!(pub time_hms <HmsTime>, alt!(
-    time_hms_extended |
-    time_hms_basic
-));
-
-named_args!(time_hm_format(extended: bool) <HmTime>, do_parse!(
-    hour: hour >>
-    cond!(extended, char!(':')) >>
-    minute: minute >>
-    (HmTime { hour, minute })
-));
-named!(time_hm_basic    <HmTime>, call!(time_hm_format, false));
-named!(time_hm_extended <HmTime>, call!(time_hm_format, true));
-
-named!(pub time_hm <HmTime>, alt!(
-    time_hm_extended |
-    time_hm_basic
-));
-
-named!(pub time_h <HTime>, map!(hour, |hour| HTime { hour }));
-
-named!(time_naive_approx <ApproxNaiveTime>, alt!(
-    complete!(map!(time_hms, ApproxNaiveTime::HMS)) |
-    complete!(map!(time_hm,  ApproxNaiveTime::HM)) |
-    complete!(map!(time_h,   ApproxNaiveTime::H))
-));
-
-named!(pub time_local_approx <ApproxLocalTime>, do_parse!(
-    naive: time_naive_approx >>
-    fraction: opt!(complete!(frac32)) >>
-    (match naive {
-        ApproxNaiveTime::HMS(naive) => ApproxLocalTime::HMS(LocalTime {
-            naive,
-            fraction: fraction.unwrap_or(0.)
-        }),
-        ApproxNaiveTime::HM(naive) => ApproxLocalTime::HM(LocalTime {
-            naive,
-            fraction: fraction.unwrap_or(0.)
-        }),
-        ApproxNaiveTime::H(naive) => ApproxLocalTime::H(LocalTime {
-            naive,
-            fraction: fraction.unwrap_or(0.)
-        })
-    })
-));
-
-named!(pub time_global_approx <ApproxGlobalTime>, do_parse!(
-    local: time_local_approx >>
-    timezone: timezone >>
-    (match local {
-        ApproxLocalTime::HMS(local) => ApproxGlobalTime::HMS(GlobalTime { local, timezone }),
-        ApproxLocalTime::HM (local) => ApproxGlobalTime::HM (GlobalTime { local, timezone }),
-        ApproxLocalTime::H  (local) => ApproxGlobalTime::H  (GlobalTime { local, timezone })
-    })
-));
-
-named!(pub time_any_approx <ApproxAnyTime>, alt!(
-    map!(time_any_hms, ApproxAnyTime::HMS) |
-    map!(time_any_hm,  ApproxAnyTime::HM) |
-    map!(time_any_h,   ApproxAnyTime::H)
-));
+use super::complete::{
+    hour,
+    minute,
+    second,
+    fraction,
+    offset,
+    sign
+};
+use super::{
+    ParseOptions,
+    TimeDesignator
+};
+use nom::{
+    IResult,
+    branch::alt,
+    character::complete::{
+        anychar,
+        char,
+        one_of
+    },
+    combinator::{
+        cond,
+        map,
+        map_opt,
+        not,
+        opt,
+        recognize,
+        verify
+    },
+    sequence::{
+        pair,
+        preceded,
+        terminated,
+        tuple
+    }
+};
+
+fn time_hms_format<'a>(extended: bool) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], HmsTime> {
+    map(
+        tuple((
+            hour,
+            cond(extended, char(':')),
+            minute,
+            cond(extended, char(':')),
+            second
+        )),
+        |(hour, _, minute, _, second)| HmsTime { hour, minute, second }
+    )
+}
 
-macro_rules! time_local_accuracy {
-    (pub $name:ident, $naive:ty, $naive_submac:ident) => {
-        named!(pub $name <LocalTime<$naive>>, do_parse!(
-            opt!(char!('T')) >>
-            naive: $naive_submac >>
-            fraction: opt!(complete!(frac32)) >>
-            (LocalTime {
+// either separator may be omitted, as in `1213:14`
+fn time_hms_mixed_format(i: &[u8]) -> IResult<&[u8], HmsTime> {
+    map(
+        tuple((
+            hour,
+            opt(char(':')),
+            minute,
+            opt(char(':')),
+            second
+        )),
+        |(hour, _, minute, _, second)| HmsTime { hour, minute, second }
+    )(i)
+}
+
+/// Rejects a time that is followed by more digits or separators,
+/// so components cannot be taken for others.
+fn whole<'a, O, P>(parser: P) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], O>
+where P: FnMut(&'a [u8]) -> IResult<&'a [u8], O> {
+    terminated(parser, not(one_of("0123456789:")))
+}
+
+pub fn time_hms_with<'a>(options: ParseOptions) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], HmsTime> {
+    alt((
+        whole(time_hms_format(true)),
+        whole(time_hms_format(false)),
+        verify(whole(time_hms_mixed_format), move |_| options.allow_mixed_time_format)
+    ))
+}
+
+pub fn time_hms(i: &[u8]) -> IResult<&[u8], HmsTime> {
+    time_hms_with(ParseOptions::default())(i)
+}
+
+fn time_hm_format<'a>(extended: bool) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], HmTime> {
+    map(
+        tuple((
+            hour,
+            cond(extended, char(':')),
+            minute
+        )),
+        |(hour, _, minute)| HmTime { hour, minute }
+    )
+}
+
+pub fn time_hm(i: &[u8]) -> IResult<&[u8], HmTime> {
+    alt((
+        whole(time_hm_format(true)),
+        whole(time_hm_format(false))
+    ))(i)
+}
+
+// there is only one format to choose
+fn time_hm_with<'a>(_options: ParseOptions) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], HmTime> {
+    time_hm
+}
+
+pub fn time_h(i: &[u8]) -> IResult<&[u8], HTime> {
+    map(whole(hour), |hour| HTime { hour })(i)
+}
+
+// see `time_hm_with`
+fn time_h_with<'a>(_options: ParseOptions) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], HTime> {
+    time_h
+}
+
+/// A decimal fraction with at most as many digits as the options keep.
+fn fraction_with<'a>(options: ParseOptions) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], f32> {
+    move |i| match options.max_fraction_digits {
+        None => fraction(i),
+        Some(digits) => map_opt(recognize(fraction), |repr| truncated_fraction(repr, digits))(i)
+    }
+}
+
+fn truncated_fraction(repr: &[u8], digits: u8) -> Option<f32> {
+    let repr = ::std::str::from_utf8(repr).ok()?;
+    if repr.contains(['e', 'E']) {
+        // the digits written are not those of the value
+        let scale = 10f64.powi(i32::from(digits));
+        return Some(((repr.parse::<f64>().ok()? * scale).trunc() / scale) as f32);
+    }
+
+    // the separator and the digits to keep
+    match &repr[..repr.len().min(1 + usize::from(digits))] {
+        "." => Some(0.),
+        repr => repr.parse().ok()
+    }
+}
+
+/// The time designator before a time of day on its own, as the options require.
+fn time_designator<'a>(options: ParseOptions) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], Option<char>> {
+    move |i| match options.time_designator {
+        TimeDesignator::Optional  => opt(designator('T', options))(i),
+        TimeDesignator::Required  => map(designator('T', options), Some)(i),
+        TimeDesignator::Forbidden => map(not(designator('T', options)), |_| None)(i)
+    }
+}
+
+fn time_naive_approx<'a>(options: ParseOptions) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], ApproxNaiveTime> {
+    alt((
+        map(time_hms_with(options), ApproxNaiveTime::HMS),
+        map(time_hm,                ApproxNaiveTime::HM),
+        map(time_h,                 ApproxNaiveTime::H)
+    ))
+}
+
+pub fn time_local_approx_with<'a>(options: ParseOptions) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], ApproxLocalTime> {
+    map(
+        tuple((time_designator(options), time_naive_approx(options), opt(fraction_with(options)))),
+        |(_, naive, fraction)| match naive {
+            ApproxNaiveTime::HMS(naive) => ApproxLocalTime::HMS(LocalTime {
+                naive,
+                fraction: fraction.unwrap_or(0.)
+            }),
+            ApproxNaiveTime::HM(naive) => ApproxLocalTime::HM(LocalTime {
+                naive,
+                fraction: fraction.unwrap_or(0.)
+            }),
+            ApproxNaiveTime::H(naive) => ApproxLocalTime::H(LocalTime {
                 naive,
                 fraction: fraction.unwrap_or(0.)
             })
-        ));
+        }
+    )
+}
+
+pub fn time_local_approx(i: &[u8]) -> IResult<&[u8], ApproxLocalTime> {
+    time_local_approx_with(ParseOptions::default())(i)
+}
+
+pub fn time_global_approx_with<'a>(options: ParseOptions) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], ApproxGlobalTime> {
+    map(
+        pair(time_local_approx_with(options), timezone_with(options)),
+        |(local, timezone)| match local {
+            ApproxLocalTime::HMS(local) => ApproxGlobalTime::HMS(GlobalTime { local, timezone }),
+            ApproxLocalTime::HM (local) => ApproxGlobalTime::HM (GlobalTime { local, timezone }),
+            ApproxLocalTime::H  (local) => ApproxGlobalTime::H  (GlobalTime { local, timezone })
+        }
+    )
+}
+
+pub fn time_global_approx(i: &[u8]) -> IResult<&[u8], ApproxGlobalTime> {
+    time_global_approx_with(ParseOptions::default())(i)
+}
+
+pub fn time_any_approx_with<'a>(options: ParseOptions) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], ApproxAnyTime> {
+    alt((
+        map(time_any_hms_with(options), ApproxAnyTime::HMS),
+        map(time_any_hm_with(options),  ApproxAnyTime::HM),
+        map(time_any_h_with(options),   ApproxAnyTime::H)
+    ))
+}
+
+pub fn time_any_approx(i: &[u8]) -> IResult<&[u8], ApproxAnyTime> {
+    time_any_approx_with(ParseOptions::default())(i)
+}
+
+macro_rules! time_local_accuracy {
+    (pub $name:ident, $name_with:ident, $naive:ty, $naive_parser:ident) => {
+        pub fn $name_with<'a>(options: ParseOptions) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], LocalTime<$naive>> {
+            map(
+                tuple((time_designator(options), $naive_parser(options), opt(fraction_with(options)))),
+                |(_, naive, fraction)| LocalTime {
+                    naive,
+                    fraction: fraction.unwrap_or(0.)
+                }
+            )
+        }
+
+        pub fn $name(i: &[u8]) -> IResult<&[u8], LocalTime<$naive>> {
+            $name_with(ParseOptions::default())(i)
+        }
     }
 }
-time_local_accuracy!(pub time_local_hms, HmsTime, time_hms);
-time_local_accuracy!(pub time_local_hm,  HmTime,  time_hm);
-time_local_accuracy!(pub time_local_h,   HTime,   time_h);
+time_local_accuracy!(pub time_local_hms, time_local_hms_with, HmsTime, time_hms_with);
+time_local_accuracy!(pub time_local_hm,  time_local_hm_with,  HmTime,  time_hm_with);
+time_local_accuracy!(pub time_local_h,   time_local_h_with,   HTime,   time_h_with);
 
 macro_rules! time_global_accuracy {
-    (pub $name:ident, $naive:ty, $local_submac:ident) => {
-        named!(pub $name <GlobalTime<$naive>>, do_parse!(
-            local: $local_submac >>
-            timezone: complete!(timezone) >>
-            (GlobalTime { local, timezone })
-        ));
+    (pub $name:ident, $name_with:ident, $naive:ty, $local_parser:ident) => {
+        pub fn $name_with<'a>(options: ParseOptions) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], GlobalTime<$naive>> {
+            map(
+                pair($local_parser(options), timezone_with(options)),
+                |(local, timezone)| GlobalTime { local, timezone }
+            )
+        }
+
+        pub fn $name(i: &[u8]) -> IResult<&[u8], GlobalTime<$naive>> {
+            $name_with(ParseOptions::default())(i)
+        }
     }
 }
-time_global_accuracy!(pub time_global_hms, HmsTime, time_local_hms);
-time_global_accuracy!(pub time_global_hm,  HmTime,  time_local_hm);
-time_global_accuracy!(pub time_global_h,   HTime,   time_local_h);
+time_global_accuracy!(pub time_global_hms, time_global_hms_with, HmsTime, time_local_hms_with);
+time_global_accuracy!(pub time_global_hm,  time_global_hm_with,  HmTime,  time_local_hm_with);
+time_global_accuracy!(pub time_global_h,   time_global_h_with,   HTime,   time_local_h_with);
 
 macro_rules! time_any_accuracy {
-    (pub $name:ident, $naive:ty, $local_submac:ident, $global_submac:ident) => {
-        named!(pub $name <AnyTime<$naive>>, alt!(
-            complete!(map!($global_submac, AnyTime::Global)) |
-            complete!(map!($local_submac, AnyTime::Local))
-        ));
+    (pub $name:ident, $name_with:ident, $naive:ty, $local_parser:ident, $global_parser:ident) => {
+        pub fn $name_with<'a>(options: ParseOptions) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], AnyTime<$naive>> {
+            alt((
+                map($global_parser(options), AnyTime::Global),
+                map($local_parser(options),  AnyTime::Local)
+            ))
+        }
+
+        pub fn $name(i: &[u8]) -> IResult<&[u8], AnyTime<$naive>> {
+            $name_with(ParseOptions::default())(i)
+        }
     }
 }
-time_any_accuracy!(pub time_any_hms, HmsTime, time_local_hms, time_global_hms);
-time_any_accuracy!(pub time_any_hm,  HmTime,  time_local_hm,  time_global_hm);
-time_any_accuracy!(pub time_any_h,   HTime,   time_local_h,   time_global_h);
+time_any_accuracy!(pub time_any_hms, time_any_hms_with, HmsTime, time_local_hms_with, time_global_hms_with);
+time_any_accuracy!(pub time_any_hm,  time_any_hm_with,  HmTime,  time_local_hm_with,  time_global_hm_with);
+time_any_accuracy!(pub time_any_h,   time_any_h_with,   HTime,   time_local_h_with,   time_global_h_with);
+
+/// An uppercase designator or, if allowed, its lowercase form.
+pub(super) fn designator<'a>(c: char, options: ParseOptions) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], char> {
+    verify(anychar, move |&x| x == c || options.allow_lowercase && x == c.to_ascii_lowercase())
+}
 
-named!(timezone_utc <i16>, map!(char!('Z'), |_| 0));
+/// `Z` or an offset in any format the options accept,
+/// regardless of the format of the time of day before it.
+fn timezone_with<'a>(options: ParseOptions) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], i16> {
+    alt((
+        map(designator('Z', options), |_| 0),
+        map(
+            verify(
+                tuple((sign, hour, char(':'), minute)),
+                move |_| options.require_extended_offset
+            ),
+            |(sign, hour, _, minute)| i16::from(sign) * (i16::from(hour) * 60 + i16::from(minute))
+        ),
+        verify(offset, move |_| !options.require_extended_offset)
+    ))
+}
+
+/// Difference from UTC with optional minutes and seconds,
+/// either `Z` or the basic or extended format,
+/// on its own as in columns separate from the local time.
+pub fn time_zone_offset(i: &[u8]) -> IResult<&[u8], TimeZoneOffset> {
+    time_zone_offset_with(ParseOptions::default())(i)
+}
 
-named!(timezone_fixed <i16>, do_parse!(
-    sign: sign >>
-    hour: hour >>
-    minute: opt!(complete!(do_parse!(
-        opt!(char!(':')) >>
-        minute: minute >>
-        (minute)
-    ))) >>
-    (sign as i16 * (hour as i16 * 60 + minute.unwrap_or(0) as i16))
-));
+pub fn time_zone_offset_with<'a>(options: ParseOptions) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], TimeZoneOffset> {
+    alt((
+        map(designator('Z', options), |_| TimeZoneOffset::default()),
+        map(
+            tuple((
+                sign,
+                hour,
+                verify(
+                    opt(alt((
+                        preceded(char(':'), map(pair(minute, opt(preceded(char(':'), second))), |x| (true, x))),
+                        map(pair(minute, opt(second)), |x| (false, x))
+                    ))),
+                    move |rest| !options.require_extended_offset || matches!(rest, Some((true, _)))
+                )
+            )),
+            |(sign, hour, rest)| {
+                let (minute, second) = rest.map_or((0, None), |(_, rest)| rest);
+                TimeZoneOffset {
+                    seconds: i32::from(sign) * ((i32::from(hour) * 60 + i32::from(minute)) * 60 + i32::from(second.unwrap_or(0)))
+                }
+            }
+        )
+    ))
+}
 
-named!(timezone <i16>, alt!(timezone_utc | timezone_fixed));
+#[cfg(test)]
+fn timezone(i: &[u8]) -> IResult<&[u8], i16> {
+    timezone_with(ParseOptions::default())(i)
+}
 
 #[cfg(test)]
 mod tests {
@@ -180,19 +353,19 @@ mod tests {
 
     #[test]
     fn timezone_fixed() {
-        assert_eq!(super::timezone_fixed(b"+23:59 "), Ok((&b" "[..],  23 * 60 + 59)));
-        assert_eq!(super::timezone_fixed(b"+23:59"),  Ok((&[][..],    23 * 60 + 59)));
-        assert_eq!(super::timezone_fixed(b"+2359 "),  Ok((&b" "[..],  23 * 60 + 59)));
-        assert_eq!(super::timezone_fixed(b"+2359"),   Ok((&[][..],    23 * 60 + 59)));
-        assert_eq!(super::timezone_fixed(b"-23 "),    Ok((&b" "[..], -23 * 60)));
-        assert_eq!(super::timezone_fixed(b"-23"),     Ok((&[][..],   -23 * 60)));
+        assert_eq!(super::timezone(b"+23:59 "), Ok((&b" "[..],  23 * 60 + 59)));
+        assert_eq!(super::timezone(b"+23:59"),  Ok((&[][..],    23 * 60 + 59)));
+        assert_eq!(super::timezone(b"+2359 "),  Ok((&b" "[..],  23 * 60 + 59)));
+        assert_eq!(super::timezone(b"+2359"),   Ok((&[][..],    23 * 60 + 59)));
+        assert_eq!(super::timezone(b"-23 "),    Ok((&b" "[..], -23 * 60)));
+        assert_eq!(super::timezone(b"-23"),     Ok((&[][..],   -23 * 60)));
     }
 
     #[test]
     fn timezone_utc() {
-        assert_eq!(super::timezone_utc(b"Z "), Ok((&b" "[..], 0)));
-        assert_eq!(super::timezone_utc(b"Z"),  Ok((&[][..],   0)));
-        assert_eq!(super::timezone_utc(b"z"),  Err(Err::Error(Error { input: &b"z"[..], code: Char })));
+        assert_eq!(super::timezone(b"Z "), Ok((&b" "[..], 0)));
+        assert_eq!(super::timezone(b"Z"),  Ok((&[][..],   0)));
+        assert_eq!(super::timezone(b"z"),  Err(Err::Error(Error { input: &b"z"[..], code: Char })));
     }
 
     #[test]
@@ -205,6 +378,61 @@ mod tests {
         assert_eq!(super::timezone(b"Z"),       Ok((&[][..],   0)));
     }
 
+    #[test]
+    fn time_zone_offset() {
+        let offset = |seconds| TimeZoneOffset { seconds };
+        assert_eq!(super::time_zone_offset(b"+01:23:45"), Ok((&[][..], offset(3600 + 23 * 60 + 45))));
+        assert_eq!(super::time_zone_offset(b"-012345"),   Ok((&[][..], offset(-(3600 + 23 * 60 + 45)))));
+        assert_eq!(super::time_zone_offset(b"+01:23"),    Ok((&[][..], offset(3600 + 23 * 60))));
+        assert_eq!(super::time_zone_offset(b"-01"),       Ok((&[][..], offset(-3600))));
+        assert_eq!(super::time_zone_offset(b"Z"),         Ok((&[][..], offset(0))));
+        assert_eq!(super::time_zone_offset(b"+01:2345"),  Ok((&b"45"[..], offset(3600 + 23 * 60))));
+        assert!(super::time_zone_offset(b"z").is_err());
+        assert_eq!(
+            super::time_zone_offset_with(ParseOptions::default().allow_lowercase(true))(b"z"),
+            Ok((&[][..], offset(0)))
+        );
+    }
+
+    #[test]
+    fn offset_formats() {
+        let strict = ParseOptions::default().require_extended_offset(true);
+        for &(offset, minutes, extended) in &[
+            ("+05",    5 * 60,       false),
+            ("+0530",  5 * 60 + 30,  false),
+            ("+05:30", 5 * 60 + 30,  true),
+            ("-05",    -5 * 60,      false),
+            ("-05:30", -5 * 60 - 30, true),
+            ("Z",      0,            true)
+        ] {
+            for &time in &["12:30:00", "123000", "T12:30:00", "T123000"] {
+                let input = format!("{}{}", time, offset);
+                let (_, global) = super::time_global_hms(input.as_bytes()).unwrap();
+                assert_eq!(global.timezone, minutes, "{}", input);
+                assert_eq!(time_global_hms_with(strict)(input.as_bytes()).is_ok(), extended, "{}", input);
+            }
+
+            for &datetime in &["2018-08-02T12:30:00", "20180802T123000"] {
+                let input = format!("{}{}", datetime, offset);
+                let (_, global) = ::parse::datetime_global_hms(input.as_bytes()).unwrap();
+                assert_eq!(global.time.timezone, minutes, "{}", input);
+                assert_eq!(::parse::datetime_global_hms_with(strict)(input.as_bytes()).is_ok(), extended, "{}", input);
+            }
+
+            assert_eq!(timezone_with(strict)(offset.as_bytes()).is_ok(), extended, "{}", offset);
+            assert_eq!(super::time_zone_offset(offset.as_bytes()).map(|x| x.1.seconds), Ok(i32::from(minutes) * 60));
+            assert_eq!(time_zone_offset_with(strict)(offset.as_bytes()).is_ok(), extended, "{}", offset);
+        }
+
+        let rfc3339 = ParseOptions::from(::parse::Profile::Rfc3339);
+        assert!(::parse::datetime_global_hms_with(rfc3339)(b"2018-08-02 12:30:00+05:30").is_ok());
+        assert!(::parse::datetime_global_hms_with(rfc3339)(b"2018-08-02 12:30:00+0530").is_err());
+        assert!(::parse::datetime_global_hms_with(rfc3339)(b"2018-08-02 12:30:00+05").is_err());
+
+        assert!(time_zone_offset_with(strict)(b"+05:30:15").is_ok());
+        assert!(time_zone_offset_with(strict)(b"+053015").is_err());
+    }
+
     #[test]
     fn time_hms() {
         let value = HmsTime {
@@ -212,9 +440,9 @@ mod tests {
             minute: 22,
             second: 33
         };
-        assert_eq!(super::time_hms(b"11:22:33 "), Ok((&b" "[..], value.clone())));
-        assert_eq!(super::time_hms(b"11:22:33"),  Ok((&[][..],   value.clone())));
-        assert_eq!(super::time_hms(b"112233 "),   Ok((&b" "[..], value.clone())));
+        assert_eq!(super::time_hms(b"11:22:33 "), Ok((&b" "[..], value)));
+        assert_eq!(super::time_hms(b"11:22:33"),  Ok((&[][..],   value)));
+        assert_eq!(super::time_hms(b"112233 "),   Ok((&b" "[..], value)));
         assert_eq!(super::time_hms(b"112233"),    Ok((&[][..],   value)));
     }
 
@@ -224,18 +452,77 @@ mod tests {
             hour: 11,
             minute: 22
         };
-        assert_eq!(super::time_hm(b"11:22 "), Ok((&b" "[..], value.clone())));
-        assert_eq!(super::time_hm(b"11:22"),  Ok((&[][..],   value.clone())));
-        assert_eq!(super::time_hm(b"1122 "),  Ok((&b" "[..], value.clone())));
+        assert_eq!(super::time_hm(b"11:22 "), Ok((&b" "[..], value)));
+        assert_eq!(super::time_hm(b"11:22"),  Ok((&[][..],   value)));
+        assert_eq!(super::time_hm(b"1122 "),  Ok((&b" "[..], value)));
         assert_eq!(super::time_hm(b"1122"),   Ok((&[][..],   value)));
     }
 
+    #[test]
+    fn mixed_format() {
+        let value = HmsTime {
+            hour: 12,
+            minute: 13,
+            second: 14
+        };
+        let options = ParseOptions::default().allow_mixed_time_format(true);
+        assert!(super::time_hms(b"1213:14").is_err());
+        assert!(super::time_hms(b"12:1314").is_err());
+        assert!(super::time_hm(b"1213:14").is_err());
+        assert!(super::time_hm(b"12:1314").is_err());
+        assert!(super::time_h(b"1213").is_err());
+        assert!(super::time_local_approx(b"1213:14").is_err());
+        assert_eq!(time_hms_with(options)(b"1213:14"), Ok((&[][..], value)));
+        assert_eq!(time_hms_with(options)(b"12:1314"), Ok((&[][..], value)));
+
+        // four digits are hour and minute
+        assert_eq!(super::time_any_approx(b"T1415"), Ok((&[][..], ApproxAnyTime::HM(AnyTime::Local(LocalTime {
+            naive: HmTime {
+                hour: 14,
+                minute: 15
+            },
+            fraction: 0.
+        })))));
+    }
+
+    #[test]
+    fn time_designator() {
+        let value = LocalTime {
+            naive: HmTime {
+                hour: 14,
+                minute: 30
+            },
+            fraction: 0.
+        };
+        let approx = ApproxLocalTime::HM(value);
+
+        let options = ParseOptions::default();
+        assert_eq!(options.time_designator, TimeDesignator::Optional);
+        assert_eq!(time_local_hm_with(options)(b"T14:30"), Ok((&[][..], value)));
+        assert_eq!(time_local_hm_with(options)(b"14:30"),  Ok((&[][..], value)));
+        assert_eq!(time_local_approx_with(options)(b"T14:30"), Ok((&[][..], approx)));
+        assert_eq!(time_local_approx_with(options)(b"14:30"),  Ok((&[][..], approx)));
+
+        let options = ParseOptions::default().time_designator(TimeDesignator::Required);
+        assert_eq!(time_local_hm_with(options)(b"T14:30"), Ok((&[][..], value)));
+        assert!(time_local_hm_with(options)(b"14:30").is_err());
+        assert_eq!(time_local_approx_with(options)(b"T14:30"), Ok((&[][..], approx)));
+        assert!(time_local_approx_with(options)(b"14:30").is_err());
+        assert!(time_global_hm_with(options)(b"14:30Z").is_err());
+        assert!(time_local_hm_with(options.allow_lowercase(true))(b"t14:30").is_ok());
+
+        let options = ParseOptions::default().time_designator(TimeDesignator::Forbidden);
+        assert!(time_local_hm_with(options)(b"T14:30").is_err());
+        assert_eq!(time_local_hm_with(options)(b"14:30"), Ok((&[][..], value)));
+        assert!(time_any_approx_with(options)(b"T14:30").is_err());
+    }
+
     #[test]
     fn time_h() {
         let value = HTime {
             hour: 11
         };
-        assert_eq!(super::time_h(b"11 "), Ok((&b" "[..], value.clone())));
+        assert_eq!(super::time_h(b"11 "), Ok((&b" "[..], value)));
         assert_eq!(super::time_h(b"11"),  Ok((&[][..],   value)));
     }
 
@@ -249,17 +536,17 @@ mod tests {
             },
             fraction: 0.1
         };
-        assert_eq!(super::time_local_hms(b"T16:43:52.1 "), Ok((&b" "[..], value.clone())));
-        assert_eq!(super::time_local_hms(b"T16:43:52.1"),  Ok((&[][..],   value.clone())));
-        assert_eq!(super::time_local_hms(b"16:43:52.1"),   Ok((&[][..],   value.clone())));
-        assert_eq!(super::time_local_hms(b"T164352.1"),    Ok((&[][..],   value.clone())));
-        assert_eq!(super::time_local_hms(b"164352.1"),     Ok((&[][..],   value.clone())));
+        assert_eq!(super::time_local_hms(b"T16:43:52.1 "), Ok((&b" "[..], value)));
+        assert_eq!(super::time_local_hms(b"T16:43:52.1"),  Ok((&[][..],   value)));
+        assert_eq!(super::time_local_hms(b"16:43:52.1"),   Ok((&[][..],   value)));
+        assert_eq!(super::time_local_hms(b"T164352.1"),    Ok((&[][..],   value)));
+        assert_eq!(super::time_local_hms(b"164352.1"),     Ok((&[][..],   value)));
 
         let value = LocalTime {
             fraction: 0.,
             ..value
         };
-        assert_eq!(super::time_local_hms(b"T16:43:52"), Ok((&[][..], value.clone())));
+        assert_eq!(super::time_local_hms(b"T16:43:52"), Ok((&[][..], value)));
         assert_eq!(super::time_local_hms(b"16:43:52"),  Ok((&[][..], value)));
     }
 
@@ -272,18 +559,18 @@ mod tests {
             },
             fraction: 0.1
         };
-        assert_eq!(super::time_local_hm(b"T16:43.1"), Ok((&[][..], value.clone())));
-        assert_eq!(super::time_local_hm(b"16:43.1"),  Ok((&[][..], value.clone())));
-        assert_eq!(super::time_local_hm(b"T1643.1"),  Ok((&[][..], value.clone())));
-        assert_eq!(super::time_local_hm(b"1643.1"),   Ok((&[][..], value.clone())));
+        assert_eq!(super::time_local_hm(b"T16:43.1"), Ok((&[][..], value)));
+        assert_eq!(super::time_local_hm(b"16:43.1"),  Ok((&[][..], value)));
+        assert_eq!(super::time_local_hm(b"T1643.1"),  Ok((&[][..], value)));
+        assert_eq!(super::time_local_hm(b"1643.1"),   Ok((&[][..], value)));
 
         let value = LocalTime {
             fraction: 0.,
             ..value
         };
-        assert_eq!(super::time_local_hm(b"T16:43"), Ok((&[][..], value.clone())));
-        assert_eq!(super::time_local_hm(b"16:43"),  Ok((&[][..], value.clone())));
-        assert_eq!(super::time_local_hm(b"T1643"),  Ok((&[][..], value.clone())));
+        assert_eq!(super::time_local_hm(b"T16:43"), Ok((&[][..], value)));
+        assert_eq!(super::time_local_hm(b"16:43"),  Ok((&[][..], value)));
+        assert_eq!(super::time_local_hm(b"T1643"),  Ok((&[][..], value)));
         assert_eq!(super::time_local_hm(b"1643"),   Ok((&[][..], value)));
     }
 
@@ -295,14 +582,14 @@ mod tests {
             },
             fraction: 0.1
         };
-        assert_eq!(super::time_local_h(b"T16.1"), Ok((&[][..], value.clone())));
-        assert_eq!(super::time_local_h(b"16.1"),  Ok((&[][..], value.clone())));
+        assert_eq!(super::time_local_h(b"T16.1"), Ok((&[][..], value)));
+        assert_eq!(super::time_local_h(b"16.1"),  Ok((&[][..], value)));
 
         let value = LocalTime {
             fraction: 0.,
             ..value
         };
-        assert_eq!(super::time_local_h(b"T16"), Ok((&[][..], value.clone())));
+        assert_eq!(super::time_local_h(b"T16"), Ok((&[][..], value)));
         assert_eq!(super::time_local_h(b"16"),  Ok((&[][..], value)));
     }
 
@@ -319,20 +606,20 @@ mod tests {
             },
             timezone: 0
         };
-        assert_eq!(super::time_global_hms(b"T16:43:52Z"), Ok((&[][..], value.clone())));
-        assert_eq!(super::time_global_hms(b"16:43:52Z"),  Ok((&[][..], value.clone())));
-        assert_eq!(super::time_global_hms(b"T164352Z"),   Ok((&[][..], value.clone())));
-        assert_eq!(super::time_global_hms(b"164352Z"),    Ok((&[][..], value.clone())));
+        assert_eq!(super::time_global_hms(b"T16:43:52Z"), Ok((&[][..], value)));
+        assert_eq!(super::time_global_hms(b"16:43:52Z"),  Ok((&[][..], value)));
+        assert_eq!(super::time_global_hms(b"T164352Z"),   Ok((&[][..], value)));
+        assert_eq!(super::time_global_hms(b"164352Z"),    Ok((&[][..], value)));
 
         {
             let value = GlobalTime {
                 timezone: 2,
-                ..value.clone()
+                ..value
             };
-            assert_eq!(super::time_global_hms(b"T16:43:52+0002"), Ok((&[][..], value.clone())));
-            assert_eq!(super::time_global_hms(b"16:43:52+0002"),  Ok((&[][..], value.clone())));
-            assert_eq!(super::time_global_hms(b"T164352+0002"),   Ok((&[][..], value.clone())));
-            assert_eq!(super::time_global_hms(b"164352+0002"),    Ok((&[][..], value.clone())));
+            assert_eq!(super::time_global_hms(b"T16:43:52+0002"), Ok((&[][..], value)));
+            assert_eq!(super::time_global_hms(b"16:43:52+0002"),  Ok((&[][..], value)));
+            assert_eq!(super::time_global_hms(b"T164352+0002"),   Ok((&[][..], value)));
+            assert_eq!(super::time_global_hms(b"164352+0002"),    Ok((&[][..], value)));
 
             let value = GlobalTime {
                 local: LocalTime {
@@ -341,9 +628,9 @@ mod tests {
                 },
                 ..value
             };
-            assert_eq!(super::time_global_hms(b"T16:43:52.1+0002"), Ok((&[][..], value.clone())));
-            assert_eq!(super::time_global_hms(b"16:43:52.1+0002"),  Ok((&[][..], value.clone())));
-            assert_eq!(super::time_global_hms(b"T164352.1+0002"),   Ok((&[][..], value.clone())));
+            assert_eq!(super::time_global_hms(b"T16:43:52.1+0002"), Ok((&[][..], value)));
+            assert_eq!(super::time_global_hms(b"16:43:52.1+0002"),  Ok((&[][..], value)));
+            assert_eq!(super::time_global_hms(b"T164352.1+0002"),   Ok((&[][..], value)));
             assert_eq!(super::time_global_hms(b"164352.1+0002"),    Ok((&[][..], value)));
         }
 
@@ -354,9 +641,9 @@ mod tests {
             },
             ..value
         };
-        assert_eq!(super::time_global_hms(b"T16:43:52.1Z"), Ok((&[][..], value.clone())));
-        assert_eq!(super::time_global_hms(b"16:43:52.1Z"),  Ok((&[][..], value.clone())));
-        assert_eq!(super::time_global_hms(b"T164352.1Z"),   Ok((&[][..], value.clone())));
+        assert_eq!(super::time_global_hms(b"T16:43:52.1Z"), Ok((&[][..], value)));
+        assert_eq!(super::time_global_hms(b"16:43:52.1Z"),  Ok((&[][..], value)));
+        assert_eq!(super::time_global_hms(b"T164352.1Z"),   Ok((&[][..], value)));
         assert_eq!(super::time_global_hms(b"164352.1Z"),    Ok((&[][..], value)));
     }
 
@@ -372,10 +659,10 @@ mod tests {
             },
             timezone: 0
         };
-        assert_eq!(super::time_global_hm(b"T16:43Z"), Ok((&[][..], value.clone())));
-        assert_eq!(super::time_global_hm(b"16:43Z"),  Ok((&[][..], value.clone())));
-        assert_eq!(super::time_global_hm(b"T1643Z"),  Ok((&[][..], value.clone())));
-        assert_eq!(super::time_global_hm(b"1643Z"),   Ok((&[][..], value.clone())));
+        assert_eq!(super::time_global_hm(b"T16:43Z"), Ok((&[][..], value)));
+        assert_eq!(super::time_global_hm(b"16:43Z"),  Ok((&[][..], value)));
+        assert_eq!(super::time_global_hm(b"T1643Z"),  Ok((&[][..], value)));
+        assert_eq!(super::time_global_hm(b"1643Z"),   Ok((&[][..], value)));
 
         let value = GlobalTime {
             local: LocalTime {
@@ -384,9 +671,9 @@ mod tests {
             },
             ..value
         };
-        assert_eq!(super::time_global_hm(b"T16:43.1Z"), Ok((&[][..], value.clone())));
-        assert_eq!(super::time_global_hm(b"16:43.1Z"),  Ok((&[][..], value.clone())));
-        assert_eq!(super::time_global_hm(b"T1643.1Z"),  Ok((&[][..], value.clone())));
+        assert_eq!(super::time_global_hm(b"T16:43.1Z"), Ok((&[][..], value)));
+        assert_eq!(super::time_global_hm(b"16:43.1Z"),  Ok((&[][..], value)));
+        assert_eq!(super::time_global_hm(b"T1643.1Z"),  Ok((&[][..], value)));
         assert_eq!(super::time_global_hm(b"1643.1Z"),   Ok((&[][..], value)));
     }
 
@@ -401,8 +688,8 @@ mod tests {
             },
             timezone: 0
         };
-        assert_eq!(super::time_global_h(b"T16Z"), Ok((&[][..], value.clone())));
-        assert_eq!(super::time_global_h(b"16Z"),  Ok((&[][..], value.clone())));
+        assert_eq!(super::time_global_h(b"T16Z"), Ok((&[][..], value)));
+        assert_eq!(super::time_global_h(b"16Z"),  Ok((&[][..], value)));
 
         let value = GlobalTime {
             local: LocalTime {
@@ -411,7 +698,7 @@ mod tests {
             },
             ..value
         };
-        assert_eq!(super::time_global_h(b"T16.1Z"), Ok((&[][..], value.clone())));
+        assert_eq!(super::time_global_h(b"T16.1Z"), Ok((&[][..], value)));
         assert_eq!(super::time_global_h(b"16.1Z"),  Ok((&[][..], value)));
     }
 
@@ -425,9 +712,9 @@ mod tests {
             },
             fraction: 0.
         });
-        assert_eq!(super::time_any_hms(b"T16:43:52"), Ok((&[][..], value.clone())));
-        assert_eq!(super::time_any_hms(b"16:43:52"),  Ok((&[][..], value.clone())));
-        assert_eq!(super::time_any_hms(b"T164352"),   Ok((&[][..], value.clone())));
+        assert_eq!(super::time_any_hms(b"T16:43:52"), Ok((&[][..], value)));
+        assert_eq!(super::time_any_hms(b"16:43:52"),  Ok((&[][..], value)));
+        assert_eq!(super::time_any_hms(b"T164352"),   Ok((&[][..], value)));
         assert_eq!(super::time_any_hms(b"164352"),    Ok((&[][..], value)));
 
         let value = AnyTime::Global(GlobalTime {
@@ -441,9 +728,9 @@ mod tests {
             },
             timezone: 0
         });
-        assert_eq!(super::time_any_hms(b"T02:03:52Z"), Ok((&[][..], value.clone())));
-        assert_eq!(super::time_any_hms(b"02:03:52Z"),  Ok((&[][..], value.clone())));
-        assert_eq!(super::time_any_hms(b"T020352Z"),   Ok((&[][..], value.clone())));
+        assert_eq!(super::time_any_hms(b"T02:03:52Z"), Ok((&[][..], value)));
+        assert_eq!(super::time_any_hms(b"02:03:52Z"),  Ok((&[][..], value)));
+        assert_eq!(super::time_any_hms(b"T020352Z"),   Ok((&[][..], value)));
         assert_eq!(super::time_any_hms(b"020352Z"),    Ok((&[][..], value)));
 
         let value = AnyTime::Global(GlobalTime {
@@ -455,11 +742,11 @@ mod tests {
                 },
                 fraction: 0.
             },
-            timezone: -1 * 60
+            timezone: -60
         });
-        assert_eq!(super::time_any_hms(b"T02:03:52-01"), Ok((&[][..], value.clone())));
-        assert_eq!(super::time_any_hms(b"02:03:52-01"),  Ok((&[][..], value.clone())));
-        assert_eq!(super::time_any_hms(b"T020352-01"),   Ok((&[][..], value.clone())));
+        assert_eq!(super::time_any_hms(b"T02:03:52-01"), Ok((&[][..], value)));
+        assert_eq!(super::time_any_hms(b"02:03:52-01"),  Ok((&[][..], value)));
+        assert_eq!(super::time_any_hms(b"T020352-01"),   Ok((&[][..], value)));
         assert_eq!(super::time_any_hms(b"020352-01"),    Ok((&[][..], value)));
     }
 
@@ -472,9 +759,9 @@ mod tests {
             },
             fraction: 0.
         });
-        assert_eq!(super::time_any_hm(b"T16:43"), Ok((&[][..], value.clone())));
-        assert_eq!(super::time_any_hm(b"16:43"),  Ok((&[][..], value.clone())));
-        assert_eq!(super::time_any_hm(b"T1643"),  Ok((&[][..], value.clone())));
+        assert_eq!(super::time_any_hm(b"T16:43"), Ok((&[][..], value)));
+        assert_eq!(super::time_any_hm(b"16:43"),  Ok((&[][..], value)));
+        assert_eq!(super::time_any_hm(b"T1643"),  Ok((&[][..], value)));
         assert_eq!(super::time_any_hm(b"1643"),   Ok((&[][..], value)));
 
         let value = AnyTime::Global(GlobalTime {
@@ -487,9 +774,9 @@ mod tests {
             },
             timezone: 0
         });
-        assert_eq!(super::time_any_hm(b"T02:03Z"), Ok((&[][..], value.clone())));
-        assert_eq!(super::time_any_hm(b"02:03Z"),  Ok((&[][..], value.clone())));
-        assert_eq!(super::time_any_hm(b"T0203Z"),  Ok((&[][..], value.clone())));
+        assert_eq!(super::time_any_hm(b"T02:03Z"), Ok((&[][..], value)));
+        assert_eq!(super::time_any_hm(b"02:03Z"),  Ok((&[][..], value)));
+        assert_eq!(super::time_any_hm(b"T0203Z"),  Ok((&[][..], value)));
         assert_eq!(super::time_any_hm(b"0203Z"),   Ok((&[][..], value)));
 
         let value = AnyTime::Global(GlobalTime {
@@ -500,11 +787,11 @@ mod tests {
                 },
                 fraction: 0.
             },
-            timezone: -1 * 60
+            timezone: -60
         });
-        assert_eq!(super::time_any_hm(b"T02:03-01"), Ok((&[][..], value.clone())));
-        assert_eq!(super::time_any_hm(b"02:03-01"),  Ok((&[][..], value.clone())));
-        assert_eq!(super::time_any_hm(b"T0203-01"),  Ok((&[][..], value.clone())));
+        assert_eq!(super::time_any_hm(b"T02:03-01"), Ok((&[][..], value)));
+        assert_eq!(super::time_any_hm(b"02:03-01"),  Ok((&[][..], value)));
+        assert_eq!(super::time_any_hm(b"T0203-01"),  Ok((&[][..], value)));
         assert_eq!(super::time_any_hm(b"0203-01"),   Ok((&[][..], value)));
     }
 
@@ -516,7 +803,7 @@ mod tests {
             },
             fraction: 0.
         });
-        assert_eq!(super::time_any_h(b"T16"), Ok((&[][..], value.clone())));
+        assert_eq!(super::time_any_h(b"T16"), Ok((&[][..], value)));
         assert_eq!(super::time_any_h(b"16"),  Ok((&[][..], value)));
 
         let value = AnyTime::Global(GlobalTime {
@@ -528,7 +815,7 @@ mod tests {
             },
             timezone: 0
         });
-        assert_eq!(super::time_any_h(b"T02Z"), Ok((&[][..], value.clone())));
+        assert_eq!(super::time_any_h(b"T02Z"), Ok((&[][..], value)));
         assert_eq!(super::time_any_h(b"02Z"),  Ok((&[][..], value)));
 
         let value = AnyTime::Global(GlobalTime {
@@ -538,9 +825,9 @@ mod tests {
                 },
                 fraction: 0.
             },
-            timezone: -1 * 60
+            timezone: -60
         });
-        assert_eq!(super::time_any_h(b"T02-01"), Ok((&[][..], value.clone())));
+        assert_eq!(super::time_any_h(b"T02-01"), Ok((&[][..], value)));
         assert_eq!(super::time_any_h(b"02-01"),  Ok((&[][..], value)));
     }
 
@@ -0,0 +1,359 @@
+//! Lexical spaces of the date and time datatypes of XML Schema (XSD).
+//!
+//! Years may have more than four digits but must fit an `i16`.
+//! All types allow an optional timezone where `-00:00` means UTC.
+
+use ::{
+    Valid,
+    date::*,
+    time::*,
+    datetime::*,
+    duration::*
+};
+use super::{
+    buf_to_int,
+    complete::{
+        month,
+        day,
+        hour,
+        minute,
+        second
+    }
+};
+use nom::{
+    IResult,
+    branch::alt,
+    bytes::complete::{
+        tag,
+        take_while1,
+        take_while_m_n
+    },
+    character::{
+        is_digit,
+        complete::{
+            char,
+            one_of
+        }
+    },
+    combinator::{
+        map,
+        map_opt,
+        map_res,
+        opt,
+        recognize,
+        verify
+    },
+    sequence::{
+        pair,
+        preceded,
+        separated_pair,
+        terminated,
+        tuple
+    }
+};
+
+/// Version of XML Schema, which differ in their handling of years before 1 CE.
+#[derive(Eq, PartialEq, Clone, Copy, Default, Debug)]
+pub enum Version {
+    /// Disallows year `0000` and counts `-0001` as 1 BCE,
+    /// which is year `0000` in ISO 8601.
+    #[default]
+    V1_0,
+    /// Allows year `0000` as 1 BCE like ISO 8601.
+    V1_1
+}
+
+/// A value with the optional timezone, in minutes,
+/// that all XSD date and time types allow.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct Zoned<T> {
+    pub value: T,
+    pub timezone: Option<i16>
+}
+
+fn year<'a>(version: Version) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], i16> {
+    map_opt(
+        pair(opt(char('-')), take_while1(is_digit)),
+        move |(minus, digits): (Option<char>, &[u8])| {
+            // more than four digits may not have leading zeros
+            if digits.len() < 4 || digits.len() > 4 && digits[0] == b'0' {
+                return None;
+            }
+            let year: i16 = ::std::str::from_utf8(digits).ok()?.parse().ok()?;
+            match (version, minus) {
+                (Version::V1_0, _) if year == 0 => None,
+                (Version::V1_0, Some(_)) => Some(1 - year),
+                (Version::V1_1, Some(_)) => Some(-year),
+                (_, None) => Some(year)
+            }
+        }
+    )
+}
+
+/// `Z` or `±hh:mm` up to 14 hours.
+pub fn timezone(i: &[u8]) -> IResult<&[u8], i16> {
+    alt((
+        map(char('Z'), |_| 0),
+        map(
+            verify(
+                tuple((one_of("+-"), hour, char(':'), minute)),
+                |&(_, hour, _, minute)| hour < 14 && minute < 60 || hour == 14 && minute == 0
+            ),
+            |(sign, hour, _, minute)| {
+                let offset = i16::from(hour) * 60 + i16::from(minute);
+                if sign == '-' { -offset } else { offset }
+            }
+        )
+    ))(i)
+}
+
+fn zoned<'a, T, P>(mut parser: P) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], Zoned<T>>
+where P: FnMut(&'a [u8]) -> IResult<&'a [u8], T> {
+    move |i| {
+        let (i, value) = parser(i)?;
+        let (i, timezone) = opt(timezone)(i)?;
+        Ok((i, Zoned { value, timezone }))
+    }
+}
+
+fn local_time(i: &[u8]) -> IResult<&[u8], LocalTime> {
+    verify(
+        map(
+            tuple((
+                hour, char(':'), minute, char(':'), second,
+                opt(map_res(recognize(pair(char('.'), take_while1(is_digit))), |x: &[u8]| ::std::str::from_utf8(x)
+                    .ok()
+                    .and_then(|x| x.parse().ok())
+                    .ok_or(())))
+            )),
+            |(hour, _, minute, _, second, fraction)| LocalTime {
+                naive: HmsTime { hour, minute, second },
+                fraction: fraction.unwrap_or(0.)
+            }
+        ),
+        // end of day is only allowed as exactly `24:00:00`
+        |time| time.naive.hour < 24 && time.naive.minute < 60 && time.naive.second < 60 ||
+            time.naive == HmsTime { hour: 24, minute: 0, second: 0 } && time.fraction == 0.
+    )(i)
+}
+
+/// `xsd:time`, like `13:20:00-05:00`.
+pub fn time(i: &[u8]) -> IResult<&[u8], AnyTime> {
+    map(zoned(local_time), |Zoned { value, timezone }| match timezone {
+        Some(timezone) => AnyTime::Global(GlobalTime { local: value, timezone }),
+        None => AnyTime::Local(value)
+    })(i)
+}
+
+fn ym_date<'a>(version: Version) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], YmDate> {
+    verify(
+        map(
+            separated_pair(year(version), char('-'), month),
+            |(year, month)| YmDate { year, month }
+        ),
+        Valid::is_valid
+    )
+}
+
+fn ymd_date<'a>(version: Version) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], YmdDate> {
+    verify(
+        map(
+            separated_pair(ym_date(version), char('-'), day),
+            |(YmDate { year, month }, day)| YmdDate { year, month, day }
+        ),
+        Valid::is_valid
+    )
+}
+
+/// `xsd:dateTime`, like `2002-10-10T12:00:00-05:00`.
+pub fn date_time_with<'a>(version: Version) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], DateTime<YmdDate, AnyTime>> {
+    map(
+        separated_pair(ymd_date(version), char('T'), time),
+        |(date, time)| DateTime { date, time }
+    )
+}
+
+pub fn date_time(i: &[u8]) -> IResult<&[u8], DateTime<YmdDate, AnyTime>> {
+    date_time_with(Version::default())(i)
+}
+
+/// `xsd:date`, like `2002-10-10Z`.
+pub fn date_with<'a>(version: Version) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], Zoned<YmdDate>> {
+    zoned(ymd_date(version))
+}
+
+pub fn date(i: &[u8]) -> IResult<&[u8], Zoned<YmdDate>> {
+    date_with(Version::default())(i)
+}
+
+/// `xsd:gYearMonth`, like `2002-10`.
+pub fn g_year_month_with<'a>(version: Version) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], Zoned<YmDate>> {
+    zoned(ym_date(version))
+}
+
+pub fn g_year_month(i: &[u8]) -> IResult<&[u8], Zoned<YmDate>> {
+    g_year_month_with(Version::default())(i)
+}
+
+/// `xsd:gYear`, like `2002`.
+pub fn g_year_with<'a>(version: Version) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], Zoned<YDate>> {
+    zoned(map(year(version), |year| YDate { year }))
+}
+
+pub fn g_year(i: &[u8]) -> IResult<&[u8], Zoned<YDate>> {
+    g_year_with(Version::default())(i)
+}
+
+/// `xsd:gMonthDay` as month and day, like `--10-10`.
+pub fn g_month_day(i: &[u8]) -> IResult<&[u8], Zoned<(u8, u8)>> {
+    zoned(verify(
+        preceded(tag("--"), separated_pair(month, char('-'), day)),
+        // in a leap year so that February 29 is allowed
+        |&(month, day)| YmdDate { year: 4, month, day }.is_valid()
+    ))(i)
+}
+
+/// `xsd:gMonth`, like `--10`.
+pub fn g_month(i: &[u8]) -> IResult<&[u8], Zoned<u8>> {
+    zoned(verify(preceded(tag("--"), month), |month| (1..=12).contains(month)))(i)
+}
+
+/// `xsd:gDay`, like `---10`.
+pub fn g_day(i: &[u8]) -> IResult<&[u8], Zoned<u8>> {
+    zoned(verify(preceded(tag("---"), day), |day| (1..=31).contains(day)))(i)
+}
+
+fn duration_number<'a>(designator: char) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], Option<u32>> {
+    opt(terminated(map(take_while_m_n(1, 9, is_digit), buf_to_int), char(designator)))
+}
+
+fn duration_seconds(i: &[u8]) -> IResult<&[u8], Option<(u32, u32)>> {
+    opt(terminated(
+        map(
+            pair(
+                map(take_while_m_n(1, 9, is_digit), buf_to_int),
+                opt(preceded(char('.'), map(take_while1(is_digit), |digits: &[u8]| {
+                    // nanoseconds, ignoring finer digits
+                    let digits = &digits[..digits.len().min(9)];
+                    buf_to_int::<u32>(digits) * 10u32.pow(9 - digits.len() as u32)
+                })))
+            ),
+            |(seconds, nanoseconds)| (seconds, nanoseconds.unwrap_or(0))
+        ),
+        char('S')
+    ))(i)
+}
+
+/// `xsd:duration`, like `-P1Y2M3DT10H30M`,
+/// with the sign separately since durations are unsigned.
+pub fn duration(i: &[u8]) -> IResult<&[u8], (i8, Duration)> {
+    map_opt(
+        tuple((
+            map(opt(char('-')), |sign| if sign.is_some() { -1 } else { 1 }),
+            char('P'),
+            duration_number('Y'),
+            duration_number('M'),
+            duration_number('D'),
+            opt(preceded(char('T'), tuple((
+                duration_number('H'),
+                duration_number('M'),
+                duration_seconds
+            ))))
+        )),
+        |(sign, _, years, months, days, time)| {
+            let (hours, minutes, seconds) = match time {
+                // the time designator must be followed by a time component
+                Some((None, None, None)) => return None,
+                Some(time) => time,
+                None if (years, months, days) == (None, None, None) => return None,
+                None => (None, None, None)
+            };
+            let (seconds, nanoseconds) = seconds.unwrap_or((0, 0));
+            Some((sign, Duration {
+                years: years.unwrap_or(0),
+                months: months.unwrap_or(0),
+                days: days.unwrap_or(0),
+                hours: hours.unwrap_or(0),
+                minutes: minutes.unwrap_or(0),
+                seconds,
+                nanoseconds,
+                ..Default::default()
+            }))
+        }
+    )(i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn year() {
+        assert_eq!(super::year(Version::V1_0)(b"2002"),   Ok((&[][..], 2002)));
+        assert_eq!(super::year(Version::V1_0)(b"12002"),  Ok((&[][..], 12002)));
+        assert_eq!(super::year(Version::V1_0)(b"-0001"),  Ok((&[][..], 0)));
+        assert_eq!(super::year(Version::V1_1)(b"-0001"),  Ok((&[][..], -1)));
+        assert_eq!(super::year(Version::V1_1)(b"0000"),   Ok((&[][..], 0)));
+        assert!(super::year(Version::V1_0)(b"0000").is_err());
+        assert!(super::year(Version::V1_0)(b"02002").is_err());
+        assert!(super::year(Version::V1_0)(b"202").is_err());
+        assert!(super::year(Version::V1_0)(b"99999").is_err());
+    }
+
+    #[test]
+    fn date_time() {
+        assert_eq!(super::date_time(b"2002-10-10T12:00:00-00:00"), Ok((&[][..], DateTime {
+            date: YmdDate { year: 2002, month: 10, day: 10 },
+            time: AnyTime::Global(GlobalTime {
+                local: LocalTime {
+                    naive: HmsTime { hour: 12, minute: 0, second: 0 },
+                    fraction: 0.
+                },
+                timezone: 0
+            })
+        })));
+        assert!(super::date_time(b"2002-10-10T24:00:00").is_ok());
+        assert!(super::date_time(b"2002-10-10T24:00:00.5").is_err());
+        assert!(super::date_time(b"2002-10-10T24:00:01").is_err());
+        assert!(super::date_time(b"2002-02-29T00:00:00").is_err());
+        assert_eq!(super::date_time(b"2002-10-10T12:00:00+14:01").map(|x| x.0), Ok(&b"+14:01"[..]));
+    }
+
+    #[test]
+    fn gregorian() {
+        assert_eq!(super::g_year_month(b"2002-10Z"), Ok((&[][..], Zoned {
+            value: YmDate { year: 2002, month: 10 },
+            timezone: Some(0)
+        })));
+        assert_eq!(super::g_month_day(b"--02-29"), Ok((&[][..], Zoned {
+            value: (2, 29),
+            timezone: None
+        })));
+        assert_eq!(super::g_day(b"---31+01:00"), Ok((&[][..], Zoned {
+            value: 31,
+            timezone: Some(60)
+        })));
+        assert_eq!(super::g_month(b"--12"), Ok((&[][..], Zoned {
+            value: 12,
+            timezone: None
+        })));
+        assert!(super::g_month_day(b"--02-30").is_err());
+    }
+
+    #[test]
+    fn duration() {
+        assert_eq!(super::duration(b"-P1Y2M3DT10H30M1.5S"), Ok((&[][..], (-1, Duration {
+            years: 1,
+            months: 2,
+            days: 3,
+            hours: 10,
+            minutes: 30,
+            seconds: 1,
+            nanoseconds: 500_000_000,
+            ..Default::default()
+        }))));
+        assert!(super::duration(b"P").is_err());
+        assert!(super::duration(b"P1YT").is_err());
+        assert!(super::duration(b"P1W").is_err());
+    }
+}
@@ -0,0 +1,103 @@
+use {
+    Valid,
+    date::*,
+    time::*,
+    datetime::*,
+    names::*
+};
+
+/// Components that a datetime must have, in the manner of cron,
+/// where a missing component matches any value.
+///
+/// Components are compared as written, in the datetime's own offset.
+#[derive(Eq, PartialEq, Clone, Copy, Default, Debug)]
+pub struct TemporalPattern {
+    pub month: Option<Month>,
+    /// Day of the month
+    pub day: Option<u8>,
+    pub weekday: Option<Weekday>,
+    pub hour: Option<u8>,
+    pub minute: Option<u8>,
+    pub second: Option<u8>
+}
+
+impl TemporalPattern {
+    pub fn month(mut self, month: Month) -> Self {
+        self.month = Some(month);
+        self
+    }
+
+    pub fn day(mut self, day: u8) -> Self {
+        self.day = Some(day);
+        self
+    }
+
+    pub fn weekday(mut self, weekday: Weekday) -> Self {
+        self.weekday = Some(weekday);
+        self
+    }
+
+    pub fn hour(mut self, hour: u8) -> Self {
+        self.hour = Some(hour);
+        self
+    }
+
+    pub fn minute(mut self, minute: u8) -> Self {
+        self.minute = Some(minute);
+        self
+    }
+
+    pub fn second(mut self, second: u8) -> Self {
+        self.second = Some(second);
+        self
+    }
+
+    /// Always `false` if the date is not valid.
+    pub fn matches<D, T>(&self, datetime: &DateTime<D, T>) -> bool where
+        D: Datelike + Valid + Copy + Into<YmdDate>,
+        T: Timelike + CompleteTimelike
+    {
+        if !datetime.date.is_valid() {
+            return false;
+        }
+        let date: YmdDate = datetime.date.into();
+
+        fn matches<X: PartialEq>(pattern: Option<X>, value: X) -> bool {
+            pattern.is_none_or(|x| x == value)
+        }
+
+        matches(self.month.map(u8::from), date.month) &&
+        matches(self.day,                 date.day) &&
+        matches(self.weekday,             date.day_of_week()) &&
+        matches(self.hour,                datetime.hour()) &&
+        matches(self.minute,              datetime.minute()) &&
+        matches(self.second,              datetime.second())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches() {
+        let dt: DateTime<Date, GlobalTime> = "2018-08-02T13:42:02+02:00".parse().unwrap();
+
+        assert!(TemporalPattern::default().matches(&dt));
+        assert!(TemporalPattern::default().weekday(Weekday::Thursday).hour(13).matches(&dt));
+        assert!(TemporalPattern::default().month(Month::August).day(2).minute(42).second(2).matches(&dt));
+        assert!(!TemporalPattern::default().weekday(Weekday::Friday).matches(&dt));
+        assert!(!TemporalPattern::default().hour(11).matches(&dt));
+
+        let dt: DateTime<Date, LocalTime<HmTime>> = "2018-W31-4T13:42".parse().unwrap();
+        assert!(TemporalPattern::default().month(Month::August).day(2).second(0).matches(&dt));
+
+        let invalid: DateTime<Date, GlobalTime> = "2018-02-30T13:42:02Z".parse().unwrap();
+        assert!(!TemporalPattern::default().matches(&invalid));
+
+        for invalid in &["2018-400T13:42:02Z", "2018-W60-1T13:42:02Z"] {
+            let invalid: DateTime<Date, GlobalTime> = invalid.parse().unwrap();
+            assert!(!TemporalPattern::default().matches(&invalid));
+        }
+    }
+}
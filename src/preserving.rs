@@ -0,0 +1,96 @@
+use std::{
+    fmt::{
+        self,
+        Display
+    },
+    ops::Deref,
+    str::FromStr
+};
+
+/// A value together with the text it was parsed from,
+/// which `Display` writes back byte for byte,
+/// keeping details like the number of fraction digits or `Z` versus `+00:00`.
+#[derive(PartialEq, Clone, Debug)]
+pub struct Preserving<T> {
+    value: T,
+    repr: String
+}
+
+impl<T> Preserving<T> {
+    /// Pairs a value with its own canonical representation.
+    pub fn new(value: T) -> Self
+    where T: Display {
+        Self {
+            repr: value.to_string(),
+            value
+        }
+    }
+
+    /// Pairs a value with the text it was parsed from.
+    pub fn with_repr(value: T, repr: String) -> Self {
+        Self { value, repr }
+    }
+
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    pub fn into_value(self) -> T {
+        self.value
+    }
+
+    /// The original text.
+    pub fn as_str(&self) -> &str {
+        &self.repr
+    }
+}
+
+impl<T> Deref for Preserving<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T: FromStr> FromStr for Preserving<T> {
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            value: s.parse()?,
+            repr: s.to_string()
+        })
+    }
+}
+
+impl<T> Display for Preserving<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.repr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::{
+        date::*,
+        datetime::*
+    };
+
+    #[test]
+    fn roundtrip() {
+        for x in &[
+            "2018-08-02T13:42:02.500+00:00",
+            "20180802T134202Z",
+            "2018-W31-4T13:42:02.5-0230"
+        ] {
+            let preserving: Preserving<DateTime<Date>> = x.parse().unwrap();
+            assert_eq!(preserving.to_string(), *x);
+            assert_eq!(*preserving.value(), x.parse::<DateTime<Date>>().unwrap());
+        }
+
+        let canonical = Preserving::new("2018-08-02T13:42:02.500+00:00".parse::<DateTime<Date>>().unwrap());
+        assert_eq!(canonical.as_str(), "2018-08-02T13:42:02.5Z");
+    }
+}
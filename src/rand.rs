@@ -0,0 +1,191 @@
+#![cfg(feature = "rand")]
+
+//! Random valid values, for property tests and load generators.
+//!
+//! [`Standard`] samples any valid value,
+//! while [`DateRange`] and [`DateTimeRange`] sample uniformly
+//! between two inclusive bounds.
+
+extern crate rand;
+
+use {
+    Valid,
+    date::*,
+    time::*,
+    datetime::*,
+    std::convert::TryFrom
+};
+use self::rand::{
+    Rng,
+    distributions::{
+        Distribution,
+        Standard
+    }
+};
+
+const MILLIS_PER_DAY: i128 = 86_400_000;
+
+/// Uniformly distributed calendar dates between two inclusive bounds.
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub struct DateRange {
+    start: i64,
+    end: i64
+}
+
+impl DateRange {
+    /// `None` if either bound is not valid or the start is after the end.
+    pub fn new(start: YmdDate, end: YmdDate) -> Option<Self> {
+        if !start.is_valid() || !end.is_valid() {
+            return None;
+        }
+
+        let (start, end) = (days(&start), days(&end));
+        if start <= end { Some(DateRange { start, end }) } else { None }
+    }
+}
+
+impl Distribution<YmdDate> for DateRange {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> YmdDate {
+        from_days(rng.gen_range(self.start..=self.end))
+    }
+}
+
+/// Uniformly distributed instants between two inclusive bounds
+/// at millisecond precision, in UTC.
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub struct DateTimeRange {
+    start: i128,
+    end: i128
+}
+
+impl DateTimeRange {
+    /// `None` if either bound is not valid, not representable in UTC,
+    /// or the start is after the end.
+    pub fn new(start: DateTime, end: DateTime) -> Option<Self> {
+        if !start.is_valid() || !end.is_valid() {
+            return None;
+        }
+
+        // round inwards so that no sample lies outside the bounds
+        let start = (start.sort_key()? + 999_999).div_euclid(1_000_000);
+        let end = end.sort_key()?.div_euclid(1_000_000);
+        if start <= end && utc_from_millis(start).is_some() && utc_from_millis(end).is_some() {
+            Some(DateTimeRange { start, end })
+        } else {
+            None
+        }
+    }
+}
+
+impl Distribution<DateTime> for DateTimeRange {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> DateTime {
+        utc_from_millis(rng.gen_range(self.start..=self.end))
+            .expect("bounds are representable")
+    }
+}
+
+/// Any valid calendar date.
+impl Distribution<YmdDate> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> YmdDate {
+        DateRange::new(YmdDate::MIN, YmdDate::MAX).expect("bounds are valid").sample(rng)
+    }
+}
+
+/// Any valid time of day at millisecond precision
+/// without leap seconds and with any offset in whole minutes.
+impl Distribution<GlobalTime> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> GlobalTime {
+        GlobalTime {
+            local: local_from_millis(rng.gen_range(0..MILLIS_PER_DAY)),
+            timezone: rng.gen_range(-24 * 60 + 1..24 * 60)
+        }
+    }
+}
+
+/// Any valid date and time, see the distributions of its parts.
+impl Distribution<DateTime> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> DateTime {
+        DateTime {
+            date: rng.gen(),
+            time: rng.gen()
+        }
+    }
+}
+
+fn days(date: &YmdDate) -> i64 {
+    days_from_civil(date.year.into(), date.month, date.day)
+}
+
+fn from_days(days: i64) -> YmdDate {
+    let (year, month, day) = civil_from_days(days);
+    YmdDate {
+        year: year as i16,
+        month,
+        day
+    }
+}
+
+fn local_from_millis(millis: i128) -> LocalTime {
+    let (local, _) = LocalTime::from_seconds_of_day_overflowing((millis / 1000) as u64);
+    LocalTime {
+        fraction: (millis % 1000) as f32 / 1000.,
+        ..local
+    }
+}
+
+fn utc_from_millis(millis: i128) -> Option<DateTime> {
+    let (year, month, day) = civil_from_days(i64::try_from(millis.div_euclid(MILLIS_PER_DAY)).ok()?);
+    Some(DateTime {
+        date: YmdDate {
+            year: i16::try_from(year).ok()?,
+            month,
+            day
+        },
+        time: GlobalTime {
+            local: local_from_millis(millis.rem_euclid(MILLIS_PER_DAY)),
+            timezone: 0
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use self::rand::rngs::mock::StepRng;
+
+    #[test]
+    fn standard() {
+        let mut rng = StepRng::new(0, 0x9e37_79b9_7f4a_7c15);
+        for _ in 0..1000 {
+            assert!(rng.gen::<DateTime>().is_valid());
+        }
+    }
+
+    #[test]
+    fn range() {
+        let mut rng = StepRng::new(0, 0x9e37_79b9_7f4a_7c15);
+
+        let (start, end) = (YmdDate { year: 2024, month: 2, day: 28 }, YmdDate { year: 2024, month: 3, day: 1 });
+        let dates = DateRange::new(start, end).unwrap();
+        for _ in 0..100 {
+            let date = dates.sample(&mut rng);
+            assert!(date.is_valid());
+            assert!((days(&start)..=days(&end)).contains(&days(&date)));
+        }
+        assert_eq!(DateRange::new(end, start), None);
+
+        let start: DateTime<Date> = "2018-08-02T13:42:02.0005+02:00".parse().unwrap();
+        let end:   DateTime<Date> = "2018-08-02T11:42:03Z".parse().unwrap();
+        let (start, end) = (
+            DateTime { date: start.date.into(), time: start.time },
+            DateTime { date: end.date.into(),   time: end.time }
+        );
+        let datetimes = DateTimeRange::new(start, end).unwrap();
+        for _ in 0..100 {
+            let datetime = datetimes.sample(&mut rng);
+            assert!(datetime.is_valid());
+            assert!((start.sort_key()..=end.sort_key()).contains(&datetime.sort_key()));
+        }
+        assert_eq!(DateTimeRange::new(end, start), None);
+    }
+}
@@ -0,0 +1,146 @@
+#![cfg(feature = "retail-calendar")]
+
+//! Retail calendars of whole weeks, such as the 4-4-5 calendar,
+//! whose years are week-based years (2.2.10)
+//! divided into four quarters of three periods.
+
+use {
+    Valid,
+    date::*
+};
+
+/// How many weeks the periods of each quarter have.
+#[derive(Eq, PartialEq, Clone, Copy, Default, Debug)]
+pub enum Pattern {
+    #[default]
+    FourFourFive,
+    FourFiveFour,
+    FiveFourFour
+}
+
+impl Pattern {
+    /// Weeks of the periods of a quarter, in order.
+    pub fn weeks(self) -> [u8; 3] {
+        match self {
+            Pattern::FourFourFive => [4, 4, 5],
+            Pattern::FourFiveFour => [4, 5, 4],
+            Pattern::FiveFourFour => [5, 4, 4]
+        }
+    }
+
+    /// Weeks of the period of the year,
+    /// the last of which also has the 53rd week of the year.
+    ///
+    /// Panics if the period is not from 1 to 12.
+    pub fn period_weeks(self, year: i16, period: u8) -> u8 {
+        assert!((1..=12).contains(&period), "invalid period: {:?}", period);
+        self.weeks()[usize::from(period - 1) % 3] + (period == 12 && year.has_leap_week()) as u8
+    }
+}
+
+/// A day of a retail calendar.
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub struct RetailDate {
+    /// Week-based year
+    pub year: i16,
+    /// Period of the year from 1 to 12
+    pub period: u8,
+    /// Week of the period, starting at 1
+    pub week: u8,
+    /// Day of the week, Monday being 1
+    pub day: u8,
+    pub pattern: Pattern
+}
+
+impl RetailDate {
+    /// Only meaningful if the week date is valid.
+    pub fn from_week_date(date: WdDate, pattern: Pattern) -> Self {
+        let mut period = 1;
+        let mut week = date.week;
+        while period < 12 && week > pattern.period_weeks(date.year, period) {
+            week -= pattern.period_weeks(date.year, period);
+            period += 1;
+        }
+
+        RetailDate {
+            year: date.year,
+            period,
+            week,
+            day: date.day,
+            pattern
+        }
+    }
+
+    /// Quarter of the year from 1 to 4.
+    pub fn quarter(&self) -> u8 {
+        self.period.div_ceil(3)
+    }
+}
+
+impl Valid for RetailDate {
+    fn is_valid(&self) -> bool {
+        (1..=12).contains(&self.period) &&
+        (1..=self.pattern.period_weeks(self.year, self.period)).contains(&self.week) &&
+        (1..=7).contains(&self.day)
+    }
+}
+
+/// Only meaningful if the retail date is valid.
+impl From<RetailDate> for WdDate {
+    fn from(date: RetailDate) -> Self {
+        let weeks_before: u8 = (1..date.period)
+            .map(|period| date.pattern.period_weeks(date.year, period))
+            .sum();
+        WdDate {
+            year: date.year,
+            week: weeks_before + date.week,
+            day: date.day
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_week_date() {
+        let date = |week, day| WdDate { year: 2020, week, day };
+        let retail = |period, week, day, pattern| RetailDate { year: 2020, period, week, day, pattern };
+
+        assert_eq!(RetailDate::from_week_date(date(1, 1), Pattern::FourFourFive), retail(1, 1, 1, Pattern::FourFourFive));
+        assert_eq!(RetailDate::from_week_date(date(5, 3), Pattern::FourFourFive), retail(2, 1, 3, Pattern::FourFourFive));
+        assert_eq!(RetailDate::from_week_date(date(5, 3), Pattern::FiveFourFour), retail(1, 5, 3, Pattern::FiveFourFour));
+        assert_eq!(RetailDate::from_week_date(date(13, 7), Pattern::FourFiveFour), retail(3, 4, 7, Pattern::FourFiveFour));
+        assert_eq!(RetailDate::from_week_date(date(14, 1), Pattern::FourFiveFour), retail(4, 1, 1, Pattern::FourFiveFour));
+        assert_eq!(RetailDate::from_week_date(date(53, 4), Pattern::FourFourFive), retail(12, 6, 4, Pattern::FourFourFive));
+        assert_eq!(retail(12, 6, 4, Pattern::FourFourFive).quarter(), 4);
+
+        let patterns = [Pattern::FourFourFive, Pattern::FourFiveFour, Pattern::FiveFourFour];
+        for year in 2015..=2026 {
+            for &pattern in &patterns {
+                let weeks: u8 = (1..=12).map(|period| pattern.period_weeks(year, period)).sum();
+                assert_eq!(weeks, year.num_weeks());
+
+                for week in 1..=year.num_weeks() {
+                    let date = WdDate { year, week, day: 4 };
+                    let retail = RetailDate::from_week_date(date, pattern);
+                    assert!(retail.is_valid(), "{:?}", retail);
+                    assert_eq!(WdDate::from(retail), date);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn valid() {
+        let date = RetailDate { year: 2019, period: 12, week: 5, day: 7, pattern: Pattern::FourFourFive };
+        assert!(date.is_valid());
+        assert!(!RetailDate { week: 6, ..date }.is_valid());
+        assert!(RetailDate { year: 2020, week: 6, ..date }.is_valid());
+        assert!(!RetailDate { period: 13, week: 1, ..date }.is_valid());
+        assert!(!RetailDate { period: 0, ..date }.is_valid());
+        assert!(!RetailDate { pattern: Pattern::FiveFourFour, ..date }.is_valid());
+        assert!(!RetailDate { day: 8, ..date }.is_valid());
+    }
+}
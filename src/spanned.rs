@@ -0,0 +1,37 @@
+use std::ops::{
+    Deref,
+    Range
+};
+
+/// A value together with the byte range
+/// of the text it was parsed from inside a larger buffer.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct Spanned<T> {
+    pub value: T,
+    pub source: Range<usize>
+}
+
+impl<T> Spanned<T> {
+    pub fn map<U, F>(self, f: F) -> Spanned<U>
+    where F: FnOnce(T) -> U {
+        Spanned {
+            value: f(self.value),
+            source: self.source
+        }
+    }
+
+    /// The text the value was parsed from.
+    ///
+    /// Panics if the buffer is not the one that was parsed.
+    pub fn source_in<'a>(&self, buffer: &'a [u8]) -> &'a [u8] {
+        &buffer[self.source.clone()]
+    }
+}
+
+impl<T> Deref for Spanned<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
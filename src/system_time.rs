@@ -0,0 +1,217 @@
+use {
+    Valid,
+    date::*,
+    time::*,
+    datetime::*,
+    std::{
+        convert::TryFrom,
+        time::{
+            Duration,
+            SystemTime,
+            UNIX_EPOCH
+        }
+    }
+};
+
+const SECS_PER_DAY: i64 = 24 * 60 * 60;
+
+impl DateTime {
+    /// The current system time in UTC.
+    pub fn now_utc() -> Self {
+        SystemTime::now().into()
+    }
+
+    /// `None` if the year does not fit into an `i16`.
+    pub fn checked_from_system_time(time: SystemTime) -> Option<Self> {
+        let (secs, nanos) = match time.duration_since(UNIX_EPOCH) {
+            Ok(d) => (i64::try_from(d.as_secs()).ok()?, d.subsec_nanos()),
+            Err(e) => {
+                let d = e.duration();
+                let secs = i64::try_from(d.as_secs()).ok()?;
+                match d.subsec_nanos() {
+                    0 => (-secs, 0),
+                    n => (-secs - 1, 1_000_000_000 - n)
+                }
+            }
+        };
+
+        Self::checked_from_unix(secs, nanos)
+    }
+
+    /// Panics if the year does not fit into an `i16`.
+    #[cfg(any(feature = "wasm", test))]
+    pub(crate) fn from_unix(secs: i64, nanos: u32) -> Self {
+        Self::checked_from_unix(secs, nanos).expect("year out of range")
+    }
+
+    /// `None` if the year does not fit into an `i16`.
+    pub(crate) fn checked_from_unix(secs: i64, nanos: u32) -> Option<Self> {
+        let days = secs.div_euclid(SECS_PER_DAY);
+        let secs = secs.rem_euclid(SECS_PER_DAY);
+
+        let (year, month, day) = civil_from_days(days);
+        let year = i16::try_from(year).ok()?;
+
+        let (local, _) = LocalTime::from_seconds_of_day_overflowing(secs as u64);
+
+        Some(DateTime {
+            date: YmdDate { year, month, day },
+            time: GlobalTime {
+                local: LocalTime {
+                    fraction: fraction_from_nanos(nanos),
+                    ..local
+                },
+                timezone: 0
+            }
+        })
+    }
+}
+
+impl<D> DateTime<D, GlobalTime>
+where D: Datelike + Valid + Into<YmdDate> {
+    /// Seconds and nanoseconds since the Unix epoch,
+    /// `None` if the datetime is not valid.
+    pub(crate) fn into_unix(self) -> Option<(i64, u32)> {
+        if !self.is_valid() {
+            return None;
+        }
+
+        let date: YmdDate = self.date.into();
+        let secs =
+            days_from_civil(date.year.into(), date.month, date.day) * SECS_PER_DAY +
+            i64::from(self.time.local.naive.hour)   * 3600 +
+            i64::from(self.time.local.naive.minute) * 60 +
+            i64::from(self.time.local.naive.second) -
+            i64::from(self.time.timezone)           * 60;
+        Some((secs, self.time.local.nanosecond()))
+    }
+}
+
+impl From<SystemTime> for DateTime {
+    /// Panics if the year does not fit into an `i16`,
+    /// see [`checked_from_system_time`](DateTime::checked_from_system_time).
+    fn from(time: SystemTime) -> Self {
+        DateTime::checked_from_system_time(time).expect("year out of range")
+    }
+}
+
+impl<D> TryFrom<DateTime<D, GlobalTime>> for SystemTime
+where D: Datelike + Valid + Into<YmdDate> {
+    type Error = ();
+
+    /// Fails if the datetime is not valid.
+    fn try_from(dt: DateTime<D, GlobalTime>) -> Result<Self, Self::Error> {
+        let (secs, nanos) = dt.into_unix().ok_or(())?;
+
+        Ok(if secs >= 0 {
+            UNIX_EPOCH + Duration::new(secs as u64, nanos)
+        } else {
+            UNIX_EPOCH - Duration::from_secs(secs.unsigned_abs()) + Duration::from_nanos(nanos.into())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_system_time() {
+        assert_eq!(
+            DateTime::from(UNIX_EPOCH + Duration::new(1_533_217_322, 500_000_000)),
+            DateTime {
+                date: YmdDate {
+                    year: 2018,
+                    month: 8,
+                    day: 2
+                },
+                time: GlobalTime {
+                    local: LocalTime {
+                        naive: HmsTime {
+                            hour: 13,
+                            minute: 42,
+                            second: 2
+                        },
+                        fraction: 0.5
+                    },
+                    timezone: 0
+                }
+            }
+        );
+        assert_eq!(
+            DateTime::from(UNIX_EPOCH - Duration::new(1, 0)),
+            DateTime {
+                date: YmdDate {
+                    year: 1969,
+                    month: 12,
+                    day: 31
+                },
+                time: GlobalTime {
+                    local: LocalTime {
+                        naive: HmsTime {
+                            hour: 23,
+                            minute: 59,
+                            second: 59
+                        },
+                        fraction: 0.
+                    },
+                    timezone: 0
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn checked_from_system_time() {
+        let time = UNIX_EPOCH + Duration::new(1_533_217_322, 500_000_000);
+        assert_eq!(DateTime::checked_from_system_time(time), Some(DateTime::from(time)));
+
+        if let Some(time) = UNIX_EPOCH.checked_add(Duration::from_secs(1 << 40)) {
+            assert_eq!(DateTime::checked_from_system_time(time), None);
+        }
+        if let Some(time) = UNIX_EPOCH.checked_sub(Duration::from_secs(1 << 40)) {
+            assert_eq!(DateTime::checked_from_system_time(time), None);
+        }
+    }
+
+    #[test]
+    fn last_nanoseconds() {
+        let datetime = DateTime::checked_from_unix(0, 999_999_990).unwrap();
+        assert!(datetime.is_valid());
+        assert!(datetime.time.local.fraction < 1.);
+        assert!(SystemTime::try_from(datetime).is_ok());
+
+        let datetime = DateTime::from(UNIX_EPOCH + Duration::new(1, 999_999_999));
+        assert!(datetime.is_valid());
+        assert_eq!(datetime.time.local.naive.second, 1);
+    }
+
+    #[test]
+    fn try_into_system_time() {
+        use std::str::FromStr;
+
+        assert_eq!(
+            SystemTime::try_from(DateTime::<Date, GlobalTime>::from_str("2018-08-02T15:42:02+02:00").unwrap()),
+            Ok(UNIX_EPOCH + Duration::from_secs(1_533_217_322))
+        );
+        assert_eq!(
+            SystemTime::try_from(DateTime::<Date, GlobalTime>::from_str("1969-12-31T23:59:59Z").unwrap()),
+            Ok(UNIX_EPOCH - Duration::from_secs(1))
+        );
+        assert_eq!(
+            SystemTime::try_from(DateTime::<Date, GlobalTime>::from_str("2018-02-30T00:00:00Z").unwrap()),
+            Err(())
+        );
+    }
+
+    #[test]
+    fn roundtrip() {
+        let now = SystemTime::now();
+        let dt = DateTime::from(now);
+        let back = SystemTime::try_from(dt).unwrap();
+        let diff = now.duration_since(back)
+            .or_else(|e| Ok::<_, ()>(e.duration()))
+            .unwrap();
+        assert!(diff < Duration::from_micros(1));
+    }
+}
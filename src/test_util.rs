@@ -0,0 +1,134 @@
+#![cfg(any(test, feature = "test-util"))]
+
+//! Checks for wrappers and bindings to run on their own values,
+//! such as those generated by property tests.
+
+use std::{
+    fmt,
+    str::FromStr
+};
+
+/// How a value failed to survive being written and read back.
+#[derive(PartialEq, Clone, Debug)]
+pub enum RoundtripFailure<T> {
+    /// The written value does not parse.
+    Unparsable(String),
+    /// The written value parses into another value.
+    Mismatch {
+        written: String,
+        parsed: T
+    },
+    /// The parsed value is written differently.
+    Unstable {
+        written: String,
+        rewritten: String
+    }
+}
+
+impl<T: fmt::Debug> fmt::Display for RoundtripFailure<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RoundtripFailure::Unparsable(written) =>
+                write!(f, "{:?} does not parse", written),
+            RoundtripFailure::Mismatch { written, parsed } =>
+                write!(f, "{:?} parses into {:?}", written, parsed),
+            RoundtripFailure::Unstable { written, rewritten } =>
+                write!(f, "{:?} is written as {:?} after parsing", written, rewritten)
+        }
+    }
+}
+
+impl<T: fmt::Debug> ::std::error::Error for RoundtripFailure<T> {}
+
+/// Writes the value with `Display`, parses it back with `FromStr`,
+/// and checks that the result equals the value and is written the same.
+///
+/// The value should be valid, as invalid ones need not round-trip.
+pub fn roundtrip_check<T>(value: &T) -> Result<(), RoundtripFailure<T>>
+where T: fmt::Display + FromStr + PartialEq {
+    let written = value.to_string();
+    let parsed = match written.parse::<T>() {
+        Ok(parsed) => parsed,
+        Err(_) => return Err(RoundtripFailure::Unparsable(written))
+    };
+    if parsed != *value {
+        return Err(RoundtripFailure::Mismatch { written, parsed });
+    }
+
+    let rewritten = parsed.to_string();
+    if rewritten != written {
+        return Err(RoundtripFailure::Unstable { written, rewritten });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use {
+        date::*,
+        time::*,
+        datetime::*,
+        duration::*,
+        interval::*
+    };
+
+    #[test]
+    fn roundtrip_check() {
+        let check = |s: &str| {
+            super::roundtrip_check(&s.parse::<Date>().unwrap()).unwrap();
+        };
+        check("2018-08-02");
+        check("2018-W31-4");
+        check("2018-214");
+
+        super::roundtrip_check(&"2018-08-02T13:42:02.5+02:00".parse::<DateTime<Date, GlobalTime>>().unwrap()).unwrap();
+        super::roundtrip_check(&"-P1Y2M3DT4H5M6.5S".parse::<Duration>().unwrap()).unwrap();
+        super::roundtrip_check(&"2018-08-02T13:42:02Z/P1D".parse::<Interval<DateTime<Date, GlobalTime>>>().unwrap()).unwrap();
+        super::roundtrip_check(&TimeZoneOffset { seconds: -5400 }).unwrap();
+    }
+
+    #[test]
+    fn failure() {
+        /// Compares ignoring case but reads in lowercase.
+        #[derive(Debug)]
+        struct Word(String);
+
+        impl PartialEq for Word {
+            fn eq(&self, other: &Self) -> bool {
+                self.0.eq_ignore_ascii_case(&other.0)
+            }
+        }
+
+        impl fmt::Display for Word {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl FromStr for Word {
+            type Err = ();
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    "" => Err(()),
+                    "one" => Ok(Word("two".to_string())),
+                    s => Ok(Word(s.to_ascii_lowercase()))
+                }
+            }
+        }
+
+        let check = |s: &str| super::roundtrip_check(&Word(s.to_string()));
+        assert_eq!(check("word"), Ok(()));
+        assert_eq!(check(""), Err(RoundtripFailure::Unparsable("".to_string())));
+        assert_eq!(check("one"), Err(RoundtripFailure::Mismatch {
+            written: "one".to_string(),
+            parsed: Word("two".to_string())
+        }));
+        assert_eq!(check("Word"), Err(RoundtripFailure::Unstable {
+            written: "Word".to_string(),
+            rewritten: "word".to_string()
+        }));
+        assert_eq!(check("one").unwrap_err().to_string(), "\"one\" parses into Word(\"two\")");
+    }
+}
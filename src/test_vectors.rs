@@ -0,0 +1,175 @@
+#![cfg(any(test, feature = "test-vectors"))]
+
+//! Conformance corpus of valid and invalid representations
+//! with their expected values, for reuse by wrappers and bindings.
+//!
+//! The corpus is a plain text file so that it can also be read
+//! without Rust, see [`CORPUS`] for its format.
+
+/// The corpus as bundled with the crate.
+///
+/// Each line that is not empty or a `#` comment holds
+/// a production, an input and the expected value, separated by tabs.
+/// The expected value is `invalid` or space-separated `key=value` fields.
+/// The header of the file documents the fields of each production.
+pub const CORPUS: &str = include_str!("../test-vectors/iso8601.txt");
+
+/// One line of the corpus.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct Vector {
+    /// One of `date`, `time`, `datetime` or `duration`.
+    pub production: &'static str,
+    pub input: &'static str,
+    /// `None` if the input must be rejected.
+    pub expected: Option<Vec<(&'static str, &'static str)>>
+}
+
+impl Vector {
+    /// The expected value of the field with the given key.
+    pub fn field(&self, key: &str) -> Option<&'static str> {
+        self.expected.as_ref()?
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, v)| *v)
+    }
+}
+
+/// The vectors of the [`CORPUS`] in order.
+pub fn vectors() -> impl Iterator<Item = Vector> {
+    CORPUS.lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let mut columns = line.splitn(3, '\t');
+            let mut column = || columns.next().expect("vector has three columns");
+            let (production, input, expected) = (column(), column(), column());
+            Vector {
+                production,
+                input,
+                expected: match expected {
+                    "invalid" => None,
+                    fields => Some(fields
+                        .split(' ')
+                        .map(|field| {
+                            let mut kv = field.splitn(2, '=');
+                            (kv.next().unwrap(), kv.next().expect("field has a value"))
+                        })
+                        .collect())
+                }
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        Valid,
+        date::*,
+        time::*,
+        datetime::*,
+        duration::*,
+        parse
+    };
+    use nom::IResult;
+
+    type Fields = Vec<(&'static str, String)>;
+
+    fn complete<T: Valid>(parser: fn(&[u8]) -> IResult<&[u8], T>, input: &str) -> Option<T> {
+        match parser(input.as_bytes()) {
+            Ok((rest, x)) if rest.is_empty() && x.is_valid() => Some(x),
+            _ => None
+        }
+    }
+
+    fn date_fields(date: &Date) -> Fields {
+        match *date {
+            Date::YMD(YmdDate { year, month, day }) => vec![
+                ("year", year.to_string()), ("month", month.to_string()), ("day", day.to_string())
+            ],
+            Date::WD(WdDate { year, week, day }) => vec![
+                ("year", year.to_string()), ("week", week.to_string()), ("weekday", day.to_string())
+            ],
+            Date::O(ODate { year, day }) => vec![
+                ("year", year.to_string()), ("ordinal", day.to_string())
+            ]
+        }
+    }
+
+    fn time_fields(time: &AnyTime) -> Fields {
+        let (local, offset) = match *time {
+            AnyTime::Global(GlobalTime { local, timezone }) => (local, Some(timezone)),
+            AnyTime::Local(local) => (local, None)
+        };
+
+        let mut fields = vec![
+            ("hour",   local.naive.hour.to_string()),
+            ("minute", local.naive.minute.to_string()),
+            ("second", local.naive.second.to_string())
+        ];
+        if local.fraction != 0. {
+            fields.push(("fraction", local.fraction.to_string()));
+        }
+        if let Some(offset) = offset {
+            fields.push(("offset", offset.to_string()));
+        }
+        fields
+    }
+
+    fn duration_fields(duration: &Duration) -> Fields {
+        let Duration { years, months, weeks, days, hours, minutes, seconds, nanoseconds, negative } = *duration;
+        let mut fields: Fields = vec![
+            ("years",       years),
+            ("months",      months),
+            ("weeks",       weeks),
+            ("days",        days),
+            ("hours",       hours),
+            ("minutes",     minutes),
+            ("seconds",     seconds),
+            ("nanoseconds", nanoseconds)
+        ].into_iter()
+            .filter(|(_, x)| *x != 0)
+            .map(|(k, x)| (k, x.to_string()))
+            .collect();
+        if negative {
+            fields.push(("negative", true.to_string()));
+        }
+        fields
+    }
+
+    fn fields(production: &str, input: &str) -> Option<Fields> {
+        match production {
+            "date"     => complete(parse::date, input).map(|x| date_fields(&x)),
+            "time"     => complete(parse::time_any_hms, input).map(|x| time_fields(&x)),
+            "datetime" => complete(parse::datetime_any_hms, input).map(|x: DateTime<Date, AnyTime>| {
+                let mut fields = date_fields(&x.date);
+                fields.extend(time_fields(&x.time));
+                fields
+            }),
+            "duration" => complete(parse::duration, input).map(|x| duration_fields(&x)),
+            _ => panic!("unknown production {}", production)
+        }
+    }
+
+    #[test]
+    fn vectors() {
+        let mut productions = vec![];
+        for vector in super::vectors() {
+            let expected = vector.expected.as_ref().map(|x| x.iter()
+                .map(|(k, v)| (*k, v.to_string()))
+                .collect::<Fields>());
+            assert_eq!(fields(vector.production, vector.input), expected, "{:?}", vector);
+
+            if !productions.contains(&vector.production) {
+                productions.push(vector.production);
+            }
+        }
+        assert_eq!(productions, ["date", "time", "datetime", "duration"]);
+    }
+
+    #[test]
+    fn field() {
+        let vector = super::vectors().next().unwrap();
+        assert_eq!(vector.input, "2018-08-02");
+        assert_eq!(vector.field("month"), Some("8"));
+        assert_eq!(vector.field("week"), None);
+    }
+}
@@ -1,7 +1,12 @@
-use Valid;
+use {
+    Valid,
+    std::convert::TryFrom
+};
 
 /// Local time (4.2.2.2)
-#[derive(Eq, PartialEq, Clone, Debug)]
+///
+/// Defaults to midnight, as do the other naive times.
+#[derive(Eq, PartialEq, Clone, Copy, Default, Debug)]
 pub struct HmsTime {
     pub hour: u8,
     pub minute: u8,
@@ -9,20 +14,20 @@ pub struct HmsTime {
 }
 
 /// A specific hour and minute (4.2.2.3a)
-#[derive(Eq, PartialEq, Clone, Debug)]
+#[derive(Eq, PartialEq, Clone, Copy, Default, Debug)]
 pub struct HmTime {
     pub hour: u8,
     pub minute: u8
 }
 
 /// A specific hour (4.2.2.3b)
-#[derive(Eq, PartialEq, Clone, Debug)]
+#[derive(Eq, PartialEq, Clone, Copy, Default, Debug)]
 pub struct HTime {
     pub hour: u8
 }
 
 /// Local time with decimal fraction (4.2.2.4)
-#[derive(PartialEq, Clone, Debug)]
+#[derive(PartialEq, Clone, Copy, Debug)]
 pub struct LocalTime<N = HmsTime>
 where N: NaiveTime {
     pub naive: N,
@@ -30,7 +35,7 @@ where N: NaiveTime {
 }
 
 /// Local time with timezone (4.2.4)
-#[derive(PartialEq, Clone, Debug)]
+#[derive(PartialEq, Clone, Copy, Debug)]
 pub struct GlobalTime<N = HmsTime>
 where N: NaiveTime {
     pub local: LocalTime<N>,
@@ -38,71 +43,310 @@ where N: NaiveTime {
     pub timezone: i16
 }
 
-#[derive(PartialEq, Clone, Debug)]
+/// Difference from UTC in seconds,
+/// as found in historical timezone data like `+01:23:45`.
+///
+/// Global times only hold whole minutes,
+/// which this converts from and, if exact, into.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Default, Debug)]
+pub struct TimeZoneOffset {
+    pub seconds: i32
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
 pub enum AnyTime<N = HmsTime>
 where N: NaiveTime {
     Global(GlobalTime<N>),
     Local(LocalTime<N>)
 }
 
+const NANOS_PER_SEC: u64 = 1_000_000_000;
+const NANOS_PER_DAY: u64 = 86_400 * NANOS_PER_SEC;
+
+/// The fraction of a second for nanoseconds below 1e9,
+/// kept below 1 where `f32` would round the last nanoseconds up to a whole second.
+pub(crate) fn fraction_from_nanos(nanos: u32) -> f32 {
+    (nanos as f32 / 1_000_000_000.).min(1. - f32::EPSILON / 2.)
+}
+
+/// How times of day that do not fit into a day of 86 400 seconds
+/// are counted from midnight.
+#[derive(Eq, PartialEq, Clone, Copy, Default, Debug)]
+pub enum DayEndPolicy {
+    /// 24:00:00 is 86 400 seconds, the end of the day (4.2.3),
+    /// and a leap second counts as the first second of the next day,
+    /// as [`CompleteTimelike::nanos_of_day`] does.
+    #[default]
+    Midnight24,
+    /// The day has a leap second, so 23:59:60 is 86 400 seconds
+    /// and 24:00:00 is 86 401 seconds.
+    LeapSecond,
+    /// Counts stay below 86 400 seconds, as in timecodes:
+    /// 24:00:00 is 0 seconds and a leap second repeats 23:59:59.
+    Wrap
+}
+
 pub trait NaiveTime {}
 
 impl NaiveTime for HmsTime {}
 impl NaiveTime for HmTime {}
 impl NaiveTime for HTime {}
 
+/// Midnight at the start of the day.
+impl<N> Default for LocalTime<N>
+where N: NaiveTime + Default {
+    fn default() -> Self {
+        Self {
+            naive: N::default(),
+            fraction: 0.
+        }
+    }
+}
+
+/// Midnight at the start of the day in UTC.
+impl<N> Default for GlobalTime<N>
+where N: NaiveTime + Default {
+    fn default() -> Self {
+        Self {
+            local: LocalTime::default(),
+            timezone: 0
+        }
+    }
+}
+
 impl LocalTime<HmsTime> {
+    /// 00:00:00, the start of the day.
+    pub const MIDNIGHT: Self = LocalTime {
+        naive: HmsTime { hour: 0, minute: 0, second: 0 },
+        fraction: 0.
+    };
+
     pub fn nanosecond(&self) -> u32 {
-        (self.fraction * 1_000_000_000.) as u32
+        CompleteTimelike::nanosecond(self)
+    }
+
+    /// See [`CompleteTimelike::seconds_of_day`].
+    pub fn seconds_of_day(&self) -> u32 {
+        CompleteTimelike::seconds_of_day(self)
+    }
+
+    /// See [`CompleteTimelike::nanos_of_day`].
+    pub fn nanos_of_day(&self) -> u64 {
+        CompleteTimelike::nanos_of_day(self)
+    }
+
+    pub fn nanos_of_day_with(&self, policy: DayEndPolicy) -> u64 {
+        let nanos = self.nanos_of_day();
+        match policy {
+            DayEndPolicy::Midnight24 => nanos,
+            DayEndPolicy::LeapSecond if self.naive.hour == 24 => nanos + NANOS_PER_SEC,
+            DayEndPolicy::LeapSecond => nanos,
+            DayEndPolicy::Wrap if self.naive.second == 60 => nanos - NANOS_PER_SEC,
+            DayEndPolicy::Wrap => nanos % NANOS_PER_DAY
+        }
+    }
+
+    /// `None` if the nanoseconds reach past the day,
+    /// see [`from_nanos_of_day_with`](Self::from_nanos_of_day_with).
+    pub fn from_nanos_of_day(nanos: u64) -> Option<Self> {
+        Self::from_nanos_of_day_with(nanos, DayEndPolicy::default())
+    }
+
+    /// The inverse of [`nanos_of_day_with`](Self::nanos_of_day_with),
+    /// except that [`DayEndPolicy::Wrap`] wraps around the day
+    /// and does not give leap seconds.
+    ///
+    /// `None` if the nanoseconds reach past the day.
+    pub fn from_nanos_of_day_with(nanos: u64, policy: DayEndPolicy) -> Option<Self> {
+        let midnight24 = LocalTime {
+            naive: HmsTime { hour: 24, minute: 0, second: 0 },
+            fraction: 0.
+        };
+        let leap_second = |fraction| LocalTime {
+            naive: HmsTime { hour: 23, minute: 59, second: 60 },
+            fraction
+        };
+        let fraction = fraction_from_nanos((nanos % NANOS_PER_SEC) as u32);
+
+        match policy {
+            DayEndPolicy::Midnight24 if nanos == NANOS_PER_DAY =>
+                Some(midnight24),
+            DayEndPolicy::LeapSecond if (NANOS_PER_DAY..NANOS_PER_DAY + NANOS_PER_SEC).contains(&nanos) =>
+                Some(leap_second(fraction)),
+            DayEndPolicy::LeapSecond if nanos == NANOS_PER_DAY + NANOS_PER_SEC =>
+                Some(midnight24),
+            DayEndPolicy::Wrap => Self::from_nanos_of_day_with(nanos % NANOS_PER_DAY, DayEndPolicy::Midnight24),
+            _ if nanos < NANOS_PER_DAY => Some(LocalTime {
+                fraction,
+                ..LocalTime::from_seconds_of_day_overflowing(nanos / NANOS_PER_SEC).0
+            }),
+            _ => None
+        }
+    }
+
+    /// The time of day after the given seconds since midnight,
+    /// along with the number of whole days they carry over.
+    pub fn from_seconds_of_day_overflowing(seconds: u64) -> (Self, u64) {
+        let (days, seconds) = (seconds / 86_400, seconds % 86_400);
+        (
+            LocalTime {
+                naive: HmsTime {
+                    hour:   (seconds / 3600)    as u8,
+                    minute: (seconds / 60 % 60) as u8,
+                    second: (seconds % 60)      as u8
+                },
+                fraction: 0.
+            },
+            days
+        )
+    }
+}
+
+impl GlobalTime<HmsTime> {
+    /// 00:00:00Z, the start of the day in UTC.
+    pub const MIDNIGHT_UTC: Self = GlobalTime {
+        local: LocalTime::MIDNIGHT,
+        timezone: 0
+    };
+
+    /// A time of day in UTC.
+    pub fn utc(hour: u8, minute: u8, second: u8) -> Self {
+        Self::with_offset_hm(hour, minute, second, 0, 0)
+    }
+
+    /// A time of day with the given offset from UTC.
+    ///
+    /// Both offset components carry the sign,
+    /// so `-5, -30` is `-05:30`.
+    pub fn with_offset_hm(hour: u8, minute: u8, second: u8, offset_hour: i8, offset_minute: i8) -> Self {
+        Self {
+            local: LocalTime {
+                naive: HmsTime { hour, minute, second },
+                fraction: 0.
+            },
+            timezone: i16::from(offset_hour) * 60 + i16::from(offset_minute)
+        }
+    }
+}
+
+impl<N> GlobalTime<N>
+where N: NaiveTime {
+    /// The whole hours of the offset from UTC, carrying its sign.
+    pub fn offset_hours(&self) -> i16 {
+        self.timezone / 60
+    }
+
+    /// The minutes of the offset from UTC beyond its whole hours, carrying its sign.
+    pub fn offset_minutes(&self) -> i16 {
+        self.timezone % 60
+    }
+}
+
+impl TimeZoneOffset {
+    pub fn from_minutes(minutes: i16) -> Self {
+        Self { seconds: i32::from(minutes) * 60 }
+    }
+
+    /// The offset in whole minutes if it has no seconds.
+    pub fn minutes(&self) -> Option<i16> {
+        if self.seconds % 60 == 0 {
+            i16::try_from(self.seconds / 60).ok()
+        } else {
+            None
+        }
+    }
+
+    /// Rounds to the nearest multiple of 15 minutes,
+    /// halfway away from zero.
+    pub fn quantize_to_quarter_hour(&self) -> Self {
+        const QUARTER_HOUR: i32 = 15 * 60;
+        Self {
+            seconds: self.seconds.signum() * (
+                (self.seconds.abs() + QUARTER_HOUR / 2) / QUARTER_HOUR * QUARTER_HOUR
+            )
+        }
+    }
+
+    /// Whether some time zone currently uses this offset,
+    /// in standard or daylight saving time,
+    /// which is any whole hour from `-12:00` to `+14:00`
+    /// or one of a few others like `+05:30` and `+05:45`.
+    ///
+    /// Meant to flag improbable offsets like `+03:17`, which still are valid.
+    pub fn is_standard_offset(&self) -> bool {
+        const OTHER_MINUTES: [i16; 13] = [
+            -9 * 60 - 30, -3 * 60 - 30, -2 * 60 - 30,
+            3 * 60 + 30, 4 * 60 + 30, 5 * 60 + 30, 5 * 60 + 45, 6 * 60 + 30,
+            8 * 60 + 45, 9 * 60 + 30, 10 * 60 + 30, 12 * 60 + 45, 13 * 60 + 45
+        ];
+        match self.minutes() {
+            Some(minutes) if minutes % 60 == 0 => (-12..=14).contains(&(minutes / 60)),
+            Some(minutes) => OTHER_MINUTES.contains(&minutes),
+            None => false
+        }
+    }
+}
+
+impl From<i16> for TimeZoneOffset {
+    fn from(minutes: i16) -> Self {
+        Self::from_minutes(minutes)
+    }
+}
+
+impl TryFrom<TimeZoneOffset> for i16 {
+    type Error = ();
+
+    fn try_from(offset: TimeZoneOffset) -> Result<Self, Self::Error> {
+        offset.minutes().ok_or(())
     }
 }
 
 impl LocalTime<HmTime>{
     pub fn second(&self) -> u8 {
-        (self.fraction * 60.) as u8
+        CompleteTimelike::second(self)
     }
 
     pub fn nanosecond(&self) -> u32 {
-        (self.fraction * 1_000_000_000.) as u32 % 1_000_000_000
+        CompleteTimelike::nanosecond(self)
     }
 }
 
 impl LocalTime<HTime> {
     pub fn minute(&self) -> u8 {
-        (self.fraction * 60.) as u8
+        CompleteTimelike::minute(self)
     }
 
     pub fn second(&self) -> u8 {
-        (self.fraction * 60.) as u8 % 60
+        CompleteTimelike::second(self)
     }
 
     pub fn nanosecond(&self) -> u32 {
-        (self.fraction * 1_000_000_000.) as u32 % 1_000_000_000
+        CompleteTimelike::nanosecond(self)
     }
 }
 
-#[derive(Eq, PartialEq, Clone, Debug)]
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
 pub enum ApproxNaiveTime {
     HMS(HmsTime),
     HM (HmTime),
     H  (HTime)
 }
 
-#[derive(PartialEq, Clone, Debug)]
+#[derive(PartialEq, Clone, Copy, Debug)]
 pub enum ApproxLocalTime {
     HMS(LocalTime<HmsTime>),
     HM (LocalTime<HmTime>),
     H  (LocalTime<HTime>),
 }
 
-#[derive(PartialEq, Clone, Debug)]
+#[derive(PartialEq, Clone, Copy, Debug)]
 pub enum ApproxGlobalTime {
     HMS(GlobalTime<HmsTime>),
     HM (GlobalTime<HmTime>),
     H  (GlobalTime<HTime>)
 }
 
-#[derive(PartialEq, Clone, Debug)]
+#[derive(PartialEq, Clone, Copy, Debug)]
 pub enum ApproxAnyTime {
     HMS(AnyTime<HmsTime>),
     HM (AnyTime<HmTime>),
@@ -119,6 +363,177 @@ impl Timelike for ApproxLocalTime {}
 impl Timelike for ApproxGlobalTime {}
 impl Timelike for ApproxAnyTime {}
 
+/// Accessors common to all time representations.
+///
+/// Components of lower order than the representation
+/// are derived from its decimal fraction.
+/// Global times give their local time of day.
+pub trait CompleteTimelike {
+    fn hour(&self) -> u8;
+    fn minute(&self) -> u8;
+    fn second(&self) -> u8;
+    fn nanosecond(&self) -> u32;
+
+    /// A leap second counts as the first second of the next day.
+    fn seconds_of_day(&self) -> u32 {
+        (u32::from(self.hour()) * 60 + u32::from(self.minute())) * 60 + u32::from(self.second())
+    }
+
+    /// A leap second counts as the first second of the next day.
+    fn nanos_of_day(&self) -> u64 {
+        u64::from(self.seconds_of_day()) * 1_000_000_000 + u64::from(self.nanosecond())
+    }
+}
+
+/// Splits a decimal fraction of a unit of the given seconds
+/// into whole seconds and nanoseconds.
+fn split_fraction(fraction: f32, unit: u16) -> (u16, u32) {
+    let secs = fraction * f32::from(unit);
+    let whole = secs.trunc();
+    (whole as u16, ((secs - whole) * 1_000_000_000.) as u32)
+}
+
+impl CompleteTimelike for HmsTime {
+    fn hour      (&self) -> u8  { self.hour }
+    fn minute    (&self) -> u8  { self.minute }
+    fn second    (&self) -> u8  { self.second }
+    fn nanosecond(&self) -> u32 { 0 }
+}
+
+impl CompleteTimelike for HmTime {
+    fn hour      (&self) -> u8  { self.hour }
+    fn minute    (&self) -> u8  { self.minute }
+    fn second    (&self) -> u8  { 0 }
+    fn nanosecond(&self) -> u32 { 0 }
+}
+
+impl CompleteTimelike for HTime {
+    fn hour      (&self) -> u8  { self.hour }
+    fn minute    (&self) -> u8  { 0 }
+    fn second    (&self) -> u8  { 0 }
+    fn nanosecond(&self) -> u32 { 0 }
+}
+
+impl CompleteTimelike for LocalTime<HmsTime> {
+    fn hour  (&self) -> u8 { self.naive.hour }
+    fn minute(&self) -> u8 { self.naive.minute }
+    fn second(&self) -> u8 { self.naive.second }
+
+    fn nanosecond(&self) -> u32 {
+        split_fraction(self.fraction, 1).1
+    }
+}
+
+impl CompleteTimelike for LocalTime<HmTime> {
+    fn hour  (&self) -> u8 { self.naive.hour }
+    fn minute(&self) -> u8 { self.naive.minute }
+
+    fn second(&self) -> u8 {
+        split_fraction(self.fraction, 60).0 as u8
+    }
+
+    fn nanosecond(&self) -> u32 {
+        split_fraction(self.fraction, 60).1
+    }
+}
+
+impl CompleteTimelike for LocalTime<HTime> {
+    fn hour(&self) -> u8 { self.naive.hour }
+
+    fn minute(&self) -> u8 {
+        (split_fraction(self.fraction, 60 * 60).0 / 60) as u8
+    }
+
+    fn second(&self) -> u8 {
+        (split_fraction(self.fraction, 60 * 60).0 % 60) as u8
+    }
+
+    fn nanosecond(&self) -> u32 {
+        split_fraction(self.fraction, 60 * 60).1
+    }
+}
+
+impl<N> CompleteTimelike for GlobalTime<N> where
+    N: NaiveTime,
+    LocalTime<N>: CompleteTimelike
+{
+    fn hour      (&self) -> u8  { self.local.hour() }
+    fn minute    (&self) -> u8  { self.local.minute() }
+    fn second    (&self) -> u8  { self.local.second() }
+    fn nanosecond(&self) -> u32 { self.local.nanosecond() }
+}
+
+macro_rules! impl_complete_timelike_delegate {
+    ($ty:ty, $self:ident => $($pat:pat => $time:expr),+) => {
+        impl CompleteTimelike for $ty {
+            fn hour(&$self) -> u8 {
+                match $self { $($pat => $time.hour()),+ }
+            }
+
+            fn minute(&$self) -> u8 {
+                match $self { $($pat => $time.minute()),+ }
+            }
+
+            fn second(&$self) -> u8 {
+                match $self { $($pat => $time.second()),+ }
+            }
+
+            fn nanosecond(&$self) -> u32 {
+                match $self { $($pat => $time.nanosecond()),+ }
+            }
+        }
+    }
+}
+
+impl<N> CompleteTimelike for AnyTime<N> where
+    N: NaiveTime,
+    LocalTime<N>: CompleteTimelike
+{
+    fn hour(&self) -> u8 {
+        match self {
+            AnyTime::Global(time) => time.hour(),
+            AnyTime::Local (time) => time.hour()
+        }
+    }
+
+    fn minute(&self) -> u8 {
+        match self {
+            AnyTime::Global(time) => time.minute(),
+            AnyTime::Local (time) => time.minute()
+        }
+    }
+
+    fn second(&self) -> u8 {
+        match self {
+            AnyTime::Global(time) => time.second(),
+            AnyTime::Local (time) => time.second()
+        }
+    }
+
+    fn nanosecond(&self) -> u32 {
+        match self {
+            AnyTime::Global(time) => time.nanosecond(),
+            AnyTime::Local (time) => time.nanosecond()
+        }
+    }
+}
+
+impl_complete_timelike_delegate!(ApproxLocalTime, self =>
+    ApproxLocalTime::HMS(time) => time,
+    ApproxLocalTime::HM (time) => time,
+    ApproxLocalTime::H  (time) => time
+);
+impl_complete_timelike_delegate!(ApproxGlobalTime, self =>
+    ApproxGlobalTime::HMS(time) => time,
+    ApproxGlobalTime::HM (time) => time,
+    ApproxGlobalTime::H  (time) => time
+);
+impl_complete_timelike_delegate!(ApproxAnyTime, self =>
+    ApproxAnyTime::HMS(time) => time,
+    ApproxAnyTime::HM (time) => time,
+    ApproxAnyTime::H  (time) => time
+);
+
 impl_fromstr_parse!(GlobalTime<HmsTime>, time_global_hms);
 impl_fromstr_parse!(GlobalTime<HmTime>,  time_global_hm);
 impl_fromstr_parse!(GlobalTime<HTime>,   time_global_h);
@@ -131,19 +546,20 @@ impl_fromstr_parse!(AnyTime<HTime>,      time_any_h);
 impl_fromstr_parse!(ApproxGlobalTime,    time_global_approx);
 impl_fromstr_parse!(ApproxLocalTime,     time_local_approx);
 impl_fromstr_parse!(ApproxAnyTime,       time_any_approx);
+impl_fromstr_parse!(TimeZoneOffset,      time_zone_offset);
 
 impl Valid for HmsTime {
     /// Accepts leap seconds on any day
     /// since they are not predictable.
     fn is_valid(&self) -> bool {
-        HmTime::from(self.clone()).is_valid() &&
+        HmTime::from(*self).is_valid() &&
         self.second <= 60
     }
 }
 
 impl Valid for HmTime {
     fn is_valid(&self) -> bool {
-        HTime::from(self.clone()).is_valid() &&
+        HTime::from(*self).is_valid() &&
         self.minute <= 59
     }
 }
@@ -171,6 +587,13 @@ where N: NaiveTime + Valid {
     }
 }
 
+impl Valid for TimeZoneOffset {
+    fn is_valid(&self) -> bool {
+        self.seconds > -24 * 3600 &&
+        self.seconds <  24 * 3600
+    }
+}
+
 impl<N> Valid for AnyTime<N>
 where N: NaiveTime + Valid {
     fn is_valid(&self) -> bool {
@@ -310,10 +733,185 @@ impl From<AnyTime<HmTime>> for AnyTime<HTime> {
     }
 }
 
+/// From hour, minute, and second, if valid.
+impl TryFrom<(u8, u8, u8)> for HmsTime {
+    type Error = ();
+
+    fn try_from((hour, minute, second): (u8, u8, u8)) -> Result<Self, Self::Error> {
+        let time = Self { hour, minute, second };
+        if time.is_valid() { Ok(time) } else { Err(()) }
+    }
+}
+
+/// From hour, minute, second, and nanosecond, if valid.
+impl TryFrom<(u8, u8, u8, u32)> for LocalTime {
+    type Error = ();
+
+    fn try_from((hour, minute, second, nanosecond): (u8, u8, u8, u32)) -> Result<Self, Self::Error> {
+        if nanosecond >= 1_000_000_000 {
+            return Err(());
+        }
+
+        Ok(Self {
+            naive: HmsTime::try_from((hour, minute, second))?,
+            fraction: fraction_from_nanos(nanosecond)
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn try_from_tuple() {
+        assert_eq!(HmsTime::try_from((13, 42, 2)), Ok(HmsTime { hour: 13, minute: 42, second: 2 }));
+        assert_eq!(HmsTime::try_from((13, 60, 2)), Err(()));
+        assert_eq!(LocalTime::try_from((13, 42, 2, 500_000_000)), Ok(LocalTime {
+            naive: HmsTime { hour: 13, minute: 42, second: 2 },
+            fraction: 0.5
+        }));
+        assert_eq!(LocalTime::try_from((13, 42, 2, 1_000_000_000)), Err(()));
+
+        let last = LocalTime::try_from((0, 0, 0, 999_999_999)).unwrap();
+        assert!(last.is_valid());
+        assert_eq!(last.naive, HmsTime { hour: 0, minute: 0, second: 0 });
+    }
+
+    #[test]
+    fn nanos_of_day() {
+        let time = |hour, minute, second, fraction| LocalTime { naive: HmsTime { hour, minute, second }, fraction };
+        let policies = [DayEndPolicy::Midnight24, DayEndPolicy::LeapSecond, DayEndPolicy::Wrap];
+        let nanos = |t: LocalTime| policies.iter().map(|&p| t.nanos_of_day_with(p)).collect::<Vec<_>>();
+
+        let afternoon = time(13, 42, 2, 0.5);
+        assert_eq!(afternoon.seconds_of_day(), 49_322);
+        assert_eq!(nanos(afternoon), [49_322_500_000_000; 3]);
+        assert_eq!(nanos(time(24, 0, 0, 0.)), [86_400_000_000_000, 86_401_000_000_000, 0]);
+        assert_eq!(nanos(time(23, 59, 60, 0.5)), [86_400_500_000_000, 86_400_500_000_000, 86_399_500_000_000]);
+
+        for &t in &[afternoon, time(0, 0, 0, 0.), time(24, 0, 0, 0.), time(23, 59, 60, 0.25)] {
+            for &p in &policies[..2] {
+                let back = LocalTime::from_nanos_of_day_with(t.nanos_of_day_with(p), p);
+                if p == DayEndPolicy::Midnight24 && t.naive.second == 60 {
+                    assert_eq!(back, None);
+                } else {
+                    assert_eq!(back, Some(t));
+                }
+            }
+        }
+
+        assert_eq!(LocalTime::from_nanos_of_day(49_322_500_000_000), Some(afternoon));
+        assert_eq!(LocalTime::from_nanos_of_day(86_400_000_000_001), None);
+        assert_eq!(LocalTime::from_nanos_of_day_with(86_401_000_000_001, DayEndPolicy::LeapSecond), None);
+        assert_eq!(LocalTime::from_nanos_of_day_with(86_400_000_000_000 + 49_322_500_000_000, DayEndPolicy::Wrap), Some(afternoon));
+
+        let last = LocalTime::from_nanos_of_day(86_399_999_999_999).unwrap();
+        assert!(last.is_valid());
+        assert_eq!(last.naive, HmsTime { hour: 23, minute: 59, second: 59 });
+        let leap = LocalTime::from_nanos_of_day_with(86_400_999_999_999, DayEndPolicy::LeapSecond).unwrap();
+        assert!(leap.is_valid());
+        assert_eq!(leap.naive.second, 60);
+    }
+
+    #[test]
+    fn from_seconds_of_day_overflowing() {
+        assert_eq!(LocalTime::from_seconds_of_day_overflowing(49_322), (LocalTime {
+            naive: HmsTime { hour: 13, minute: 42, second: 2 },
+            fraction: 0.
+        }, 0));
+        assert_eq!(LocalTime::from_seconds_of_day_overflowing(2 * 86_400 + 59), (LocalTime {
+            naive: HmsTime { hour: 0, minute: 0, second: 59 },
+            fraction: 0.
+        }, 2));
+    }
+
+    #[test]
+    fn with_offset_hm() {
+        let time = GlobalTime::with_offset_hm(13, 42, 2, -5, -30);
+        assert_eq!(time.timezone, -(5 * 60 + 30));
+        assert_eq!((time.offset_hours(), time.offset_minutes()), (-5, -30));
+        assert_eq!(GlobalTime::utc(13, 42, 2), GlobalTime {
+            local: LocalTime {
+                naive: HmsTime { hour: 13, minute: 42, second: 2 },
+                fraction: 0.
+            },
+            timezone: 0
+        });
+    }
+
+    #[test]
+    fn time_zone_offset() {
+        let offset = TimeZoneOffset { seconds: -(3600 + 23 * 60 + 45) };
+        assert_eq!(offset.minutes(), None);
+        assert_eq!(i16::try_from(offset), Err(()));
+        assert_eq!(TimeZoneOffset::from(-90).minutes(), Some(-90));
+        assert!(offset.is_valid());
+        assert!(!TimeZoneOffset { seconds: 24 * 3600 }.is_valid());
+
+        let minutes = TimeZoneOffset::from_minutes;
+        assert_eq!(minutes(3 * 60 + 17).quantize_to_quarter_hour(), minutes(3 * 60 + 15));
+        assert_eq!(minutes(-(5 * 60 + 38)).quantize_to_quarter_hour(), minutes(-(5 * 60 + 45)));
+        assert_eq!(TimeZoneOffset { seconds: 450 }.quantize_to_quarter_hour(), minutes(15));
+        assert_eq!(TimeZoneOffset { seconds: -449 }.quantize_to_quarter_hour(), minutes(0));
+        assert!(minutes(5 * 60 + 45).is_standard_offset());
+        assert!(minutes(-12 * 60).is_standard_offset());
+        assert!(minutes(-(3 * 60 + 30)).is_standard_offset());
+        assert!(!minutes(3 * 60 + 17).is_standard_offset());
+        assert!(!minutes(15 * 60).is_standard_offset());
+        assert!(!minutes(-(5 * 60 + 30)).is_standard_offset());
+        assert!(!offset.is_standard_offset());
+
+        let parse = |s: &str| s.parse::<TimeZoneOffset>().map(|x| x.seconds);
+        assert_eq!(parse("Z"),      Ok(0));
+        assert_eq!(parse("+05:30"), Ok(5 * 3600 + 30 * 60));
+        assert_eq!(parse("-0700"),  Ok(-7 * 3600));
+        assert_eq!(parse("+05"),    Ok(5 * 3600));
+        assert_eq!(parse("05:30"),  Err(()));
+    }
+
+    #[test]
+    fn complete_timelike() {
+        let time = LocalTime {
+            naive: HmsTime {
+                hour: 13,
+                minute: 42,
+                second: 2
+            },
+            fraction: 0.5
+        };
+        assert_eq!(time.hour(), 13);
+        assert_eq!(time.minute(), 42);
+        assert_eq!(time.second(), 2);
+        assert_eq!(time.nanosecond(), 500_000_000);
+        assert_eq!(time.seconds_of_day(), 49_322);
+        assert_eq!(time.nanos_of_day(), 49_322_500_000_000);
+
+        let time = LocalTime {
+            naive: HmTime {
+                hour: 13,
+                minute: 42
+            },
+            fraction: 0.5
+        };
+        assert_eq!(time.second(), 30);
+        assert_eq!(time.nanosecond(), 0);
+
+        let time = ApproxAnyTime::H(AnyTime::Global(GlobalTime {
+            local: LocalTime {
+                naive: HTime {
+                    hour: 13
+                },
+                fraction: 0.7
+            },
+            timezone: 60
+        }));
+        assert_eq!(time.hour(), 13);
+        assert_eq!(time.minute(), 42);
+        assert_eq!(time.second(), 0);
+        assert_eq!(time.seconds_of_day(), 49_320);
+    }
+
     #[test]
     fn valid_time_hms() {
         assert!(HmsTime {
@@ -420,7 +1018,7 @@ mod tests {
             },
             fraction: 0.
         };
-        assert!(!AnyTime::Local(local.clone()).is_valid());
+        assert!(!AnyTime::Local(local).is_valid());
         assert!(!AnyTime::Global(GlobalTime {
             local,
             timezone: 0
@@ -0,0 +1,172 @@
+#![cfg(feature = "timescales")]
+
+//! Time scales other than UTC that representations may count in,
+//! as in satellite navigation and telemetry.
+//!
+//! TAI runs ahead of UTC by the leap seconds inserted so far,
+//! GPS time runs behind TAI by the 19 seconds it had at its epoch in 1980.
+
+use {
+    date::*,
+    time::*,
+    datetime::*,
+    std::{
+        convert::TryFrom,
+        fmt,
+        ops::Deref
+    }
+};
+
+const NANOS_PER_SEC: i128 = 1_000_000_000;
+
+/// Seconds that GPS time runs behind TAI.
+const GPS_BEHIND_TAI: i128 = 19;
+
+/// Year and month from the first day of which
+/// TAI ran ahead of UTC by the given seconds.
+///
+/// There is no scheduled leap second after 2016-12-31,
+/// so this holds at least until the end of 2026.
+const LEAP_SECONDS: [(i64, u8, i128); 28] = [
+    (1972, 1, 10), (1972, 7, 11), (1973, 1, 12), (1974, 1, 13),
+    (1975, 1, 14), (1976, 1, 15), (1977, 1, 16), (1978, 1, 17),
+    (1979, 1, 18), (1980, 1, 19), (1981, 7, 20), (1982, 7, 21),
+    (1983, 7, 22), (1985, 7, 23), (1988, 1, 24), (1990, 1, 25),
+    (1991, 1, 26), (1992, 7, 27), (1993, 7, 28), (1994, 7, 29),
+    (1996, 1, 30), (1997, 7, 31), (1999, 1, 32), (2006, 1, 33),
+    (2009, 1, 34), (2012, 7, 35), (2015, 7, 36), (2017, 1, 37)
+];
+
+/// The scale that a datetime counts in.
+#[derive(Eq, PartialEq, Clone, Copy, Default, Debug)]
+pub enum TimeScale {
+    /// Coordinated Universal Time, which the standard assumes
+    #[default]
+    Utc,
+    /// International Atomic Time
+    Tai,
+    /// GPS time
+    Gps
+}
+
+impl fmt::Display for TimeScale {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            TimeScale::Utc => "UTC",
+            TimeScale::Tai => "TAI",
+            TimeScale::Gps => "GPS"
+        })
+    }
+}
+
+/// A datetime together with the time scale it counts in.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Scaled<T = DateTime> {
+    pub value: T,
+    pub scale: TimeScale
+}
+
+impl Scaled {
+    /// The same instant in another time scale, written without offset.
+    ///
+    /// `None` before 1972, when UTC was not yet offset from TAI by whole seconds,
+    /// or if the year does not fit.
+    pub fn to_scale(&self, scale: TimeScale) -> Option<Self> {
+        let value = from_tai(to_tai(self)?, scale)?;
+        Some(Scaled { value, scale })
+    }
+}
+
+impl<T> Deref for Scaled<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+/// Seconds since the Unix epoch in UTC at which each entry of [`LEAP_SECONDS`] begins.
+fn leap_second_starts() -> impl DoubleEndedIterator<Item = (i128, i128)> {
+    LEAP_SECONDS.iter().map(|&(year, month, offset)|
+        (i128::from(days_from_civil(year, month, 1)) * 86_400, offset))
+}
+
+/// Nanoseconds since 1970-01-01T00:00:00 TAI.
+fn to_tai(datetime: &Scaled) -> Option<i128> {
+    let key = datetime.value.sort_key()?;
+    match datetime.scale {
+        TimeScale::Tai => Some(key),
+        TimeScale::Gps => Some(key + GPS_BEHIND_TAI * NANOS_PER_SEC),
+        TimeScale::Utc => {
+            // a leap second has the key of the second after it
+            let key_before = if datetime.value.time.second() == 60 { key - NANOS_PER_SEC } else { key };
+            let (_, offset) = leap_second_starts().rev().find(|&(start, _)| key_before >= start * NANOS_PER_SEC)?;
+            Some(key + offset * NANOS_PER_SEC)
+        }
+    }
+}
+
+fn from_tai(tai: i128, scale: TimeScale) -> Option<DateTime> {
+    let from_nanos = |nanos: i128| DateTime::checked_from_unix(
+        i64::try_from(nanos.div_euclid(NANOS_PER_SEC)).ok()?,
+        nanos.rem_euclid(NANOS_PER_SEC) as u32
+    );
+
+    match scale {
+        TimeScale::Tai => from_nanos(tai),
+        TimeScale::Gps => from_nanos(tai - GPS_BEHIND_TAI * NANOS_PER_SEC),
+        TimeScale::Utc => {
+            let starts: Vec<_> = leap_second_starts().collect();
+            let i = starts.iter().rposition(|&(start, offset)| tai >= (start + offset) * NANOS_PER_SEC)?;
+            let utc = tai - starts[i].1 * NANOS_PER_SEC;
+            match starts.get(i + 1) {
+                // within the second inserted before the next entry
+                Some(&(next, _)) if utc >= next * NANOS_PER_SEC => {
+                    let mut datetime = from_nanos(utc - NANOS_PER_SEC)?;
+                    datetime.time.local.naive.second = 60;
+                    Some(datetime)
+                }
+                _ => from_nanos(utc)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dt(s: &str) -> DateTime {
+        let x: DateTime<Date> = s.parse().unwrap();
+        DateTime { date: x.date.into(), time: x.time }
+    }
+
+    fn utc(s: &str) -> Scaled {
+        Scaled { value: dt(s), scale: TimeScale::Utc }
+    }
+
+    #[test]
+    fn to_scale() {
+        let tai = utc("2017-01-01T00:00:00Z").to_scale(TimeScale::Tai).unwrap();
+        assert_eq!(tai, Scaled { value: dt("2017-01-01T00:00:37Z"), scale: TimeScale::Tai });
+        assert_eq!(tai.to_scale(TimeScale::Utc).unwrap(), utc("2017-01-01T00:00:00Z"));
+
+        assert_eq!(utc("2017-01-01T01:00:00+01:00").to_scale(TimeScale::Gps).unwrap().value, dt("2017-01-01T00:00:18Z"));
+        assert_eq!(utc("2016-12-31T23:59:59Z").to_scale(TimeScale::Tai).unwrap().value, dt("2017-01-01T00:00:35Z"));
+        assert_eq!(
+            Scaled { value: dt("1980-01-06T00:00:00Z"), scale: TimeScale::Gps }.to_scale(TimeScale::Utc).unwrap().value,
+            dt("1980-01-06T00:00:00Z")
+        );
+
+        assert_eq!(utc("1971-12-31T23:59:59Z").to_scale(TimeScale::Tai), None);
+    }
+
+    #[test]
+    fn leap_second() {
+        let leap = utc("2016-12-31T23:59:60.5Z");
+        let tai = leap.to_scale(TimeScale::Tai).unwrap();
+        assert_eq!(tai.value, dt("2017-01-01T00:00:36.5Z"));
+        assert_eq!(tai.to_scale(TimeScale::Utc).unwrap(), leap);
+        assert_eq!(tai.value.time.second(), 36);
+    }
+}
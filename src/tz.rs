@@ -0,0 +1,107 @@
+#![cfg(feature = "tz")]
+
+//! Wall-clock arithmetic in named time zones.
+
+extern crate chrono;
+extern crate chrono_tz;
+
+use {
+    datetime::*,
+    std::convert::TryFrom
+};
+use self::chrono::{
+    LocalResult,
+    NaiveDateTime,
+    Offset,
+    TimeZone
+};
+pub use self::chrono_tz::Tz;
+
+/// How to resolve a wall-clock time
+/// that a daylight saving transition skips or repeats.
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub enum DstPolicy {
+    /// Give up on times that do not exist or are ambiguous.
+    Skip,
+    /// Take the earlier of two repeated times.
+    /// A skipped time is read with the offset after the transition,
+    /// which puts it before the gap by the length of the gap.
+    Earliest,
+    /// Take the later of two repeated times.
+    /// A skipped time is read with the offset before the transition,
+    /// which puts it after the gap by the length of the gap.
+    Latest
+}
+
+impl DateTime {
+    /// The same wall-clock time on the next day in the zone,
+    /// which is not always 24 hours later.
+    ///
+    /// `None` if the policy skips the time,
+    /// the offset in the zone is not in whole minutes
+    /// or either date and time is out of range.
+    pub fn same_local_time_next_day(&self, zone: Tz, policy: DstPolicy) -> Option<Self> {
+        let (secs, nanos) = self.into_unix()?;
+        let local = zone.timestamp_opt(secs, nanos).single()?.naive_local();
+        let next = local.date().succ_opt()?.and_time(local.time());
+
+        let instant = match zone.from_local_datetime(&next) {
+            LocalResult::Single(x) => x,
+            LocalResult::Ambiguous(earliest, latest) => match policy {
+                DstPolicy::Skip     => return None,
+                DstPolicy::Earliest => earliest,
+                DstPolicy::Latest   => latest
+            },
+            LocalResult::None => {
+                // transitions are more than a day apart
+                let offset = |local: NaiveDateTime| zone.offset_from_utc_datetime(&local).fix();
+                let offset = match policy {
+                    DstPolicy::Skip     => return None,
+                    DstPolicy::Earliest => offset(next + chrono::Duration::days(1)),
+                    DstPolicy::Latest   => offset(next - chrono::Duration::days(1))
+                };
+                zone.from_utc_datetime(&(next - offset))
+            }
+        };
+
+        let offset = instant.offset().fix().local_minus_utc();
+        if offset % 60 != 0 {
+            return None;
+        }
+        let utc = instant.timestamp();
+        let mut datetime = DateTime::checked_from_unix(utc + i64::from(offset), instant.timestamp_subsec_nanos())?;
+        datetime.time.timezone = i16::try_from(offset / 60).ok()?;
+        Some(datetime)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use self::chrono_tz::Europe::Berlin;
+
+    fn datetime(s: &str) -> DateTime {
+        let x: DateTime<::Date> = s.parse().unwrap();
+        DateTime {
+            date: x.date.into(),
+            time: x.time
+        }
+    }
+
+    #[test]
+    fn same_local_time_next_day() {
+        let next = |s, policy| datetime(s).same_local_time_next_day(Berlin, policy);
+
+        assert_eq!(next("2024-03-30T12:00:00+01:00", DstPolicy::Skip), Some(datetime("2024-03-31T12:00:00+02:00")));
+
+        // 02:30 does not exist on 2024-03-31
+        assert_eq!(next("2024-03-30T02:30:00+01:00", DstPolicy::Skip),     None);
+        assert_eq!(next("2024-03-30T02:30:00+01:00", DstPolicy::Earliest), Some(datetime("2024-03-31T01:30:00+01:00")));
+        assert_eq!(next("2024-03-30T02:30:00+01:00", DstPolicy::Latest),   Some(datetime("2024-03-31T03:30:00+02:00")));
+
+        // 02:30 happens twice on 2024-10-27
+        assert_eq!(next("2024-10-26T02:30:00+02:00", DstPolicy::Skip),     None);
+        assert_eq!(next("2024-10-26T02:30:00+02:00", DstPolicy::Earliest), Some(datetime("2024-10-27T02:30:00+02:00")));
+        assert_eq!(next("2024-10-26T02:30:00+02:00", DstPolicy::Latest),   Some(datetime("2024-10-27T02:30:00+01:00")));
+    }
+}
@@ -0,0 +1,152 @@
+use {
+    Valid,
+    date::*,
+    time::*,
+    datetime::*,
+    std::{
+        convert::TryFrom,
+        fmt,
+        str::FromStr
+    }
+};
+
+const NANOS_PER_SEC: i128 = 1_000_000_000;
+const NANOS_PER_MIN: i128 = 60 * NANOS_PER_SEC;
+const NANOS_PER_DAY: i128 = 86_400 * NANOS_PER_SEC;
+
+/// A valid datetime normalized to UTC when it is created,
+/// so that comparing and hashing are those of integers,
+/// as for keys of ordered maps.
+///
+/// A leap second at the end of a UTC day sorts between that day and the next.
+/// One that is not, which the standard does not rule out,
+/// is taken for the second after it.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Debug)]
+pub struct UtcDateTime {
+    /// Days since 1970-01-01
+    days: i64,
+    /// Nanoseconds since midnight, reaching past the day within a leap second
+    nanos: u64
+}
+
+impl UtcDateTime {
+    /// See [`DateTime::sort_key`].
+    pub fn sort_key(&self) -> i128 {
+        i128::from(self.days) * NANOS_PER_DAY + i128::from(self.nanos)
+    }
+}
+
+/// `Err` if the datetime is not valid
+/// or its date in UTC is out of [`YmdDate::MIN`] to [`YmdDate::MAX`].
+impl<D> TryFrom<DateTime<D, GlobalTime>> for UtcDateTime
+where D: Datelike + Valid + Into<YmdDate> {
+    type Error = ();
+
+    fn try_from(datetime: DateTime<D, GlobalTime>) -> Result<Self, Self::Error> {
+        if !datetime.is_valid() {
+            return Err(());
+        }
+
+        let date: YmdDate = datetime.date.into();
+        let local = datetime.time.local;
+        // a leap second is the second after the 59th of its minute
+        let leap = local.naive.second == 60;
+        let nanos =
+            i128::from(days_from_civil(date.year.into(), date.month, date.day)) * NANOS_PER_DAY +
+            i128::from(local.nanos_of_day()) -
+            i128::from(leap) * NANOS_PER_SEC -
+            i128::from(datetime.time.timezone) * NANOS_PER_MIN;
+
+        let days = nanos.div_euclid(NANOS_PER_DAY) as i64;
+        let first = days_from_civil(i16::MIN.into(), 1, 1);
+        let last = days_from_civil(i16::MAX.into(), 12, 31);
+        if !(first..=last).contains(&days) {
+            return Err(());
+        }
+        Ok(UtcDateTime {
+            days,
+            nanos: (nanos.rem_euclid(NANOS_PER_DAY) + i128::from(leap) * NANOS_PER_SEC) as u64
+        })
+    }
+}
+
+impl From<UtcDateTime> for DateTime {
+    fn from(datetime: UtcDateTime) -> Self {
+        let (year, month, day) = civil_from_days(datetime.days);
+        let local = LocalTime::from_nanos_of_day_with(datetime.nanos, DayEndPolicy::LeapSecond)
+            .expect("within the day or a leap second");
+        DateTime {
+            date: YmdDate {
+                year: year as i16,
+                month,
+                day
+            },
+            time: GlobalTime {
+                local,
+                timezone: 0
+            }
+        }
+    }
+}
+
+impl FromStr for UtcDateTime {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s.parse::<DateTime<Date, GlobalTime>>()?)
+    }
+}
+
+impl fmt::Display for UtcDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        DateTime::from(*self).fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    fn utc(s: &str) -> UtcDateTime {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn normalized() {
+        assert_eq!(utc("2018-08-02T13:42:02+02:00"), utc("2018-08-02T11:42:02Z"));
+        assert_eq!(utc("2018-W31-4T00:30:00+01:00"), utc("2018-08-01T23:30:00Z"));
+        assert_eq!(utc("2018-08-02T24:00:00Z"), utc("2018-08-03T00:00:00Z"));
+        assert_eq!(utc("2018-08-02T13:42:02+02:00").to_string(), "2018-08-02T11:42:02Z");
+        assert_eq!(utc("1970-01-01T01:00:00.5+01:00").sort_key(), 500_000_000);
+
+        assert_eq!("2018-02-30T13:42:02Z".parse::<UtcDateTime>(), Err(()));
+        let min = |timezone| DateTime { time: GlobalTime { timezone, ..DateTime::MIN.time }, ..DateTime::MIN };
+        assert_eq!(UtcDateTime::try_from(min(0)).map(DateTime::from), Ok(DateTime::MIN));
+        assert_eq!(UtcDateTime::try_from(min(60)), Err(()));
+        assert!(UtcDateTime::try_from(min(-60)).is_ok());
+    }
+
+    #[test]
+    fn leap_second() {
+        let leap = utc("2016-12-31T23:59:60.5Z");
+        assert!(utc("2016-12-31T23:59:59.9Z") < leap);
+        assert!(leap < utc("2017-01-01T00:00:00Z"));
+        assert_eq!(leap, utc("2017-01-01T00:59:60.5+01:00"));
+        assert_eq!(leap.to_string(), "2016-12-31T23:59:60.5Z");
+        assert_eq!(leap.sort_key(), utc("2017-01-01T00:00:00.5Z").sort_key());
+
+        assert_eq!(utc("2016-12-31T12:59:60Z"), utc("2016-12-31T13:00:00Z"));
+        assert_eq!(utc("2016-12-31T12:59:60Z").to_string(), "2016-12-31T13:00:00Z");
+    }
+
+    #[test]
+    fn ordered_map() {
+        let mut map = BTreeMap::new();
+        map.insert(utc("2018-08-02T13:42:02+02:00"), 1);
+        map.insert(utc("2018-08-02T11:42:02Z"), 2);
+        map.insert(utc("2018-08-02T11:00:00-01:00"), 3);
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.values().collect::<Vec<_>>(), [&2, &3]);
+    }
+}
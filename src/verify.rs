@@ -0,0 +1,131 @@
+#![cfg(feature = "exhaustive-tests")]
+
+//! Self-tests of the date conversions, to run at qualification time
+//! on the target that the crate is embedded in.
+
+use {
+    Valid,
+    date::*,
+    std::{
+        fmt,
+        ops::RangeInclusive
+    }
+};
+
+/// A conversion that a date failed.
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub enum Conversion {
+    /// The calendar date is not valid.
+    Validity,
+    /// To a week date and back.
+    Week,
+    /// To an ordinal date and back, or to the wrong day of the year.
+    Ordinal,
+    /// Between week and ordinal date.
+    WeekOrdinal,
+    /// To Rata Die and back, or to the wrong day.
+    RataDie,
+    /// To a day of the week that does not follow the day before.
+    Weekday
+}
+
+impl fmt::Display for Conversion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Conversion::Validity    => "validity",
+            Conversion::Week        => "week date",
+            Conversion::Ordinal     => "ordinal date",
+            Conversion::WeekOrdinal => "week date to ordinal date",
+            Conversion::RataDie     => "Rata Die",
+            Conversion::Weekday     => "day of the week"
+        })
+    }
+}
+
+/// The first date in a range that failed a conversion.
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub struct ConversionFailure {
+    pub date: YmdDate,
+    pub conversion: Conversion
+}
+
+impl fmt::Display for ConversionFailure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} fails the {} conversion", self.date, self.conversion)
+    }
+}
+
+impl ::std::error::Error for ConversionFailure {}
+
+/// Checks every calendar date in the years
+/// against its week date, ordinal date, Rata Die and day of the week.
+///
+/// Dates are counted independently of the conversions,
+/// so that consecutive dates must convert to consecutive days.
+///
+/// Week dates are not checked in the first and last year of `i16`,
+/// as their week-based years may not fit.
+pub fn verify_conversions(years: RangeInclusive<i16>) -> Result<(), ConversionFailure> {
+    let mut rata_die = None;
+    let mut weekday = None;
+
+    for year in years {
+        let mut ordinal = 0;
+        let edge = year == i16::MIN || year == i16::MAX;
+        for month in 1..=12 {
+            for day in 1..=days_in_month(year, month).expect("month is valid") {
+                let date = YmdDate { year, month, day };
+                let fail = |conversion| Err(ConversionFailure { date, conversion });
+                ordinal += 1;
+
+                if !date.is_valid() {
+                    return fail(Conversion::Validity);
+                }
+
+                let o = ODate::from(date);
+                if o.day != ordinal || !o.is_valid() || YmdDate::from(o) != date {
+                    return fail(Conversion::Ordinal);
+                }
+
+                let expected = rata_die.map_or_else(|| date.rata_die(), |x: i64| x + 1);
+                if date.rata_die() != expected || YmdDate::from_rata_die(expected) != Some(date) {
+                    return fail(Conversion::RataDie);
+                }
+                rata_die = Some(expected);
+
+                if edge {
+                    weekday = None;
+                    continue;
+                }
+
+                let wd = WdDate::from(date);
+                if !wd.is_valid() || YmdDate::from(wd) != date {
+                    return fail(Conversion::Week);
+                }
+
+                if ODate::from(wd) != o || WdDate::from(o) != wd {
+                    return fail(Conversion::WeekOrdinal);
+                }
+
+                let expected = weekday.map_or(wd.day, |x| x % 7 + 1);
+                if wd.day != expected || date.weekday() != expected {
+                    return fail(Conversion::Weekday);
+                }
+                weekday = Some(expected);
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversions() {
+        assert_eq!(verify_conversions(-400..=2400), Ok(()));
+        assert_eq!(verify_conversions(i16::MAX - 1..=i16::MAX), Ok(()));
+        assert_eq!(verify_conversions(i16::MIN..=i16::MIN + 1), Ok(()));
+    }
+}
@@ -0,0 +1,42 @@
+use std::{
+    io::Write,
+    process::{
+        Command,
+        Stdio
+    }
+};
+
+fn run(args: &[&str], input: &str) -> (bool, String) {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_iso8601"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    let output = child.wait_with_output().unwrap();
+    (output.status.success(), String::from_utf8(output.stdout).unwrap())
+}
+
+#[test]
+fn validate() {
+    assert_eq!(
+        run(&["validate"], "2018-08-02T13:42:02Z\n2018-08-02 13:42:02Z\n"),
+        (true, "Iso8601\nRfc3339\n".to_string())
+    );
+    assert!(!run(&["validate"], "2018-02-30T13:42:02Z\n").0);
+}
+
+#[test]
+fn utc() {
+    assert_eq!(run(&["utc"], "2018-08-02T13:42:02+02:00\n"), (true, "2018-08-02T11:42:02Z\n".to_string()));
+    assert!(!run(&["utc"], "2018-08-02T13:42:02\n").0);
+}
+
+#[test]
+fn convert() {
+    assert_eq!(run(&["week"],    "2018-08-02T13:42:02Z\n"), (true, "2018-W31-4T13:42:02Z\n".to_string()));
+    assert_eq!(run(&["ordinal"], "2018-08-02T13:42:02Z\n"), (true, "2018-214T13:42:02Z\n".to_string()));
+    assert_eq!(run(&["ymd"],     "2018-W31-4T13:42:02Z\n"), (true, "2018-08-02T13:42:02Z\n".to_string()));
+    assert_eq!(run(&["format", "%d.%m.%Y"], "2018-214T13:42:02Z\n"), (true, "02.08.2018\n".to_string()));
+}